@@ -2,18 +2,23 @@ const fs = require("fs");
 const https = require("https");
 const path = require("path");
 
-const url = "https://launchermeta.mojang.com/mc/game/version_manifest.json";
+const url = "https://launchermeta.mojang.com/mc/game/version_manifest_v2.json";
 
-// Resolve output path relative to the script location
+// Resolve output paths relative to the script location
 const outputPath = path.resolve(
   __dirname,
   "../../src-tauri/assets/game/versions.txt"
 );
+const manifestOutputPath = path.resolve(
+  __dirname,
+  "../../src-tauri/assets/game/version_manifest_v2.json"
+);
 
 https
   .get(url, (res) => {
     if (res.statusCode !== 200) {
       console.error(`Request failed with status code: ${res.statusCode}`);
+      process.exitCode = 1;
       return;
     }
     let data = "";
@@ -47,11 +52,25 @@ https
 
         fs.writeFileSync(outputPath, text);
         console.log(`Version list saved to: ${outputPath}`);
+
+        // Bundled snapshot used by the launcher when offline, keep only the fields it reads
+        const manifest = {
+          latest: json.latest,
+          versions: json.versions.map((v) => ({
+            id: v.id,
+            type: v.type,
+            releaseTime: v.releaseTime,
+          })),
+        };
+        fs.writeFileSync(manifestOutputPath, JSON.stringify(manifest) + "\n");
+        console.log(`Version manifest snapshot saved to: ${manifestOutputPath}`);
       } catch (error) {
         console.error("Error parsing JSON:", error);
+        process.exitCode = 1;
       }
     });
   })
   .on("error", (err) => {
     console.error("Error fetching data:", err);
+    process.exitCode = 1;
   });
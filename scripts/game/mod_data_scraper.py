@@ -1,5 +1,7 @@
 import argparse
 import csv
+import hashlib
+import json
 import os
 import random
 import re
@@ -338,6 +340,17 @@ class MCModScraper:
             writer.writerow(mod_data)
 
 
+def write_meta(filename):
+    """Write the version and sha1 of the CSV, checked by the launcher before updating its copy."""
+    with open(filename, "rb") as f:
+        sha1 = hashlib.sha1(f.read()).hexdigest()
+    meta_path = os.path.splitext(filename)[0] + "_meta.json"
+    with open(meta_path, "w", encoding="utf-8") as f:
+        json.dump({"version": int(time.time()), "sha1": sha1}, f, indent=2)
+        f.write("\n")
+    print(f"Meta file: {meta_path}")
+
+
 def main(
     start_id=1,
     end_id=10,
@@ -354,6 +367,7 @@ def main(
     scraper.scrape_mods_streaming(
         start_id=start_id, end_id=end_id, filename=filename, append=append
     )
+    write_meta(filename)
 
 
 if __name__ == "__main__":
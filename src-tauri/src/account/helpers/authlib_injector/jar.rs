@@ -2,7 +2,7 @@ use crate::account::helpers::authlib_injector::constants::AUTHLIB_INJECTOR_JAR_N
 use crate::account::models::AccountError;
 use crate::error::SJMCLResult;
 use crate::launcher_config::models::LauncherConfig;
-use crate::resource::helpers::misc::{get_download_api, get_source_priority_list};
+use crate::resource::helpers::misc::{get_download_api, get_source_priority_list_for};
 use crate::resource::models::{ResourceType, SourceType};
 use serde::{Deserialize, Serialize};
 use std::io::Read;
@@ -34,7 +34,9 @@ async fn get_latest_meta(
   let client = app.state::<reqwest::Client>();
 
   for source in priority_list.iter() {
-    let url = get_download_api(*source, ResourceType::AuthlibInjector)?;
+    let Ok(url) = get_download_api(*source, ResourceType::AuthlibInjector) else {
+      continue;
+    };
     let response = client
       .get(url.join("artifact/latest.json")?)
       .send()
@@ -110,7 +112,11 @@ pub async fn check_authlib_jar(app: &AppHandle) -> SJMCLResult<()> {
   let latest_meta = {
     let config_state = app.state::<Mutex<LauncherConfig>>();
     let launcher_config = config_state.lock()?.clone();
-    get_latest_meta(app, &get_source_priority_list(&launcher_config)).await?
+    get_latest_meta(
+      app,
+      &get_source_priority_list_for(&launcher_config, ResourceType::AuthlibInjector),
+    )
+    .await?
   };
 
   if let Ok(local_version) = get_local_version(app) {
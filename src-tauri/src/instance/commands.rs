@@ -5,20 +5,32 @@ use crate::instance::helpers::client_json::{replace_native_libraries, McClientIn
 use crate::instance::helpers::game_version::{build_game_version_cmp_fn, compare_game_versions};
 use crate::instance::helpers::loader::common::{execute_processors, install_mod_loader};
 use crate::instance::helpers::loader::forge::InstallProfile;
+use crate::instance::helpers::loader::liteloader::install_liteloader_loader;
 use crate::instance::helpers::loader::optifine::{
-  download_optifine_installer, finish_optifine_install,
+  download_optifine_installer, finish_optifine_install, remove_optifine_patch,
 };
 use crate::instance::helpers::misc::{
   get_instance_game_config, get_instance_subdir_path_by_id, get_instance_subdir_paths,
   refresh_and_update_instances, unify_instance_name,
 };
-use crate::instance::helpers::modpack::misc::{
-  extract_overrides, get_download_params, ModpackMetaInfo,
+use crate::instance::helpers::modpack::misc::{install_modpack_files, ModpackMetaInfo};
+use crate::instance::helpers::modpack::packwiz::export_instance_to_packwiz;
+use crate::instance::helpers::modpack::update::{
+  apply_modpack_update, discard_modpack_update, plan_modpack_update, resolve_modpack_ids,
 };
+use crate::instance::helpers::modpack::verify::{
+  check_modpack_files, emit_modpack_files_check, get_repair_params, spawn_modpack_files_check,
+  ModpackVerificationReport,
+};
+use crate::instance::helpers::mods::bisect::{build_bisect_units, ModBisectSession};
 use crate::instance::helpers::mods::common::{
-  add_local_mod_translations, compress_icon, get_mod_info_from_dir, get_mod_info_from_jar,
+  add_local_mod_translations, compress_icon, load_local_mod_infos, LocalModMetadataCache,
   LocalModTranslationEntry, LocalModTranslationsCache,
 };
+use crate::instance::helpers::mods::duplicate::{find_duplicate_mods, DuplicateModGroup};
+use crate::instance::helpers::mods::profile::{
+  apply_mod_profile as apply_mod_profile_to_dir, capture_mod_profile, ModProfile,
+};
 use crate::instance::helpers::options_txt::get_zh_hans_lang_tag;
 use crate::instance::helpers::resourcepack::{
   load_resourcepack_from_dir, load_resourcepack_from_zip,
@@ -38,13 +50,14 @@ use crate::launch::helpers::file_validator::{get_invalid_assets, get_invalid_lib
 use crate::launcher_config::helpers::misc::get_global_game_config;
 use crate::launcher_config::models::{GameConfig, GameDirectory, LauncherConfig};
 use crate::partial::{PartialError, PartialUpdate};
-use crate::resource::helpers::misc::get_source_priority_list;
+use crate::resource::helpers::misc::{get_first_source_for, get_source_priority_list};
 use crate::resource::models::{
-  GameClientResourceInfo, ModLoaderResourceInfo, OptiFineResourceInfo,
+  GameClientResourceInfo, ModLoaderResourceInfo, OptiFineResourceInfo, ResourceType,
 };
 use crate::storage::{load_json_async, save_json_async, Storage};
 use crate::tasks::commands::schedule_progressive_task_group;
 use crate::tasks::download::DownloadParam;
+use crate::tasks::monitor::TaskMonitor;
 use crate::tasks::PTaskParam;
 use crate::utils::fs::{
   copy_whole_dir, create_url_shortcut, generate_unique_filename, get_files_with_regex,
@@ -56,6 +69,7 @@ use regex::{Regex, RegexBuilder};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::pin::Pin;
 use std::sync::{Arc, Mutex};
 use std::time::SystemTime;
 use tauri::State;
@@ -453,55 +467,7 @@ pub async fn retrieve_local_mod_list(
     None => return Ok(Vec::new()),
   };
 
-  let valid_extensions = RegexBuilder::new(r"\.(jar|zip)(\.disabled)*$")
-    .case_insensitive(true)
-    .build()
-    .unwrap();
-
-  let mod_paths = get_files_with_regex(&mods_dir, &valid_extensions).unwrap_or_default();
-  let mut tasks = Vec::new();
-  let semaphore = Arc::new(Semaphore::new(
-    std::thread::available_parallelism().unwrap().into(),
-  ));
-  for path in mod_paths {
-    let permit = semaphore
-      .clone()
-      .acquire_owned()
-      .await
-      .map_err(|_| InstanceError::SemaphoreAcquireFailed)?;
-    let task = tokio::spawn(async move {
-      log::debug!("Load mod info from dir: {}", path.display());
-      let info = get_mod_info_from_jar(&path).await.ok();
-      drop(permit);
-      info
-    });
-    tasks.push(task);
-  }
-  #[cfg(debug_assertions)]
-  {
-    // mod information detection from folders is only used for debugging.
-    let mod_paths = get_subdirectories(&mods_dir).unwrap_or_default();
-    for path in mod_paths {
-      let permit = semaphore
-        .clone()
-        .acquire_owned()
-        .await
-        .map_err(|_| InstanceError::SemaphoreAcquireFailed)?;
-      let task = tokio::spawn(async move {
-        log::debug!("Load mod info from dir: {}", path.display());
-        let info = get_mod_info_from_dir(&path).await.ok();
-        drop(permit);
-        info
-      });
-      tasks.push(task);
-    }
-  }
-  let mut mod_infos = Vec::new();
-  for task in tasks {
-    if let Ok(Some(mod_info)) = task.await {
-      mod_infos.push(mod_info);
-    }
-  }
+  let mut mod_infos = load_local_mod_infos(&app, &mods_dir).await?;
 
   // check potential incompatibility
   let incompatible_loader_type = {
@@ -520,13 +486,16 @@ pub async fn retrieve_local_mod_list(
 
   mod_infos.iter_mut().for_each(|mod_info| {
     if let Some(loader_type) = &incompatible_loader_type {
-      mod_info.potential_incompatibility = mod_info.loader_type != *loader_type;
+      mod_info.potential_incompatibility = !loader_type.can_load(&mod_info.loader_type);
     } else {
       mod_info.potential_incompatibility = false;
     }
   });
 
   // Add translations for mod names and descriptions concurrently
+  let semaphore = Arc::new(Semaphore::new(
+    std::thread::available_parallelism().unwrap().into(),
+  ));
   let mut translation_tasks = Vec::new();
   for mut mod_info in mod_infos {
     let app = app.clone();
@@ -567,6 +536,8 @@ pub async fn retrieve_local_mod_list(
     );
   }
   cache.save()?;
+  // save remote matches found while translating
+  app.state::<Mutex<LocalModMetadataCache>>().lock()?.save()?;
 
   Ok(mod_infos)
 }
@@ -777,7 +748,8 @@ pub fn retrieve_screenshot_list(
 }
 
 lazy_static! {
-  static ref RENAME_LOCK: Mutex<()> = Mutex::new(());
+  // held while renaming mod files, by the mods page, profiles, bisect and safe mode
+  pub static ref RENAME_LOCK: Mutex<()> = Mutex::new(());
   static ref RENAME_REGEX: Regex = RegexBuilder::new(r"^(.*?)(\.disabled)*$")
     .case_insensitive(true)
     .build()
@@ -821,6 +793,224 @@ pub fn toggle_mod_by_extension(file_path: PathBuf, enable: bool) -> SJMCLResult<
   Ok(())
 }
 
+#[tauri::command]
+pub async fn retrieve_duplicate_mod_list(
+  app: AppHandle,
+  instance_id: String,
+) -> SJMCLResult<Vec<DuplicateModGroup>> {
+  let mods_dir = match get_instance_subdir_path_by_id(&app, &instance_id, &InstanceSubdirType::Mods)
+  {
+    Some(path) => path,
+    None => return Ok(Vec::new()),
+  };
+  let mod_infos = load_local_mod_infos(&app, &mods_dir).await?;
+  Ok(find_duplicate_mods(&mod_infos))
+}
+
+#[tauri::command]
+pub async fn cleanup_duplicate_mods(
+  app: AppHandle,
+  instance_id: String,
+  delete: bool,
+) -> SJMCLResult<()> {
+  let duplicate_groups = retrieve_duplicate_mod_list(app, instance_id).await?;
+  for group in &duplicate_groups {
+    for mod_info in &group.duplicates {
+      if delete {
+        if mod_info.file_path.is_dir() {
+          fs::remove_dir_all(&mod_info.file_path)?;
+        } else {
+          fs::remove_file(&mod_info.file_path)?;
+        }
+      } else if mod_info.enabled && mod_info.file_path.is_file() {
+        toggle_mod_by_extension(mod_info.file_path.clone(), false)?;
+      }
+    }
+  }
+  Ok(())
+}
+
+#[tauri::command]
+pub fn retrieve_mod_profile_list(
+  app: AppHandle,
+  instance_id: String,
+) -> SJMCLResult<Vec<ModProfile>> {
+  let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+  let state = binding.lock()?;
+  let instance = state
+    .get(&instance_id)
+    .ok_or(InstanceError::InstanceNotFoundByID)?;
+  Ok(instance.mod_profiles.clone())
+}
+
+#[tauri::command]
+pub async fn save_mod_profile(
+  app: AppHandle,
+  instance_id: String,
+  profile_name: String,
+) -> SJMCLResult<ModProfile> {
+  let mods_dir = get_instance_subdir_path_by_id(&app, &instance_id, &InstanceSubdirType::Mods)
+    .ok_or(InstanceError::InstanceNotFoundByID)?;
+  let profile = capture_mod_profile(&mods_dir, profile_name)?;
+
+  let instance = {
+    let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+    let mut state = binding.lock()?;
+    let instance = state
+      .get_mut(&instance_id)
+      .ok_or(InstanceError::InstanceNotFoundByID)?;
+    // overwrite the profile with the same name
+    instance.mod_profiles.retain(|p| p.name != profile.name);
+    instance.mod_profiles.push(profile.clone());
+    instance.clone()
+  };
+  instance.save_json_cfg().await?;
+  Ok(profile)
+}
+
+#[tauri::command]
+pub async fn delete_mod_profile(
+  app: AppHandle,
+  instance_id: String,
+  profile_name: String,
+) -> SJMCLResult<()> {
+  let instance = {
+    let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+    let mut state = binding.lock()?;
+    let instance = state
+      .get_mut(&instance_id)
+      .ok_or(InstanceError::InstanceNotFoundByID)?;
+    if !instance.mod_profiles.iter().any(|p| p.name == profile_name) {
+      return Err(InstanceError::ModProfileNotFound.into());
+    }
+    instance.mod_profiles.retain(|p| p.name != profile_name);
+    instance.clone()
+  };
+  instance.save_json_cfg().await?;
+  Ok(())
+}
+
+#[tauri::command]
+pub fn apply_mod_profile(
+  app: AppHandle,
+  instance_id: String,
+  profile_name: String,
+) -> SJMCLResult<()> {
+  let mods_dir = get_instance_subdir_path_by_id(&app, &instance_id, &InstanceSubdirType::Mods)
+    .ok_or(InstanceError::InstanceNotFoundByID)?;
+  let profile = {
+    let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+    let state = binding.lock()?;
+    let instance = state
+      .get(&instance_id)
+      .ok_or(InstanceError::InstanceNotFoundByID)?;
+    instance
+      .mod_profiles
+      .iter()
+      .find(|p| p.name == profile_name)
+      .cloned()
+      .ok_or(InstanceError::ModProfileNotFound)?
+  };
+
+  let _lock = RENAME_LOCK.lock().expect("Failed to acquire lock");
+  apply_mod_profile_to_dir(&mods_dir, &profile)
+}
+
+#[tauri::command]
+pub fn retrieve_mod_bisect_session(
+  app: AppHandle,
+  instance_id: String,
+) -> SJMCLResult<Option<ModBisectSession>> {
+  let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+  let state = binding.lock()?;
+  let instance = state
+    .get(&instance_id)
+    .ok_or(InstanceError::InstanceNotFoundByID)?;
+  Ok(instance.mod_bisect.clone())
+}
+
+async fn save_mod_bisect_session(
+  app: &AppHandle,
+  instance_id: &str,
+  session: Option<ModBisectSession>,
+) -> SJMCLResult<()> {
+  let instance = {
+    let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+    let mut state = binding.lock()?;
+    let instance = state
+      .get_mut(instance_id)
+      .ok_or(InstanceError::InstanceNotFoundByID)?;
+    instance.mod_bisect = session;
+    instance.clone()
+  };
+  instance.save_json_cfg().await?;
+  Ok(())
+}
+
+#[tauri::command]
+pub async fn start_mod_bisect(
+  app: AppHandle,
+  instance_id: String,
+) -> SJMCLResult<ModBisectSession> {
+  if retrieve_mod_bisect_session(app.clone(), instance_id.clone())?.is_some_and(|s| !s.finished) {
+    return Err(InstanceError::ModBisectInProgress.into());
+  }
+  let mods_dir = get_instance_subdir_path_by_id(&app, &instance_id, &InstanceSubdirType::Mods)
+    .ok_or(InstanceError::InstanceNotFoundByID)?;
+
+  let mod_infos = load_local_mod_infos(&app, &mods_dir).await?;
+  let original = capture_mod_profile(&mods_dir, "original".to_string())?;
+  let mut session = ModBisectSession::new(original, build_bisect_units(&mod_infos));
+  if let Some(profile) = session.next_profile() {
+    let _lock = RENAME_LOCK.lock().expect("Failed to acquire lock");
+    apply_mod_profile_to_dir(&mods_dir, &profile)?;
+  }
+
+  save_mod_bisect_session(&app, &instance_id, Some(session.clone())).await?;
+  Ok(session)
+}
+
+#[tauri::command]
+pub async fn submit_mod_bisect_result(
+  app: AppHandle,
+  instance_id: String,
+  reached_ready: bool,
+) -> SJMCLResult<ModBisectSession> {
+  let mut session = retrieve_mod_bisect_session(app.clone(), instance_id.clone())?
+    .filter(|s| !s.finished)
+    .ok_or(InstanceError::ModBisectNotInProgress)?;
+  if !session.record_result(reached_ready) {
+    return Err(InstanceError::ModBisectNotInProgress.into());
+  }
+  let mods_dir = get_instance_subdir_path_by_id(&app, &instance_id, &InstanceSubdirType::Mods)
+    .ok_or(InstanceError::InstanceNotFoundByID)?;
+
+  {
+    let _lock = RENAME_LOCK.lock().expect("Failed to acquire lock");
+    match session.next_profile() {
+      Some(profile) => apply_mod_profile_to_dir(&mods_dir, &profile)?,
+      // culprit found, restore the original state
+      None => apply_mod_profile_to_dir(&mods_dir, &session.original)?,
+    }
+  }
+
+  save_mod_bisect_session(&app, &instance_id, Some(session.clone())).await?;
+  Ok(session)
+}
+
+#[tauri::command]
+pub async fn stop_mod_bisect(app: AppHandle, instance_id: String) -> SJMCLResult<()> {
+  let session = retrieve_mod_bisect_session(app.clone(), instance_id.clone())?
+    .ok_or(InstanceError::ModBisectNotInProgress)?;
+  if !session.finished {
+    let mods_dir = get_instance_subdir_path_by_id(&app, &instance_id, &InstanceSubdirType::Mods)
+      .ok_or(InstanceError::InstanceNotFoundByID)?;
+    let _lock = RENAME_LOCK.lock().expect("Failed to acquire lock");
+    apply_mod_profile_to_dir(&mods_dir, &session.original)?;
+  }
+  save_mod_bisect_session(&app, &instance_id, None).await
+}
+
 #[tauri::command]
 pub async fn retrieve_world_details(
   app: AppHandle,
@@ -877,6 +1067,8 @@ pub async fn create_instance(
   optifine: Option<OptiFineResourceInfo>,
   modpack_path: Option<String>,
   is_install_fabric_api: Option<bool>,
+  modpack_optional_files: Option<Vec<String>>,
+  liteloader: Option<ModLoaderResourceInfo>,
 ) -> SJMCLResult<()> {
   let client = app.state::<reqwest::Client>();
   let launcher_config_state = app.state::<Mutex<LauncherConfig>>();
@@ -891,6 +1083,10 @@ pub async fn create_instance(
   if version_path.exists() {
     return Err(InstanceError::ConflictNameError.into());
   }
+  // LiteLoader can only be installed on top of Forge, otherwise it is the mod loader itself
+  if liteloader.is_some() && mod_loader.loader_type != ModLoaderType::Forge {
+    return Err(InstanceError::UnsupportedModLoader.into());
+  }
   let optifine_info = optifine.as_ref().map(|info| OptiFine {
     filename: info.filename.clone(),
     version: format!("{}_{}", info.r#type, info.patch),
@@ -898,7 +1094,7 @@ pub async fn create_instance(
   });
 
   // Create instance config
-  let instance = Instance {
+  let mut instance = Instance {
     id: format!("{}:{}", directory.name, name.clone()),
     name: name.clone(),
     version: game.id.clone(),
@@ -907,7 +1103,10 @@ pub async fn create_instance(
       loader_type: mod_loader.loader_type.clone(),
       status: if matches!(
         mod_loader.loader_type,
-        ModLoaderType::Unknown | ModLoaderType::Fabric
+        ModLoaderType::Unknown
+          | ModLoaderType::Fabric
+          | ModLoaderType::Quilt
+          | ModLoaderType::LiteLoader
       ) {
         ModLoaderStatus::Installed
       } else {
@@ -923,6 +1122,11 @@ pub async fn create_instance(
     play_time: 0,
     use_spec_game_config: false,
     spec_game_config: None,
+    mod_profiles: Vec::new(),
+    mod_bisect: None,
+    safe_mode_profile: None,
+    mod_update_history: Vec::new(),
+    modpack: None,
   };
 
   // Download version info
@@ -960,6 +1164,8 @@ pub async fn create_instance(
     dest: instance.version_path.join(format!("{}.jar", name)),
     filename: None,
     sha1: Some(client_download_info.sha1.clone()),
+    alt_srcs: vec![],
+    served_src: None,
   }));
   let subdirs = get_instance_subdir_paths(
     &app,
@@ -980,13 +1186,15 @@ pub async fn create_instance(
     .map_err(|_| InstanceError::ClientJsonParseError)?;
 
   // We only download libraries if they are invalid (not already downloaded)
+  let libraries_source = get_first_source_for(&priority_list, ResourceType::Libraries);
   task_params.extend(
-    get_invalid_library_files(priority_list[0], libraries_dir, &version_info, false).await?,
+    get_invalid_library_files(libraries_source, libraries_dir, &version_info, false).await?,
   );
 
   // We only download assets if they are invalid (not already downloaded)
+  let assets_source = get_first_source_for(&priority_list, ResourceType::Assets);
   task_params
-    .extend(get_invalid_assets(&app, &version_info, priority_list[0], assets_dir, false).await?);
+    .extend(get_invalid_assets(&app, &version_info, assets_source, assets_dir, false).await?);
 
   // download loader (installer)
   if instance.mod_loader.loader_type != ModLoaderType::Unknown {
@@ -1004,6 +1212,24 @@ pub async fn create_instance(
     .await?;
   }
 
+  if let Some(info) = liteloader.as_ref() {
+    let liteloader = ModLoader {
+      loader_type: ModLoaderType::LiteLoader,
+      version: info.version.clone(),
+      ..Default::default()
+    };
+    install_liteloader_loader(
+      &app,
+      &priority_list,
+      &instance.version,
+      &liteloader,
+      libraries_dir.to_path_buf(),
+      &mut version_info,
+      &mut task_params,
+    )
+    .await?;
+  }
+
   if let Some(info) = optifine.as_ref() {
     download_optifine_installer(
       &instance.version,
@@ -1016,19 +1242,35 @@ pub async fn create_instance(
 
   // If modpack path is provided, install it
   if let Some(modpack_path) = modpack_path {
-    let path = PathBuf::from(modpack_path);
-    let file = fs::File::open(&path).map_err(|_| InstanceError::FileNotFoundError)?;
-    task_params.extend(get_download_params(&app, &file, &version_path).await?);
-    extract_overrides(&file, &version_path)?;
+    let (modpack_params, mut installed) = install_modpack_files(
+      &app,
+      &modpack_path,
+      &version_path,
+      modpack_optional_files.as_deref(),
+    )
+    .await?;
+    resolve_modpack_ids(&app, &modpack_path, &mut installed).await;
+    task_params.extend(modpack_params);
+    instance.modpack = Some(installed);
   }
 
-  schedule_progressive_task_group(
+  let task_group = schedule_progressive_task_group(
     app.clone(),
     format!("game-client?{}", name),
     task_params,
     true,
   )
-  .await?;
+  .await?
+  .task_group;
+  if let Some(installed) = instance.modpack.clone() {
+    spawn_modpack_files_check(
+      app.clone(),
+      task_group,
+      instance.id.clone(),
+      version_path.clone(),
+      installed,
+    );
+  }
 
   // Optionally skip first-screen options by adding options.txt (available for zh-Hans only)
   let (language, skip_first_screen_options) = {
@@ -1189,6 +1431,7 @@ pub async fn change_mod_loader(
   instance_id: String,
   new_mod_loader: ModLoaderResourceInfo,
   is_install_fabric_api: Option<bool>,
+  is_move_mods_aside: Option<bool>,
 ) -> SJMCLResult<()> {
   let mut instance = {
     let binding = app.state::<Mutex<HashMap<String, Instance>>>();
@@ -1222,7 +1465,10 @@ pub async fn change_mod_loader(
     version: new_mod_loader.version.clone(),
     status: if matches!(
       new_mod_loader.loader_type,
-      ModLoaderType::Unknown | ModLoaderType::Fabric
+      ModLoaderType::Unknown
+        | ModLoaderType::Fabric
+        | ModLoaderType::Quilt
+        | ModLoaderType::LiteLoader
     ) {
       ModLoaderStatus::Installed
     } else {
@@ -1240,12 +1486,28 @@ pub async fn change_mod_loader(
   let [libraries_dir, mods_dir] = subdirs.as_slice() else {
     return Err(InstanceError::InstanceNotFoundByID.into());
   };
-  // Remove Fabric API mods if switching from Fabric modloader
-  if instance.mod_loader.loader_type == ModLoaderType::Fabric && version_isolation {
+  // Remove (Quilted) Fabric API mods if switching from Fabric or Quilt modloader
+  if matches!(
+    instance.mod_loader.loader_type,
+    ModLoaderType::Fabric | ModLoaderType::Quilt
+  ) && version_isolation
+  {
     remove_fabric_api_mods(mods_dir).await?;
   }
-  // construct new version info
+  let is_uninstall = mod_loader.loader_type == ModLoaderType::Unknown;
+  // keep the mods of the removed loader out of the vanilla game, e.g. `mods-forge`
+  if is_uninstall && is_move_mods_aside.unwrap_or(false) && version_isolation && mods_dir.exists() {
+    let parent = mods_dir.parent().ok_or(InstanceError::InvalidSourcePath)?;
+    let backup_name = format!(
+      "mods-{}",
+      instance.mod_loader.loader_type.to_string().to_lowercase()
+    );
+    let backup_dir = generate_unique_filename(parent, backup_name.as_ref());
+    fs::rename(mods_dir, &backup_dir).map_err(|_| InstanceError::FileMoveFailed)?;
+  }
+  // construct new version info, the vanilla patch restores the main class and arguments
   instance.mod_loader = mod_loader.clone();
+  instance.optifine = None;
   let mut version_info: McClientInfo = vanilla_info.clone();
   version_info.id = current_info.id.clone();
   version_info.jar = Some(instance.name.clone());
@@ -1253,31 +1515,33 @@ pub async fn change_mod_loader(
   version_info.client_version = Some(instance.version.clone());
   version_info.patches = vec![vanilla_info];
 
-  // install new mod loader
-  let mut task_params: Vec<PTaskParam> = Vec::new();
-  install_mod_loader(
-    app.clone(),
-    &priority_list,
-    &game_version,
-    &mod_loader,
-    libraries_dir.to_path_buf(),
-    mods_dir.to_path_buf(),
-    &mut version_info,
-    &mut task_params,
-    is_install_fabric_api,
-  )
-  .await?;
+  // install new mod loader, nothing to install when reverting to vanilla
+  if !is_uninstall {
+    let mut task_params: Vec<PTaskParam> = Vec::new();
+    install_mod_loader(
+      app.clone(),
+      &priority_list,
+      &game_version,
+      &mod_loader,
+      libraries_dir.to_path_buf(),
+      mods_dir.to_path_buf(),
+      &mut version_info,
+      &mut task_params,
+      is_install_fabric_api,
+    )
+    .await?;
 
-  schedule_progressive_task_group(
-    app.clone(),
-    format!(
-      "change-mod-loader?{} {}",
-      mod_loader.loader_type, mod_loader.version
-    ),
-    task_params,
-    true,
-  )
-  .await?;
+    schedule_progressive_task_group(
+      app.clone(),
+      format!(
+        "change-mod-loader?{} {}",
+        mod_loader.loader_type, mod_loader.version
+      ),
+      task_params,
+      true,
+    )
+    .await?;
+  }
 
   save_json_async(&version_info, &json_path).await?;
   instance
@@ -1288,14 +1552,196 @@ pub async fn change_mod_loader(
   Ok(())
 }
 
+#[tauri::command]
+pub async fn remove_optifine(app: AppHandle, instance_id: String) -> SJMCLResult<()> {
+  let instance = {
+    let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+    let mut state = binding.lock()?;
+    let instance = state
+      .get_mut(&instance_id)
+      .ok_or(InstanceError::InstanceNotFoundByID)?;
+    instance.optifine = None;
+    instance.clone()
+  };
+
+  let json_path = instance
+    .version_path
+    .join(format!("{}.json", instance.name));
+  let mut client_info: McClientInfo = load_json_async(&json_path).await?;
+  remove_optifine_patch(&mut client_info);
+
+  save_json_async(&client_info, &json_path).await?;
+  instance
+    .save_json_cfg()
+    .await
+    .map_err(|_| InstanceError::FileCreationFailed)?;
+
+  Ok(())
+}
+
 #[tauri::command]
 pub async fn retrieve_modpack_meta_info(
   app: AppHandle,
   path: String,
 ) -> SJMCLResult<ModpackMetaInfo> {
-  let path = PathBuf::from(path);
-  let file = fs::File::open(&path).map_err(|_| InstanceError::FileNotFoundError)?;
-  ModpackMetaInfo::from_archive(&app, &file).await
+  ModpackMetaInfo::from_path(&app, &path).await
+}
+
+#[tauri::command]
+pub async fn update_modpack(app: AppHandle, instance_id: String, path: String) -> SJMCLResult<()> {
+  let instance = {
+    let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+    let state = binding.lock()?;
+    state
+      .get(&instance_id)
+      .cloned()
+      .ok_or(InstanceError::InstanceNotFoundByID)?
+  };
+  let installed = instance
+    .modpack
+    .clone()
+    .ok_or(InstanceError::NotModpackInstance)?;
+  let version_path = instance.version_path.clone();
+
+  let mut plan = plan_modpack_update(
+    &app,
+    &path,
+    &version_path,
+    &installed,
+    &instance.version,
+    &instance.mod_loader,
+  )
+  .await?;
+  resolve_modpack_ids(&app, &path, &mut plan.updated).await;
+
+  // the new files are downloaded aside, the instance is left untouched until all of them succeed
+  discard_modpack_update(&version_path);
+  let task_group = schedule_progressive_task_group(
+    app.clone(),
+    format!("modpack-update?{}", instance.name),
+    plan.get_staged_task_params(&version_path),
+    true,
+  )
+  .await?
+  .task_group;
+
+  tauri::async_runtime::spawn(async move {
+    let monitor = app.state::<Pin<Box<TaskMonitor>>>();
+    if !monitor.wait_for_group(&task_group).await {
+      discard_modpack_update(&version_path);
+      return;
+    }
+    let updated = match apply_modpack_update(plan, &path, &version_path, &installed) {
+      Ok(updated) => updated,
+      Err(e) => {
+        log::error!("Failed to update the modpack of {}: {:?}", instance_id, e);
+        return;
+      }
+    };
+
+    let instance = {
+      let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+      let Ok(mut state) = binding.lock() else {
+        return;
+      };
+      let Some(instance) = state.get_mut(&instance_id) else {
+        return;
+      };
+      instance.modpack = Some(updated.clone());
+      instance.clone()
+    };
+    if let Err(e) = instance.save_json_cfg().await {
+      log::error!("Failed to save the updated modpack record: {:?}", e);
+    }
+    emit_modpack_files_check(&app, instance_id, version_path, updated).await;
+  });
+
+  Ok(())
+}
+
+#[tauri::command]
+pub async fn verify_modpack_files(
+  app: AppHandle,
+  instance_id: String,
+) -> SJMCLResult<ModpackVerificationReport> {
+  let instance = {
+    let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+    let state = binding.lock()?;
+    state
+      .get(&instance_id)
+      .cloned()
+      .ok_or(InstanceError::InstanceNotFoundByID)?
+  };
+  let installed = instance.modpack.ok_or(InstanceError::NotModpackInstance)?;
+
+  // hashing hundreds of mods takes a while
+  let report = tauri::async_runtime::spawn_blocking(move || {
+    check_modpack_files(&instance.version_path, &installed)
+  })
+  .await?;
+  Ok(report)
+}
+
+#[tauri::command]
+pub async fn repair_modpack_files(app: AppHandle, instance_id: String) -> SJMCLResult<()> {
+  let instance = {
+    let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+    let state = binding.lock()?;
+    state
+      .get(&instance_id)
+      .cloned()
+      .ok_or(InstanceError::InstanceNotFoundByID)?
+  };
+  let installed = instance
+    .modpack
+    .clone()
+    .ok_or(InstanceError::NotModpackInstance)?;
+
+  let version_path = instance.version_path.clone();
+  let task_params = tauri::async_runtime::spawn_blocking(move || {
+    let report = check_modpack_files(&version_path, &installed);
+    if report.is_valid() {
+      return Ok(Vec::new());
+    }
+    get_repair_params(&version_path, &installed, &report)
+  })
+  .await??;
+  if task_params.is_empty() {
+    return Ok(());
+  }
+
+  schedule_progressive_task_group(
+    app.clone(),
+    format!("modpack-repair?{}", instance.name),
+    task_params,
+    true,
+  )
+  .await?;
+  Ok(())
+}
+
+#[tauri::command]
+pub async fn export_packwiz_pack(
+  app: AppHandle,
+  instance_id: String,
+  save_path: String,
+) -> SJMCLResult<()> {
+  let instance = {
+    let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+    let state = binding.lock()?;
+    state
+      .get(&instance_id)
+      .cloned()
+      .ok_or(InstanceError::InstanceNotFoundByID)?
+  };
+  let dirs = get_instance_subdir_paths(
+    &app,
+    &instance,
+    &[&InstanceSubdirType::Root, &InstanceSubdirType::Mods],
+  )
+  .ok_or(InstanceError::InstanceNotFoundByID)?;
+
+  export_instance_to_packwiz(&app, &instance, &dirs[0], &dirs[1], Path::new(&save_path)).await
 }
 
 #[tauri::command]
@@ -1,4 +1,7 @@
 pub const INSTANCE_CFG_FILE_NAME: &str = "sjmclcfg.json";
 pub const TRANSLATION_CACHE_EXPIRY_HOURS: u64 = 24;
 pub const TRANSLATION_CACHE_FILE_NAME: &str = "local_mod_translations.json";
+pub const METADATA_CACHE_FILE_NAME: &str = "local_mod_metadata.json";
 pub const COMPRESSED_ICON_SIZE: (u32, u32) = (64, 64);
+pub const MOD_UPDATE_BACKUP_DIR_NAME: &str = ".mod_update_backups";
+pub const MOD_UPDATE_HISTORY_LIMIT: usize = 10;
@@ -320,7 +320,11 @@ pub fn patches_to_info(
     if game_version.is_none() && patch.id == "game" {
       game_version = patch.version.clone();
     }
-    if loader_type == ModLoaderType::Unknown {
+    // LiteLoader may be installed on top of Forge, which is the main loader then
+    if matches!(
+      loader_type,
+      ModLoaderType::Unknown | ModLoaderType::LiteLoader
+    ) {
       if let Ok(found_loader_type) = ModLoaderType::from_str(&patch.id) {
         loader_type = found_loader_type;
         loader_version = patch.version.clone();
@@ -398,6 +402,11 @@ pub async fn libraries_to_info(
         }
         break;
       }
+      ("com.cleanroommc", "cleanroom") => {
+        loader_type = ModLoaderType::Cleanroom;
+        loader_version = Some(v.to_string());
+        break;
+      }
       ("com.mumfrey", "liteloader") => {
         loader_type = ModLoaderType::LiteLoader;
         loader_version = Some(v.to_string());
@@ -1,37 +1,52 @@
 use crate::launcher_config::models::LauncherConfig;
 use crate::resource::helpers::misc::get_source_priority_list;
-use crate::resource::helpers::version_manifest::get_game_version_manifest;
+use crate::resource::helpers::version_manifest::{
+  get_game_version_manifest, load_local_version_manifest,
+};
 use crate::utils::fs::get_app_resource_filepath;
+use chrono::{DateTime, FixedOffset};
 use regex::Regex;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
 use std::sync::Mutex;
-use tauri::path::BaseDirectory;
 use tauri::{AppHandle, Manager};
 
-fn load_versions(app: &AppHandle, path: &str, from_cache: bool) -> Vec<String> {
-  let list_file_path: Option<PathBuf> = if from_cache {
-    app.path().resolve(path, BaseDirectory::AppCache).ok()
-  } else {
-    get_app_resource_filepath(app, path).ok()
-  };
-
+fn load_builtin_versions(app: &AppHandle) -> Vec<String> {
   // Read & split lines, or return an empty vec
-  list_file_path
+  get_app_resource_filepath(app, "assets/game/versions.txt")
+    .ok()
     .and_then(|p| fs::read_to_string(p).ok())
     .map(|content| content.lines().map(|l| l.trim().to_string()).collect())
     .unwrap_or_default()
 }
 
+fn parse_release_time(release_time: &str) -> Option<DateTime<FixedOffset>> {
+  DateTime::parse_from_rfc3339(release_time).ok()
+}
+
+// version ids in the local manifest (persisted or bundled), ordered by release time
+// in the same way as `assets/game/versions.txt` is generated.
+fn load_manifest_versions(app: &AppHandle) -> Vec<String> {
+  let Some(manifest) = load_local_version_manifest(app) else {
+    return Vec::new();
+  };
+  let mut versions: Vec<_> = manifest
+    .versions
+    .iter()
+    .map(|v| (parse_release_time(&v.release_time), v.id.clone()))
+    .collect();
+  versions.sort();
+  versions.into_iter().map(|(_, id)| id).collect()
+}
+
 fn try_find(versions: &[String], version: &str) -> Option<usize> {
   versions.iter().position(|v| v == version)
 }
 
 /// Compare two Minecraft version IDs.
 /// The order is determined by `assets/game/versions.txt`,
-/// or fallback to cache, and optionally refresh from remote manifest if not found.
+/// or fallback to the local version manifest, and optionally refresh from remote manifest if not found.
 ///
 /// # Examples
 /// ```
@@ -56,13 +71,13 @@ pub async fn compare_game_versions(
   version_b: &str,
   fallback_fetch_remote: bool,
 ) -> Ordering {
-  let mut versions = load_versions(app, "assets/game/versions.txt", false);
+  let mut versions = load_builtin_versions(app);
   let mut idx_a = try_find(&versions, version_a);
   let mut idx_b = try_find(&versions, version_b);
 
-  // Fallback to search in cache version list saved by `get_game_version_manifest()`.
+  // Fallback to search in the local manifest persisted by `get_game_version_manifest()`.
   if idx_a.is_none() || idx_b.is_none() {
-    versions = load_versions(app, "game_versions.txt", true);
+    versions = load_manifest_versions(app);
     idx_a = try_find(&versions, version_a);
     idx_b = try_find(&versions, version_b);
   }
@@ -75,7 +90,7 @@ pub async fn compare_game_versions(
         get_source_priority_list(&locked)
       };
       let _ = get_game_version_manifest(app, &priority_list).await;
-      versions = load_versions(app, "game_versions.txt", true);
+      versions = load_manifest_versions(app);
       idx_a = try_find(&versions, version_a);
       idx_b = try_find(&versions, version_b);
     }
@@ -105,10 +120,10 @@ pub async fn compare_game_versions(
 /// A closure suitable for `.sort_by()` or `.sort_by_key()` usage.
 ///
 pub fn build_game_version_cmp_fn(app: &AppHandle) -> impl Fn(&str, &str) -> Ordering {
-  let mut versions = load_versions(app, "assets/game/versions.txt", false);
+  let mut versions = load_builtin_versions(app);
 
   if versions.is_empty() {
-    versions = load_versions(app, "game_versions.txt", true);
+    versions = load_manifest_versions(app);
   }
 
   let index_map: HashMap<String, usize> = versions
@@ -200,12 +215,12 @@ pub async fn get_major_game_version(
     return extract_major_version(version);
   }
 
-  let mut versions = load_versions(app, "assets/game/versions.txt", false);
+  let mut versions = load_builtin_versions(app);
   if let Some(idx) = try_find(&versions, version) {
     return find_closest_major_version(&versions, idx);
   }
 
-  versions = load_versions(app, "game_versions.txt", true);
+  versions = load_manifest_versions(app);
   if let Some(idx) = try_find(&versions, version) {
     return find_closest_major_version(&versions, idx);
   }
@@ -217,7 +232,7 @@ pub async fn get_major_game_version(
         get_source_priority_list(&locked)
       };
       let _ = get_game_version_manifest(app, &priority_list).await;
-      versions = load_versions(app, "game_versions.txt", true);
+      versions = load_manifest_versions(app);
       if let Some(idx) = try_find(&versions, version) {
         return find_closest_major_version(&versions, idx);
       }
@@ -226,3 +241,21 @@ pub async fn get_major_game_version(
 
   String::new()
 }
+
+/// Get the release time of a Minecraft version ID from the local version manifest,
+/// which is persisted after each fetch and bundled with the launcher, so no network is needed.
+///
+/// # Expected result
+/// - Returns the release time if the version is listed in the local manifest
+/// - Returns `None` if not found (e.g. a version newer than the local manifest) or unparsable
+pub fn get_game_version_release_time(
+  app: &AppHandle,
+  version: &str,
+) -> Option<DateTime<FixedOffset>> {
+  let manifest = load_local_version_manifest(app)?;
+  manifest
+    .versions
+    .iter()
+    .find(|v| v.id == version)
+    .and_then(|v| parse_release_time(&v.release_time))
+}
@@ -1,16 +1,22 @@
-use std::fs::File;
-use std::io::Read;
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::Mutex;
+use tauri::path::BaseDirectory;
 use tauri::{AppHandle, Manager};
 use zip::ZipArchive;
 
 use crate::error::SJMCLResult;
 use crate::instance::helpers::client_json::{LibrariesValue, McClientInfo};
 use crate::instance::helpers::loader::fabric::install_fabric_loader;
-use crate::instance::helpers::loader::forge::{install_forge_loader, InstallProfile};
+use crate::instance::helpers::loader::forge::{
+  install_cleanroom_loader, install_forge_loader, InstallProfile, ProcessorsValue,
+};
+use crate::instance::helpers::loader::liteloader::install_liteloader_loader;
 use crate::instance::helpers::loader::neoforge::install_neoforge_loader;
+use crate::instance::helpers::loader::quilt::install_quilt_loader;
 use crate::instance::helpers::misc::get_instance_game_config;
 use crate::instance::models::misc::{Instance, InstanceError, ModLoader, ModLoaderType};
 use crate::launch::helpers::file_validator::merge_library_lists;
@@ -18,6 +24,7 @@ use crate::launch::helpers::jre_selector::select_java_runtime;
 use crate::launcher_config::models::JavaInfo;
 use crate::resource::models::SourceType;
 use crate::tasks::PTaskParam;
+use crate::utils::fs::calculate_sha1;
 
 pub fn add_library_entry(
   libraries: &mut Vec<LibrariesValue>,
@@ -61,13 +68,76 @@ pub async fn install_mod_loader(
     ModLoaderType::Forge => {
       install_forge_loader(priority, game_version, loader, lib_dir.clone(), task_params).await
     }
+    ModLoaderType::Cleanroom => {
+      install_cleanroom_loader(priority, loader, lib_dir, task_params).await
+    }
     ModLoaderType::NeoForge => {
       install_neoforge_loader(priority, loader, lib_dir, task_params).await
     }
+    ModLoaderType::LiteLoader => {
+      install_liteloader_loader(
+        &app,
+        priority,
+        game_version,
+        loader,
+        lib_dir,
+        client_info,
+        task_params,
+      )
+      .await
+    }
+    ModLoaderType::Quilt => {
+      install_quilt_loader(
+        app,
+        priority,
+        game_version,
+        loader,
+        lib_dir,
+        mods_dir,
+        client_info,
+        task_params,
+        is_install_fabric_api,
+      )
+      .await
+    }
     _ => Err(InstanceError::UnsupportedModLoader.into()),
   }
 }
 
+// `outputs` map a file to its sha1, both may refer to the `data` entries and literals are quoted.
+pub fn resolve_processor_outputs(
+  outputs: &HashMap<String, String>,
+  args_map: &HashMap<String, String>,
+) -> HashMap<String, String> {
+  let resolve = |value: &str| {
+    let mut value = value.to_string();
+    for (key, replacement) in args_map {
+      value = value.replace(key, replacement);
+    }
+    value.trim_matches('\'').to_string()
+  };
+  outputs
+    .iter()
+    .map(|(path, sha1)| (resolve(path), resolve(sha1)))
+    .collect()
+}
+
+// a processor can be skipped when it declares outputs and all of them are already valid,
+// e.g. when the loader is repaired or reinstalled.
+fn is_processor_output_valid(processor: &ProcessorsValue) -> bool {
+  let Some(outputs) = &processor.outputs else {
+    return false;
+  };
+  !outputs.is_empty()
+    && outputs.iter().all(|(path, sha1)| {
+      let path = Path::new(path);
+      path.is_file()
+        && calculate_sha1(path)
+          .map(|hash| hash.eq_ignore_ascii_case(sha1))
+          .unwrap_or(false)
+    })
+}
+
 pub async fn execute_processors(
   app: &AppHandle,
   instance: &Instance,
@@ -88,10 +158,30 @@ pub async fn execute_processors(
       .java_version
       .as_ref()
       .map_or(0i32, |v| v.major_version),
+    &client_info.release_time,
   )
   .await?;
 
-  for processor in &install_profile.processors {
+  // the output of all processors in this install, overwritten on the next one
+  let log_path = app.path().resolve::<PathBuf>(
+    format!("install/{}.log", instance.name).into(),
+    BaseDirectory::AppLog,
+  )?;
+  if let Some(parent_dir) = log_path.parent() {
+    fs::create_dir_all(parent_dir)?;
+  }
+  let mut log_file = File::create(&log_path)?;
+
+  for (index, processor) in install_profile.processors.iter().enumerate() {
+    if is_processor_output_valid(processor) {
+      writeln!(
+        log_file,
+        "[{}] {} skipped, outputs are up to date",
+        index, processor.jar
+      )?;
+      continue;
+    }
+
     let mut archive = ZipArchive::new(File::open(processor.jar.clone())?)?;
     let mut manifest = archive.by_name("META-INF/MANIFEST.MF")?;
     let mut manifest_content = String::new();
@@ -130,13 +220,28 @@ pub async fn execute_processors(
       cmd_base.arg(arg);
     }
 
+    writeln!(log_file, "[{}] {} {}", index, main_class, args.join(" "))?;
     let output = cmd_base.output()?;
+    log_file.write_all(&output.stdout)?;
+    log_file.write_all(&output.stderr)?;
 
     if !output.status.success() {
-      eprintln!(
-        "[{}] Processor failed with exit code: {:?}",
+      // prefer the exception thrown by the processor, otherwise its last words
+      let stderr = String::from_utf8_lossy(&output.stderr);
+      let cause = stderr
+        .lines()
+        .find(|line| line.contains("Exception") || line.contains("Error:"))
+        .or_else(|| stderr.lines().rev().find(|line| !line.trim().is_empty()))
+        .unwrap_or_default()
+        .trim()
+        .to_string();
+      log::error!(
+        "[{}] Processor {} failed with exit code {:?}: {}, see {}",
         instance.name,
-        output.status.code()
+        main_class,
+        output.status.code(),
+        cause,
+        log_path.display()
       );
       return Err(InstanceError::ProcessorExecutionFailed.into());
     }
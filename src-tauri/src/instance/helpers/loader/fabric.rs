@@ -11,7 +11,9 @@ use crate::instance::helpers::client_json::McClientInfo;
 use crate::instance::helpers::loader::common::add_library_entry;
 use crate::instance::models::misc::ModLoader;
 use crate::launch::helpers::file_validator::convert_library_name_to_path;
-use crate::resource::helpers::misc::{convert_url_to_target_source, get_download_api};
+use crate::resource::helpers::misc::{
+  convert_url_to_target_source, get_download_api_for, get_first_source_for,
+};
 use crate::resource::helpers::modrinth::get_latest_fabric_api_mod_download;
 use crate::resource::models::{ResourceType, SourceType};
 use crate::tasks::download::DownloadParam;
@@ -32,7 +34,7 @@ pub async fn install_fabric_loader(
   let client = app.state::<reqwest::Client>();
   let loader_ver = &loader.version;
 
-  let meta_url = get_download_api(priority[0], ResourceType::FabricMeta)?
+  let meta_url = get_download_api_for(priority, ResourceType::FabricMeta)?
     .join(&format!("v2/versions/loader/{game_version}/{loader_ver}"))?;
 
   let meta: serde_json::Value = client.get(meta_url).send().await?.json().await?;
@@ -58,7 +60,7 @@ pub async fn install_fabric_loader(
     ..Default::default()
   };
 
-  let maven_root = get_download_api(priority[0], ResourceType::FabricMaven)?;
+  let maven_root = get_download_api_for(priority, ResourceType::FabricMaven)?;
 
   add_library_entry(&mut client_info.libraries, loader_path, None)?;
   add_library_entry(&mut client_info.libraries, int_path, None)?;
@@ -83,13 +85,15 @@ pub async fn install_fabric_loader(
     let src = convert_url_to_target_source(
       &Url::parse(url_root)?.join(&rel)?,
       &[ResourceType::FabricMaven, ResourceType::Libraries],
-      &priority[0],
+      &get_first_source_for(priority, ResourceType::FabricMaven),
     )?;
     task_params.push(PTaskParam::Download(DownloadParam {
       src,
       dest: lib_dir.join(&rel),
       filename: None,
       sha1: None,
+      alt_srcs: vec![],
+      served_src: None,
     }));
     Ok(())
   };
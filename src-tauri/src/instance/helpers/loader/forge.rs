@@ -11,12 +11,19 @@ use url::Url;
 use zip::ZipArchive;
 
 use crate::error::SJMCLResult;
-use crate::instance::helpers::client_json::{LaunchArgumentTemplate, LibrariesValue, McClientInfo};
-use crate::instance::helpers::loader::common::add_library_entry;
+use crate::instance::helpers::client_json::{
+  JavaVersion, LaunchArgumentTemplate, LibrariesValue, McClientInfo,
+};
+use crate::instance::helpers::loader::common::{add_library_entry, resolve_processor_outputs};
+use crate::instance::helpers::loader::liteloader::apply_liteloader_tweaker;
 use crate::instance::helpers::misc::get_instance_subdir_paths;
-use crate::instance::models::misc::{Instance, InstanceError, InstanceSubdirType, ModLoader};
+use crate::instance::models::misc::{
+  Instance, InstanceError, InstanceSubdirType, ModLoader, ModLoaderType,
+};
 use crate::launch::helpers::file_validator::convert_library_name_to_path;
-use crate::resource::helpers::misc::{convert_url_to_target_source, get_download_api};
+use crate::resource::helpers::misc::{
+  convert_url_to_target_source, get_download_api, get_download_api_for, get_first_source_for,
+};
 use crate::resource::models::{ResourceType, SourceType};
 use crate::tasks::commands::schedule_progressive_task_group;
 use crate::tasks::download::DownloadParam;
@@ -55,10 +62,12 @@ pub async fn install_forge_loader(
 ) -> SJMCLResult<()> {
   let loader_ver = &loader.version;
 
-  let root = get_download_api(priority[0], ResourceType::ForgeInstall)?;
+  let source = get_first_source_for(priority, ResourceType::ForgeInstall);
+  let root = get_download_api(source, ResourceType::ForgeInstall)?;
 
-  let installer_url = match priority.first().unwrap_or(&SourceType::Official) {
-    SourceType::Official => {
+  let installer_url = match source {
+    // custom sources follow the official layout
+    SourceType::Official | SourceType::Custom(_) => {
       let full_ver = vec![
         game_version,
         loader_ver,
@@ -86,11 +95,44 @@ pub async fn install_forge_loader(
     dest: installer_path.clone(),
     filename: None,
     sha1: None,
+    alt_srcs: vec![],
+    served_src: None,
   }));
 
   Ok(())
 }
 
+pub async fn install_cleanroom_loader(
+  priority: &[SourceType],
+  loader: &ModLoader,
+  lib_dir: PathBuf,
+  task_params: &mut Vec<PTaskParam>,
+) -> SJMCLResult<()> {
+  let installer_coord = format!("com.cleanroommc:cleanroom:{}-installer", loader.version);
+  let installer_rel = convert_library_name_to_path(&installer_coord, None)?;
+  let installer_url =
+    get_download_api_for(priority, ResourceType::CleanroomMaven)?.join(&installer_rel)?;
+
+  task_params.push(PTaskParam::Download(DownloadParam {
+    src: installer_url,
+    dest: lib_dir.join(&installer_rel),
+    filename: None,
+    sha1: None,
+    alt_srcs: vec![],
+    served_src: None,
+  }));
+
+  Ok(())
+}
+
+// Cleanroom is a fork of Forge shipping the same installer format under its own maven coordinate
+fn get_installer_artifact(loader_type: &ModLoaderType) -> (&'static str, &'static str) {
+  match loader_type {
+    ModLoaderType::Cleanroom => ("com.cleanroommc", "cleanroom"),
+    _ => ("net.minecraftforge", "forge"),
+  }
+}
+
 pub async fn download_forge_libraries(
   app: &AppHandle,
   priority: &[SourceType],
@@ -108,15 +150,16 @@ pub async fn download_forge_libraries(
   };
   let mut task_params = vec![];
 
+  let (group, artifact) = get_installer_artifact(&instance.mod_loader.loader_type);
   let installer_coord = format!(
-    "net.minecraftforge:forge:{}-installer",
+    "{group}:{artifact}:{}-installer",
     instance.mod_loader.version
   );
   let installer_rel = convert_library_name_to_path(&installer_coord, None)?;
   let installer_path = lib_dir.join(&installer_rel);
   let bin_patch = lib_dir.join(convert_library_name_to_path(
     &format!(
-      "net.minecraftforge:forge:{}:clientdata@lzma",
+      "{group}:{artifact}:{}:clientdata@lzma",
       instance.mod_loader.version
     ),
     None,
@@ -222,6 +265,8 @@ pub async fn download_forge_libraries(
               dest: lib_dir.join(mojmaps),
               filename: None,
               sha1: Some(client_mappings.sha1.clone()),
+              alt_srcs: vec![],
+              served_src: None,
             }));
           }
         }
@@ -256,6 +301,10 @@ pub async fn download_forge_libraries(
           *arg = arg.replace(key, value);
         }
       }
+
+      if let Some(outputs) = &processor.outputs {
+        processor.outputs = Some(resolve_processor_outputs(outputs, &args_map));
+      }
     }
 
     profile.processors.retain(|processor| {
@@ -296,11 +345,13 @@ pub async fn download_forge_libraries(
             ResourceType::ForgeMavenNew,
             ResourceType::Libraries,
           ],
-          &priority[0],
+          &get_first_source_for(priority, ResourceType::ForgeMaven),
         )?,
         dest: lib_dir.join(&convert_library_name_to_path(name, None)?),
         filename: None,
         sha1: None,
+        alt_srcs: vec![],
+        served_src: None,
       }));
     }
 
@@ -319,8 +370,17 @@ pub async fn download_forge_libraries(
     };
     client_info.arguments = arguments.clone();
     client_info.minecraft_arguments = minecraft_arguments.clone();
+    // Cleanroom runs on a modern Java, unlike the vanilla 1.12.2 it is installed on
+    if let Some(java_version) = forge_info.java_version.clone() {
+      client_info.java_version = Some(java_version);
+    } else if instance.mod_loader.loader_type == ModLoaderType::Cleanroom {
+      client_info.java_version = Some(JavaVersion {
+        component: "java-runtime-delta".to_string(),
+        major_version: 21,
+      });
+    }
     client_info.patches.push(McClientInfo {
-      id: "forge".to_string(),
+      id: artifact.to_string(),
       version: Some(forge_info.id.clone()),
       priority: Some(30000),
       inherits_from: forge_info.inherits_from.clone(),
@@ -352,11 +412,13 @@ pub async fn download_forge_libraries(
             ResourceType::ForgeMavenNew,
             ResourceType::Libraries,
           ],
-          &priority[0],
+          &get_first_source_for(priority, ResourceType::ForgeMaven),
         )?,
         dest: lib_dir.join(&rel),
         filename: None,
         sha1: None,
+        alt_srcs: vec![],
+        served_src: None,
       }));
     }
   } else {
@@ -371,7 +433,7 @@ pub async fn download_forge_libraries(
     client_info.main_class = Some(main_class.clone());
 
     let mut new_patch = McClientInfo {
-      id: "forge".to_string(),
+      id: artifact.to_string(),
       version: Some(instance.mod_loader.version.clone()),
       priority: Some(30000),
       main_class: Some(main_class.to_string()),
@@ -409,7 +471,7 @@ pub async fn download_forge_libraries(
       }
 
       let url = if lib.url.is_none() {
-        get_download_api(priority[0], ResourceType::Libraries)?
+        get_download_api_for(priority, ResourceType::Libraries)?
       } else {
         Url::parse(&lib.url.clone().unwrap())?
       };
@@ -422,18 +484,23 @@ pub async fn download_forge_libraries(
           ResourceType::ForgeMavenNew,
           ResourceType::Libraries,
         ],
-        &priority[0],
+        &get_first_source_for(priority, ResourceType::ForgeMaven),
       )?;
       task_params.push(PTaskParam::Download(DownloadParam {
         src,
         dest: lib_dir.join(&rel),
         filename: None,
         sha1: None,
+        alt_srcs: vec![],
+        served_src: None,
       }));
     }
     client_info.patches.push(new_patch);
   }
 
+  // LiteLoader installed on top of Forge loses its tweaker when the arguments above are replaced
+  apply_liteloader_tweaker(client_info);
+
   let mut seen = std::collections::HashSet::new();
   task_params.retain(|param| match param {
     PTaskParam::Download(dp) => seen.insert(dp.dest.clone()),
@@ -441,7 +508,7 @@ pub async fn download_forge_libraries(
 
   schedule_progressive_task_group(
     app.clone(),
-    format!("forge-libraries?{}", instance.id),
+    format!("{artifact}-libraries?{}", instance.id),
     task_params,
     true,
   )
@@ -0,0 +1,123 @@
+use std::path::PathBuf;
+use tauri::AppHandle;
+use url::Url;
+
+use crate::error::SJMCLResult;
+use crate::instance::helpers::client_json::McClientInfo;
+use crate::instance::helpers::loader::common::add_library_entry;
+use crate::instance::models::misc::ModLoader;
+use crate::launch::helpers::file_validator::convert_library_name_to_path;
+use crate::resource::helpers::loader_meta::liteloader::get_liteloader_artefact;
+use crate::resource::helpers::misc::{
+  convert_url_to_target_source, get_download_api_for, get_first_source_for,
+};
+use crate::resource::models::{ResourceType, SourceType};
+use crate::tasks::download::DownloadParam;
+use crate::tasks::PTaskParam;
+
+const LITELOADER_PATCH_ID: &str = "liteloader";
+const LAUNCHWRAPPER_MAIN_CLASS: &str = "net.minecraft.launchwrapper.Launch";
+
+// LiteLoader is a launchwrapper tweaker, on Forge (1.7.10 - 1.12.2) it is loaded by FML's launchwrapper as well.
+pub async fn install_liteloader_loader(
+  app: &AppHandle,
+  priority: &[SourceType],
+  game_version: &str,
+  loader: &ModLoader,
+  lib_dir: PathBuf,
+  client_info: &mut McClientInfo,
+  task_params: &mut Vec<PTaskParam>,
+) -> SJMCLResult<()> {
+  let artefact = get_liteloader_artefact(app, priority, game_version, &loader.version).await?;
+
+  let mut new_patch = McClientInfo {
+    id: LITELOADER_PATCH_ID.to_string(),
+    version: Some(loader.version.clone()),
+    priority: Some(30001),
+    main_class: Some(LAUNCHWRAPPER_MAIN_CLASS.to_string()),
+    minecraft_arguments: Some(format!("--tweakClass {}", artefact.tweak_class)),
+    ..Default::default()
+  };
+
+  // releases are stored by game version, snapshots by their own version
+  let liteloader_coord = format!("com.mumfrey:liteloader:{}", artefact.version);
+  let liteloader_rel = convert_library_name_to_path(&liteloader_coord, None)?;
+  let liteloader_dir = if artefact.is_snapshot() {
+    &artefact.version
+  } else {
+    game_version
+  };
+  add_library_entry(&mut client_info.libraries, &liteloader_coord, None)?;
+  add_library_entry(&mut new_patch.libraries, &liteloader_coord, None)?;
+  let mut liteloader_url = Url::parse(&artefact.repo_url)?.join(&format!(
+    "com/mumfrey/liteloader/{}/{}",
+    liteloader_dir, artefact.file
+  ))?;
+  // the repo in the meta is the official one over plain http, which is not matched by the api
+  if liteloader_url.scheme() == "http" {
+    let _ = liteloader_url.set_scheme("https");
+  }
+  task_params.push(PTaskParam::Download(DownloadParam {
+    src: convert_url_to_target_source(
+      &liteloader_url,
+      &[ResourceType::LiteloaderMaven],
+      &get_first_source_for(priority, ResourceType::LiteloaderMaven),
+    )?,
+    dest: lib_dir.join(&liteloader_rel),
+    filename: None,
+    sha1: None,
+    alt_srcs: vec![],
+    served_src: None,
+  }));
+
+  let libraries_root = get_download_api_for(priority, ResourceType::Libraries)?;
+  for library in &artefact.libraries {
+    add_library_entry(&mut client_info.libraries, &library.name, None)?;
+    add_library_entry(&mut new_patch.libraries, &library.name, None)?;
+
+    let rel = convert_library_name_to_path(&library.name, None)?;
+    let url_root = match &library.url {
+      Some(url) => Url::parse(url)?,
+      None => libraries_root.clone(),
+    };
+    task_params.push(PTaskParam::Download(DownloadParam {
+      src: convert_url_to_target_source(
+        &url_root.join(&rel)?,
+        &[ResourceType::Libraries],
+        &get_first_source_for(priority, ResourceType::Libraries),
+      )?,
+      dest: lib_dir.join(&rel),
+      filename: None,
+      sha1: None,
+      alt_srcs: vec![],
+      served_src: None,
+    }));
+  }
+
+  client_info.patches.push(new_patch);
+  apply_liteloader_tweaker(client_info);
+
+  Ok(())
+}
+
+// add the tweaker of the installed LiteLoader patch to the launch arguments,
+// called again after Forge is installed since it overwrites the arguments.
+pub fn apply_liteloader_tweaker(client_info: &mut McClientInfo) {
+  let Some(tweaker_args) = client_info
+    .patches
+    .iter()
+    .find(|patch| patch.id == LITELOADER_PATCH_ID)
+    .and_then(|patch| patch.minecraft_arguments.clone())
+  else {
+    return;
+  };
+
+  client_info.main_class = Some(LAUNCHWRAPPER_MAIN_CLASS.to_string());
+  let args = client_info.minecraft_arguments.get_or_insert_default();
+  if !args.contains(&tweaker_args) {
+    if !args.is_empty() {
+      args.push(' ');
+    }
+    args.push_str(&tweaker_args);
+  }
+}
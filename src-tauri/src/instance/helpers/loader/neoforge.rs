@@ -8,12 +8,14 @@ use zip::ZipArchive;
 
 use crate::error::SJMCLResult;
 use crate::instance::helpers::client_json::{LaunchArgumentTemplate, McClientInfo};
-use crate::instance::helpers::loader::common::add_library_entry;
+use crate::instance::helpers::loader::common::{add_library_entry, resolve_processor_outputs};
 use crate::instance::helpers::loader::forge::InstallProfile;
 use crate::instance::helpers::misc::get_instance_subdir_paths;
 use crate::instance::models::misc::{Instance, InstanceError, InstanceSubdirType, ModLoader};
 use crate::launch::helpers::file_validator::convert_library_name_to_path;
-use crate::resource::helpers::misc::{convert_url_to_target_source, get_download_api};
+use crate::resource::helpers::misc::{
+  convert_url_to_target_source, get_download_api, get_first_source_for,
+};
 use crate::resource::models::{ResourceType, SourceType};
 use crate::tasks::commands::schedule_progressive_task_group;
 use crate::tasks::download::DownloadParam;
@@ -36,10 +38,12 @@ pub async fn install_neoforge_loader(
       format!("net.neoforged:forge:{}-installer", loader.version),
     )
   } else {
-    let root = get_download_api(priority[0], ResourceType::NeoforgeInstall)?;
+    let source = get_first_source_for(priority, ResourceType::NeoforgeInstall);
+    let root = get_download_api(source, ResourceType::NeoforgeInstall)?;
     (
-      match priority.first().unwrap_or(&SourceType::Official) {
-        SourceType::Official => {
+      match source {
+        // custom sources follow the official layout
+        SourceType::Official | SourceType::Custom(_) => {
           let path = format!(
             "net/neoforged/neoforge/{v}/neoforge-{v}-installer.jar",
             v = loader_ver
@@ -63,6 +67,8 @@ pub async fn install_neoforge_loader(
     dest: installer_path.clone(),
     filename: None,
     sha1: None,
+    alt_srcs: vec![],
+    served_src: None,
   }));
 
   Ok(())
@@ -202,6 +208,8 @@ pub async fn download_neoforge_libraries(
             dest: lib_dir.join(mojmaps),
             filename: None,
             sha1: Some(client_mappings.sha1.clone()),
+            alt_srcs: vec![],
+            served_src: None,
           }));
         }
       }
@@ -236,6 +244,10 @@ pub async fn download_neoforge_libraries(
         *arg = arg.replace(key, value);
       }
     }
+
+    if let Some(outputs) = &processor.outputs {
+      processor.outputs = Some(resolve_processor_outputs(outputs, &args_map));
+    }
   }
 
   profile.processors.retain(|processor| {
@@ -272,11 +284,13 @@ pub async fn download_neoforge_libraries(
       src: convert_url_to_target_source(
         &Url::parse(url)?,
         &[ResourceType::NeoforgeMaven, ResourceType::Libraries],
-        &priority[0],
+        &get_first_source_for(priority, ResourceType::NeoforgeMaven),
       )?,
       dest: lib_dir.join(&convert_library_name_to_path(name, None)?),
       filename: None,
       sha1: None,
+      alt_srcs: vec![],
+      served_src: None,
     }));
   }
 
@@ -320,11 +334,13 @@ pub async fn download_neoforge_libraries(
       src: convert_url_to_target_source(
         &Url::parse(url)?,
         &[ResourceType::NeoforgeMaven, ResourceType::Libraries],
-        &priority[0],
+        &get_first_source_for(priority, ResourceType::NeoforgeMaven),
       )?,
       dest: lib_dir.join(&rel),
       filename: None,
       sha1: None,
+      alt_srcs: vec![],
+      served_src: None,
     }));
   }
 
@@ -9,7 +9,9 @@ use crate::launch::helpers::jre_selector::select_java_runtime;
 use crate::launcher_config::models::JavaInfo;
 use crate::launcher_config::models::LauncherConfig;
 use crate::resource::helpers::misc::get_source_priority_list;
-use crate::resource::helpers::misc::{convert_url_to_target_source, get_download_api};
+use crate::resource::helpers::misc::{
+  convert_url_to_target_source, get_download_api, get_download_api_for, get_first_source_for,
+};
 use crate::resource::models::{OptiFineResourceInfo, ResourceType, SourceType};
 use crate::tasks::commands::schedule_progressive_task_group;
 use crate::tasks::download::DownloadParam;
@@ -22,6 +24,10 @@ use std::sync::Mutex;
 use tauri::{AppHandle, Manager};
 use zip::{write::FileOptions, ZipArchive, ZipWriter};
 
+const LAUNCHWRAPPER_MAIN_CLASS: &str = "net.minecraft.launchwrapper.Launch";
+// followed by the file name of OptiFine, see `download_optifine_libraries`
+const OPTIFINE_RUNTIME_PREFIX: &str = "net.minecraftforge:optifine:";
+
 pub async fn download_optifine_installer(
   game_version: &str,
   optifine: &OptiFineResourceInfo,
@@ -47,6 +53,8 @@ pub async fn download_optifine_installer(
     dest: installer_path.clone(),
     filename: None,
     sha1: None,
+    alt_srcs: vec![],
+    served_src: None,
   }));
 
   Ok(())
@@ -149,11 +157,11 @@ async fn download_optifine_libraries(
     let lw_rel = convert_library_name_to_path(&lw_coord, None)?;
     let lw_dest = lib_dir.join(&lw_rel);
 
-    let base = get_download_api(priority[0], ResourceType::Libraries)?;
+    let base = get_download_api_for(priority, ResourceType::Libraries)?;
     let src = convert_url_to_target_source(
       &base.join(&lw_rel)?,
       &[ResourceType::Libraries],
-      &priority[0],
+      &get_first_source_for(priority, ResourceType::Libraries),
     )?;
 
     task_params.push(PTaskParam::Download(DownloadParam {
@@ -161,12 +169,14 @@ async fn download_optifine_libraries(
       dest: lw_dest,
       filename: None,
       sha1: None,
+      alt_srcs: vec![],
+      served_src: None,
     }));
   }
 
   let optifine_runtime_coord = format!("net.minecraftforge:optifine:{}", optifine.filename);
   add_library_entry(&mut client_info.libraries, &optifine_runtime_coord, None)?;
-  let lw_main = "net.minecraft.launchwrapper.Launch".to_string();
+  let lw_main = LAUNCHWRAPPER_MAIN_CLASS.to_string();
 
   if let Some(v_args) = client_info.arguments.clone() {
     let mut g: Vec<ArgumentsItem> = v_args.game.clone();
@@ -304,6 +314,7 @@ async fn run_optifine_patcher(
       .as_ref()
       .ok_or(InstanceError::ProcessorExecutionFailed)?
       .major_version,
+    &client_info.release_time,
   )
   .await?;
 
@@ -394,6 +405,93 @@ pub async fn finish_optifine_install(
   Ok(())
 }
 
+// `net.minecraft:launchwrapper`, or the one shipped in the OptiFine installer
+fn is_launchwrapper_library(name: &str) -> bool {
+  name.starts_with("net.minecraft:launchwrapper:") || name.starts_with("optifine:launchwrapper")
+}
+
+// revert what `download_optifine_libraries` added to the version json, keeping the mod loader.
+pub fn remove_optifine_patch(client_info: &mut McClientInfo) {
+  let Some(pos) = client_info
+    .patches
+    .iter()
+    .position(|patch| patch.id == "optifine")
+  else {
+    return;
+  };
+  let patch = client_info.patches.remove(pos);
+
+  // the patch also lists the launchwrapper, which Forge or LiteLoader may still launch with
+  let launchwrapper_needed = client_info.patches.iter().any(|patch| {
+    patch.main_class.as_deref() == Some(LAUNCHWRAPPER_MAIN_CLASS)
+      || patch
+        .libraries
+        .iter()
+        .any(|library| is_launchwrapper_library(&library.name))
+  });
+  let removed: Vec<&str> = patch
+    .libraries
+    .iter()
+    .map(|library| library.name.as_str())
+    .filter(|name| {
+      name.starts_with(OPTIFINE_RUNTIME_PREFIX)
+        || (!launchwrapper_needed && is_launchwrapper_library(name))
+    })
+    .collect();
+  client_info
+    .libraries
+    .retain(|library| !removed.contains(&library.name.as_str()));
+
+  // the tweaker may be `OptiFineTweaker` or `OptiFineForgeTweaker`, drop it with its flag
+  if let Some(args) = client_info.arguments.as_mut() {
+    let mut game = Vec::with_capacity(args.game.len());
+    let mut iter = args.game.drain(..).peekable();
+    while let Some(item) = iter.next() {
+      let is_tweak_flag = item.value.first().is_some_and(|v| v == "--tweakClass");
+      let is_optifine_next = iter
+        .peek()
+        .and_then(|next| next.value.first())
+        .is_some_and(|v| v.starts_with("optifine."));
+      if is_tweak_flag && is_optifine_next {
+        iter.next();
+        continue;
+      }
+      game.push(item);
+    }
+    args.game = game;
+  }
+  if let Some(args) = client_info.minecraft_arguments.as_mut() {
+    let tokens: Vec<&str> = args.split_whitespace().collect();
+    let mut kept: Vec<&str> = Vec::with_capacity(tokens.len());
+    let mut i = 0;
+    while i < tokens.len() {
+      if tokens[i] == "--tweakClass"
+        && tokens
+          .get(i + 1)
+          .is_some_and(|v| v.starts_with("optifine."))
+      {
+        i += 2;
+        continue;
+      }
+      kept.push(tokens[i]);
+      i += 1;
+    }
+    *args = kept.join(" ");
+  }
+
+  // launchwrapper was only set in place of the vanilla main class, take it from the topmost remaining patch
+  if client_info.main_class.as_deref() == Some(LAUNCHWRAPPER_MAIN_CLASS) {
+    if let Some(main_class) = client_info
+      .patches
+      .iter()
+      .rev()
+      .find_map(|patch| patch.main_class.clone())
+    {
+      client_info.main_class = Some(main_class);
+    }
+  }
+}
+
 fn remove_entry_from_zip(zip_path: &Path, entry_name: &str) -> SJMCLResult<()> {
   if !zip_path.exists() {
     return Ok(());
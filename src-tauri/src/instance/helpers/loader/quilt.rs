@@ -0,0 +1,125 @@
+use serde::Deserialize;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_http::reqwest;
+use url::Url;
+
+use crate::error::SJMCLResult;
+use crate::instance::helpers::client_json::{LaunchArgumentTemplate, McClientInfo};
+use crate::instance::helpers::loader::common::add_library_entry;
+use crate::instance::models::misc::{InstanceError, ModLoader};
+use crate::launch::helpers::file_validator::convert_library_name_to_path;
+use crate::resource::helpers::misc::{
+  convert_url_to_target_source, get_download_api_for, get_first_source_for,
+};
+use crate::resource::helpers::modrinth::get_latest_quilted_fabric_api_mod_download;
+use crate::resource::models::{ResourceType, SourceType};
+use crate::tasks::download::DownloadParam;
+use crate::tasks::PTaskParam;
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct QuiltProfile {
+  pub main_class: String,
+  pub arguments: Option<LaunchArgumentTemplate>,
+  pub libraries: Vec<QuiltProfileLibrary>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct QuiltProfileLibrary {
+  pub name: String,
+  pub url: Option<String>,
+}
+
+pub async fn install_quilt_loader(
+  app: AppHandle,
+  priority: &[SourceType],
+  game_version: &str,
+  loader: &ModLoader,
+  lib_dir: PathBuf,
+  mods_dir: PathBuf,
+  client_info: &mut McClientInfo,
+  task_params: &mut Vec<PTaskParam>,
+  is_install_fabric_api: Option<bool>,
+) -> SJMCLResult<()> {
+  let client = app.state::<reqwest::Client>();
+  let loader_ver = &loader.version;
+
+  // the profile is a version json inheriting from the vanilla one
+  let profile_url = get_download_api_for(priority, ResourceType::QuiltMeta)?.join(&format!(
+    "v3/versions/loader/{game_version}/{loader_ver}/profile/json"
+  ))?;
+
+  let profile: QuiltProfile = client
+    .get(profile_url)
+    .send()
+    .await
+    .map_err(|_| InstanceError::NetworkError)?
+    .json()
+    .await
+    .map_err(|_| InstanceError::ClientJsonParseError)?;
+
+  if profile.main_class.is_empty() {
+    return Err(InstanceError::ClientJsonParseError.into());
+  }
+  client_info.main_class = Some(profile.main_class.clone());
+
+  let mut new_patch = McClientInfo {
+    id: "quilt".to_string(),
+    version: Some(loader_ver.to_string()),
+    priority: Some(30000),
+    main_class: Some(profile.main_class.clone()),
+    arguments: profile.arguments.clone(),
+    ..Default::default()
+  };
+
+  // only merge the arguments into versions using the new argument format
+  if let (Some(args), Some(quilt_args)) = (client_info.arguments.as_mut(), &profile.arguments) {
+    args.game.extend(quilt_args.game.iter().cloned());
+    args.jvm.extend(quilt_args.jvm.iter().cloned());
+  }
+
+  let maven_root = get_download_api_for(priority, ResourceType::QuiltMaven)?;
+
+  for library in &profile.libraries {
+    add_library_entry(&mut client_info.libraries, &library.name, None)?;
+    add_library_entry(&mut new_patch.libraries, &library.name, None)?;
+
+    let rel = convert_library_name_to_path(&library.name, None)?;
+    let url_root = match &library.url {
+      Some(url) => Url::parse(url)?,
+      None => maven_root.clone(),
+    };
+    let src = convert_url_to_target_source(
+      &url_root.join(&rel)?,
+      &[
+        ResourceType::QuiltMaven,
+        ResourceType::FabricMaven,
+        ResourceType::Libraries,
+      ],
+      &get_first_source_for(priority, ResourceType::QuiltMaven),
+    )?;
+    task_params.push(PTaskParam::Download(DownloadParam {
+      src,
+      dest: lib_dir.join(&rel),
+      filename: None,
+      sha1: None,
+      alt_srcs: vec![],
+      served_src: None,
+    }));
+  }
+
+  client_info.patches.push(new_patch);
+
+  // Download Quilted Fabric API mod, which replaces Fabric API on quilt
+  if is_install_fabric_api.unwrap_or(true) {
+    if let Ok(Some(fabric_api_download)) =
+      get_latest_quilted_fabric_api_mod_download(&app, game_version, mods_dir).await
+    {
+      task_params.push(PTaskParam::Download(fabric_api_download));
+    }
+  }
+
+  Ok(())
+}
@@ -188,7 +188,7 @@ pub async fn refresh_instances(
         match cfg_read.mod_loader.status {
           ModLoaderStatus::NotDownloaded => {
             match cfg_read.mod_loader.loader_type {
-              ModLoaderType::Forge => {
+              ModLoaderType::Forge | ModLoaderType::Cleanroom => {
                 cfg_read.mod_loader.status = ModLoaderStatus::Downloading;
                 download_forge_libraries(app, &priority_list, &cfg_read, &mut client_data).await?;
               }
@@ -207,7 +207,7 @@ pub async fn refresh_instances(
             Ok(())
           }
           ModLoaderStatus::DownloadFailed => match cfg_read.mod_loader.loader_type {
-            ModLoaderType::Forge => {
+            ModLoaderType::Forge | ModLoaderType::Cleanroom => {
               cfg_read.mod_loader.status = ModLoaderStatus::Downloading;
               download_forge_libraries(app, &priority_list, &cfg_read, &mut client_data).await
             }
@@ -11,6 +11,7 @@ use zip::ZipArchive;
 
 use crate::error::{SJMCLError, SJMCLResult};
 use crate::instance::helpers::modpack::misc::{ModpackManifest, ModpackMetaInfo};
+use crate::instance::helpers::modpack::update::InstalledModpack;
 use crate::instance::models::misc::{InstanceError, ModLoader, ModLoaderType};
 use crate::resource::helpers::curseforge::misc::CurseForgeProject;
 use crate::resource::models::OtherResourceSource;
@@ -108,6 +109,7 @@ impl ModpackManifest for CurseForgeManifest {
       modpack_source: OtherResourceSource::CurseForge,
       client_version,
       mod_loader,
+      optional_files: Vec::new(),
     })
   }
 
@@ -206,6 +208,8 @@ impl ModpackManifest for CurseForgeManifest {
             })
             .join(&file_manifest.data.file_name),
           filename: Some(file_manifest.data.file_name.clone()),
+          alt_srcs: vec![],
+          served_src: None,
         });
 
         Ok::<PTaskParam, SJMCLError>(task_param)
@@ -224,4 +228,13 @@ impl ModpackManifest for CurseForgeManifest {
   fn get_overrides_path(&self) -> String {
     self.overrides.clone()
   }
+  fn to_installed_modpack(&self) -> SJMCLResult<InstalledModpack> {
+    Ok(InstalledModpack {
+      source: OtherResourceSource::CurseForge,
+      name: self.name.clone(),
+      version: self.version.clone(),
+      manifest: serde_json::to_string(self)?,
+      ..Default::default()
+    })
+  }
 }
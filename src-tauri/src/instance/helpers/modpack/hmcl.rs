@@ -0,0 +1,293 @@
+// HMCL modpacks: `modpack.json` with the `minecraft/` overrides, and the server-side update
+// variant `server-manifest.json` whose files are served by `fileApi`.
+use crate::error::SJMCLResult;
+use crate::instance::helpers::modpack::misc::{
+  is_safe_relative_path, ModpackManifest, ModpackMetaInfo,
+};
+use crate::instance::helpers::modpack::update::InstalledModpack;
+use crate::instance::models::misc::{InstanceError, ModLoader, ModLoaderType};
+use crate::resource::models::OtherResourceSource;
+use crate::tasks::download::DownloadParam;
+use crate::tasks::PTaskParam;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+use tauri::AppHandle;
+use url::Url;
+use zip::ZipArchive;
+
+const HMCL_OVERRIDES_PATH: &str = "minecraft";
+const HMCL_SERVER_OVERRIDES_PATH: &str = "overrides";
+
+// `game` for the client version, others are mod loaders or optifine
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HmclAddon {
+  pub id: String,
+  pub version: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HmclFileInfo {
+  pub path: String,
+  // sha1
+  pub hash: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct HmclManifest {
+  pub name: String,
+  pub author: Option<String>,
+  pub version: String,
+  pub description: Option<String>,
+  pub game_version: Option<String>,
+  pub addons: Vec<HmclAddon>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct HmclServerManifest {
+  pub name: String,
+  pub author: Option<String>,
+  pub version: String,
+  pub description: Option<String>,
+  pub file_api: Option<String>,
+  pub files: Vec<HmclFileInfo>,
+  pub addons: Vec<HmclAddon>,
+  // files shipped in the archive, the others are downloaded from `file_api`
+  #[serde(skip)]
+  pub packed_files: HashSet<String>,
+}
+
+// the version json of the packed instance, used when `modpack.json` has no addons
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+struct HmclPackedVersion {
+  patches: Vec<HmclAddon>,
+}
+
+fn read_archive_file(file: &File, name: &str) -> SJMCLResult<String> {
+  let mut archive = ZipArchive::new(file)?;
+  let mut entry = archive.by_name(name)?;
+  let mut content = String::new();
+  entry.read_to_string(&mut content)?;
+  Ok(content)
+}
+
+fn get_game_version(addons: &[HmclAddon]) -> SJMCLResult<String> {
+  addons
+    .iter()
+    .find(|addon| addon.id == "game")
+    .map(|addon| addon.version.clone())
+    .ok_or(InstanceError::ModpackManifestParseError.into())
+}
+
+fn get_mod_loader(addons: &[HmclAddon]) -> SJMCLResult<(ModLoaderType, String)> {
+  addons
+    .iter()
+    .filter(|addon| addon.id != "game" && addon.id != "optifine")
+    .find_map(|addon| {
+      ModLoaderType::from_str(&addon.id)
+        .ok()
+        .map(|loader_type| (loader_type, addon.version.clone()))
+    })
+    .ok_or(InstanceError::ModpackManifestParseError.into())
+}
+
+async fn build_meta_info(
+  app: &AppHandle,
+  manifest: &(impl ModpackManifest + Sync),
+  name: &str,
+  version: &str,
+  description: &Option<String>,
+  author: &Option<String>,
+) -> SJMCLResult<ModpackMetaInfo> {
+  let client_version = manifest.get_client_version()?;
+  let mod_loader = if let Ok((loader_type, version)) = manifest.get_mod_loader_type_version() {
+    Some(
+      ModLoader {
+        loader_type,
+        version,
+        ..Default::default()
+      }
+      .with_branch(app, client_version.clone())
+      .await?,
+    )
+  } else {
+    None
+  };
+  Ok(ModpackMetaInfo {
+    name: name.to_string(),
+    version: version.to_string(),
+    description: description.clone(),
+    author: author.clone(),
+    modpack_source: OtherResourceSource::Hmcl,
+    client_version,
+    mod_loader,
+    optional_files: Vec::new(),
+  })
+}
+
+#[async_trait]
+impl ModpackManifest for HmclManifest {
+  fn from_archive(file: &File) -> SJMCLResult<Self> {
+    let mut manifest: Self = serde_json::from_str(&read_archive_file(file, "modpack.json")?)?;
+    if manifest.addons.is_empty() {
+      // older packs keep the loaders in the patches of the packed version json
+      let packed_version = read_archive_file(file, &format!("{}/pack.json", HMCL_OVERRIDES_PATH))
+        .ok()
+        .and_then(|content| serde_json::from_str::<HmclPackedVersion>(&content).ok())
+        .unwrap_or_default();
+      manifest.addons = packed_version.patches;
+    }
+    if manifest.game_version.is_none() && get_game_version(&manifest.addons).is_err() {
+      return Err(InstanceError::ModpackManifestParseError.into());
+    }
+    Ok(manifest)
+  }
+
+  async fn get_meta_info(&self, app: &AppHandle) -> SJMCLResult<ModpackMetaInfo> {
+    build_meta_info(
+      app,
+      self,
+      &self.name,
+      &self.version,
+      &self.description,
+      &self.author,
+    )
+    .await
+  }
+
+  fn get_client_version(&self) -> SJMCLResult<String> {
+    match &self.game_version {
+      Some(version) => Ok(version.clone()),
+      None => get_game_version(&self.addons),
+    }
+  }
+
+  fn get_mod_loader_type_version(&self) -> SJMCLResult<(ModLoaderType, String)> {
+    get_mod_loader(&self.addons)
+  }
+
+  async fn get_download_params(
+    &self,
+    _app: &AppHandle,
+    _instance_path: &Path,
+  ) -> SJMCLResult<Vec<PTaskParam>> {
+    // all files are packed in the overrides
+    Ok(Vec::new())
+  }
+
+  fn get_overrides_path(&self) -> String {
+    HMCL_OVERRIDES_PATH.to_string()
+  }
+
+  fn to_installed_modpack(&self) -> SJMCLResult<InstalledModpack> {
+    Ok(InstalledModpack {
+      source: OtherResourceSource::Hmcl,
+      name: self.name.clone(),
+      version: self.version.clone(),
+      manifest: serde_json::to_string(self)?,
+      ..Default::default()
+    })
+  }
+}
+
+#[async_trait]
+impl ModpackManifest for HmclServerManifest {
+  fn from_archive(file: &File) -> SJMCLResult<Self> {
+    let mut manifest: Self =
+      serde_json::from_str(&read_archive_file(file, "server-manifest.json")?)?;
+    get_game_version(&manifest.addons)?;
+
+    let mut archive = ZipArchive::new(file)?;
+    let prefix = format!("{}/", HMCL_SERVER_OVERRIDES_PATH);
+    manifest.packed_files = archive
+      .file_names()
+      .filter_map(|name| name.strip_prefix(&prefix))
+      .map(|name| name.to_string())
+      .collect();
+    Ok(manifest)
+  }
+
+  async fn get_meta_info(&self, app: &AppHandle) -> SJMCLResult<ModpackMetaInfo> {
+    build_meta_info(
+      app,
+      self,
+      &self.name,
+      &self.version,
+      &self.description,
+      &self.author,
+    )
+    .await
+  }
+
+  fn get_client_version(&self) -> SJMCLResult<String> {
+    get_game_version(&self.addons)
+  }
+
+  fn get_mod_loader_type_version(&self) -> SJMCLResult<(ModLoaderType, String)> {
+    get_mod_loader(&self.addons)
+  }
+
+  async fn get_download_params(
+    &self,
+    _app: &AppHandle,
+    instance_path: &Path,
+  ) -> SJMCLResult<Vec<PTaskParam>> {
+    let Some(file_api) = self.file_api.as_ref().filter(|api| !api.is_empty()) else {
+      return Ok(Vec::new());
+    };
+    let base_url = Url::parse(&format!(
+      "{}/{}/",
+      file_api.trim_end_matches('/'),
+      HMCL_SERVER_OVERRIDES_PATH
+    ))
+    .map_err(|_| InstanceError::InvalidSourcePath)?;
+
+    self
+      .files
+      .iter()
+      .filter(|file| !self.packed_files.contains(&file.path))
+      .map(|file| -> SJMCLResult<PTaskParam> {
+        // the manifest comes from the server, its paths must stay in the instance and the file api
+        if !is_safe_relative_path(&file.path) {
+          return Err(InstanceError::InvalidSourcePath.into());
+        }
+        let src = base_url
+          .join(&file.path)
+          .ok()
+          .filter(|src| src.as_str().starts_with(base_url.as_str()))
+          .ok_or(InstanceError::InvalidSourcePath)?;
+        Ok(PTaskParam::Download(DownloadParam {
+          src,
+          dest: instance_path.join(&file.path),
+          filename: None,
+          sha1: Some(file.hash.clone()),
+          alt_srcs: vec![],
+          served_src: None,
+        }))
+      })
+      .collect()
+  }
+
+  fn get_overrides_path(&self) -> String {
+    HMCL_SERVER_OVERRIDES_PATH.to_string()
+  }
+
+  fn to_installed_modpack(&self) -> SJMCLResult<InstalledModpack> {
+    Ok(InstalledModpack {
+      source: OtherResourceSource::Hmcl,
+      name: self.name.clone(),
+      version: self.version.clone(),
+      manifest: serde_json::to_string(self)?,
+      ..Default::default()
+    })
+  }
+}
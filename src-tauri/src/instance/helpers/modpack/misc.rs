@@ -1,16 +1,24 @@
 use crate::error::SJMCLResult;
 use crate::instance::helpers::modpack::curseforge::CurseForgeManifest;
+use crate::instance::helpers::modpack::hmcl::{HmclManifest, HmclServerManifest};
 use crate::instance::helpers::modpack::modrinth::ModrinthManifest;
 use crate::instance::helpers::modpack::multimc::MultiMcManifest;
+use crate::instance::helpers::modpack::packwiz::{is_packwiz_source, PackwizManifest};
+use crate::instance::helpers::modpack::update::{
+  build_file_records, get_relative_path, is_modified_by_user, InstalledModpack,
+};
 use crate::instance::models::misc::{InstanceError, ModLoader, ModLoaderType};
 use crate::resource::commands::fetch_mod_loader_version_list;
 use crate::resource::models::OtherResourceSource;
 use crate::tasks::PTaskParam;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::collections::HashMap;
 use std::fs;
 use std::fs::File;
-use std::path::Path;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
 use tauri::AppHandle;
 use zip::ZipArchive;
 
@@ -28,9 +36,35 @@ pub trait ModpackManifest {
     instance_path: &Path,
   ) -> SJMCLResult<Vec<PTaskParam>>;
   fn get_overrides_path(&self) -> String;
+  // overrides folders applied to the client, in ascending order of priority
+  fn get_overrides_paths(&self) -> Vec<String> {
+    vec![self.get_overrides_path()]
+  }
+  // files which the user can choose whether to install, they are included in `get_download_params`
+  fn get_optional_files(&self) -> Vec<ModpackOptionalFile> {
+    Vec::new()
+  }
+  // sha512 of the files to download keyed by relative path, used to verify them after installing
+  fn get_sha512_hashes(&self) -> HashMap<String, String> {
+    HashMap::new()
+  }
+  // same as `get_sha512_hashes`, for the packs which hash their files by sha256
+  fn get_sha256_hashes(&self) -> HashMap<String, String> {
+    HashMap::new()
+  }
+  fn to_installed_modpack(&self) -> SJMCLResult<InstalledModpack>;
+  fn apply_overrides(
+    &self,
+    path: &str,
+    instance_path: &Path,
+    recorded: Option<&HashMap<String, String>>,
+  ) -> SJMCLResult<HashMap<String, String>> {
+    let file = File::open(path).map_err(|_| InstanceError::FileNotFoundError)?;
+    extract_overrides(&file, &self.get_overrides_paths(), instance_path, recorded)
+  }
 }
 
-type ManifestBox = Box<dyn ModpackManifest + Send + Sync>;
+pub type ManifestBox = Box<dyn ModpackManifest + Send + Sync>;
 type Parser = Box<dyn Fn(&File) -> SJMCLResult<ManifestBox> + Send + Sync>;
 
 fn get_parsers() -> Vec<Parser> {
@@ -53,6 +87,18 @@ fn get_parsers() -> Vec<Parser> {
         b
       })
     }),
+    Box::new(|f| {
+      HmclServerManifest::from_archive(f).map(|m| {
+        let b: ManifestBox = Box::new(m);
+        b
+      })
+    }),
+    Box::new(|f| {
+      HmclManifest::from_archive(f).map(|m| {
+        let b: ManifestBox = Box::new(m);
+        b
+      })
+    }),
   ]
 }
 
@@ -80,68 +126,169 @@ pub struct ModpackMetaInfo {
   pub modpack_source: OtherResourceSource,
   pub client_version: String,
   pub mod_loader: Option<ModLoader>,
+  pub optional_files: Vec<ModpackOptionalFile>,
 }
 
-impl ModpackMetaInfo {
-  pub async fn from_archive(app: &AppHandle, file: &File) -> SJMCLResult<Self> {
-    for parser in get_parsers() {
-      if let Ok(manifest) = parser(file) {
-        return manifest.get_meta_info(app).await;
-      }
-    }
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ModpackOptionalFile {
+  // relative to the game directory of the instance, separated by `/`
+  pub path: String,
+  pub description: Option<String>,
+  pub enabled_by_default: bool,
+}
 
-    Err(InstanceError::ModpackManifestParseError.into())
+impl ModpackMetaInfo {
+  pub async fn from_path(app: &AppHandle, path: &str) -> SJMCLResult<Self> {
+    load_modpack_manifest(app, path)
+      .await?
+      .get_meta_info(app)
+      .await
   }
 }
 
-pub async fn get_download_params(
-  app: &AppHandle,
-  file: &File,
-  instance_path: &Path,
-) -> SJMCLResult<Vec<PTaskParam>> {
-  for parser in get_parsers() {
-    if let Ok(manifest) = parser(file) {
-      return manifest.get_download_params(app, instance_path).await;
-    }
-  }
+// the paths in a remote pack must not escape the pack or the instance, e.g. by `..`
+pub fn is_safe_relative_path(path: &str) -> bool {
+  !path.is_empty()
+    && Path::new(path)
+      .components()
+      .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+}
 
-  Err(InstanceError::ModpackManifestParseError.into())
+// strip the first matching overrides folder, returns the index of the folder as well
+fn strip_overrides_path(path: &Path, overrides_paths: &[String]) -> Option<(usize, PathBuf)> {
+  overrides_paths
+    .iter()
+    .enumerate()
+    .find_map(|(index, overrides_path)| {
+      let relative_path = path.strip_prefix(format!("{}/", overrides_path)).ok()?;
+      Some((index, relative_path.to_path_buf()))
+    })
 }
 
-pub fn extract_overrides(file: &File, instance_path: &Path) -> SJMCLResult<()> {
-  let get_overrides_path = |file| {
-    for parser in get_parsers() {
-      if let Ok(manifest) = parser(file) {
-        return Some(manifest.get_overrides_path());
-      }
-    }
-    None
-  };
-  let overrides_path = get_overrides_path(file).ok_or(InstanceError::ModpackManifestParseError)?;
+// extract the overrides into the instance, returns the sha1 of the extracted files keyed by relative path.
+// when updating, files modified by the user since the `recorded` install are left untouched.
+pub fn extract_overrides(
+  file: &File,
+  overrides_paths: &[String],
+  instance_path: &Path,
+  recorded: Option<&HashMap<String, String>>,
+) -> SJMCLResult<HashMap<String, String>> {
   let mut archive = ZipArchive::new(file)?;
+
+  // a file in several overrides folders is extracted from the one with the highest priority only
+  let mut entries: HashMap<String, (usize, usize)> = HashMap::new();
   for i in 0..archive.len() {
-    let mut file = archive.by_index(i)?;
-    let path = file.mangled_name();
-    let outpath = if path.starts_with(format!("{}/", overrides_path)) {
-      // Remove "{overrides}/" prefix and join with instance path
-      let relative_path = path.strip_prefix(format!("{}/", overrides_path)).unwrap();
-      instance_path.join(relative_path)
-    } else {
+    let file = archive.by_index(i)?;
+    if !file.is_file() {
+      continue;
+    }
+    let Some((priority, relative_path)) =
+      strip_overrides_path(&file.mangled_name(), overrides_paths)
+    else {
+      continue;
+    };
+    let Some(relative_path) = get_relative_path(instance_path, &instance_path.join(relative_path))
+    else {
       continue;
     };
+    let entry = entries.entry(relative_path).or_insert((priority, i));
+    if priority >= entry.0 {
+      *entry = (priority, i);
+    }
+  }
 
-    if file.is_file() {
-      // Create parent directories if they don't exist
-      if let Some(p) = outpath.parent() {
-        if !p.exists() {
-          fs::create_dir_all(p)?;
+  let mut applied = HashMap::new();
+  for (relative_path, (_, i)) in entries {
+    if let Some(recorded) = recorded {
+      if is_modified_by_user(instance_path, &relative_path, recorded.get(&relative_path)) {
+        // keep the recorded hash, so that the file is still regarded as modified next time
+        if let Some(sha1) = recorded.get(&relative_path) {
+          applied.insert(relative_path, sha1.clone());
         }
+        continue;
+      }
+    }
+
+    let outpath = instance_path.join(&relative_path);
+    // Create parent directories if they don't exist
+    if let Some(p) = outpath.parent() {
+      if !p.exists() {
+        fs::create_dir_all(p)?;
       }
+    }
+
+    // Extract file
+    let mut file = archive.by_index(i)?;
+    let mut content = Vec::new();
+    file.read_to_end(&mut content)?;
+    fs::write(&outpath, &content)?;
+    applied.insert(relative_path, hex::encode(Sha1::digest(&content)));
+  }
+  Ok(applied)
+}
 
-      // Extract file
-      let mut outfile = File::create(&outpath)?;
-      std::io::copy(&mut file, &mut outfile)?;
+pub async fn load_modpack_manifest(app: &AppHandle, path: &str) -> SJMCLResult<ManifestBox> {
+  if is_packwiz_source(path) {
+    return Ok(Box::new(PackwizManifest::from_source(app, path).await?));
+  }
+  let file = File::open(path).map_err(|_| InstanceError::FileNotFoundError)?;
+  for parser in get_parsers() {
+    if let Ok(manifest) = parser(&file) {
+      return Ok(manifest);
     }
   }
-  Ok(())
+
+  Err(InstanceError::ModpackManifestParseError.into())
+}
+
+// drop the download params of the disabled optional files
+pub fn exclude_optional_files(
+  instance_path: &Path,
+  task_params: Vec<PTaskParam>,
+  disabled_files: &[String],
+) -> Vec<PTaskParam> {
+  task_params
+    .into_iter()
+    .filter(|param| match param {
+      PTaskParam::Download(param) => get_relative_path(instance_path, &param.dest)
+        .is_none_or(|path| !disabled_files.contains(&path)),
+      _ => true,
+    })
+    .collect()
+}
+
+// extract or copy the override files into the instance, returns the files to download.
+// optional files not in `selected_optional_files` are skipped, the defaults are used if not given.
+pub async fn install_modpack_files(
+  app: &AppHandle,
+  path: &str,
+  instance_path: &Path,
+  selected_optional_files: Option<&[String]>,
+) -> SJMCLResult<(Vec<PTaskParam>, InstalledModpack)> {
+  let manifest = load_modpack_manifest(app, path).await?;
+  let disabled_files: Vec<String> = manifest
+    .get_optional_files()
+    .into_iter()
+    .filter(|file| match selected_optional_files {
+      Some(selected) => !selected.contains(&file.path),
+      None => !file.enabled_by_default,
+    })
+    .map(|file| file.path)
+    .collect();
+  let task_params = exclude_optional_files(
+    instance_path,
+    manifest.get_download_params(app, instance_path).await?,
+    &disabled_files,
+  );
+  let mut installed = manifest.to_installed_modpack()?;
+  installed.overrides = manifest.apply_overrides(path, instance_path, None)?;
+  installed.files = build_file_records(
+    instance_path,
+    &task_params,
+    &manifest.get_sha256_hashes(),
+    &manifest.get_sha512_hashes(),
+  );
+  installed.disabled_optional_files = disabled_files;
+  Ok((task_params, installed))
 }
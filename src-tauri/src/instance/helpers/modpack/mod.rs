@@ -1,4 +1,8 @@
 pub mod curseforge;
+pub mod hmcl;
 pub mod misc;
 pub mod modrinth;
 pub mod multimc;
+pub mod packwiz;
+pub mod update;
+pub mod verify;
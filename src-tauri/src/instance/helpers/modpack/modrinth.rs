@@ -9,7 +9,10 @@ use tauri::AppHandle;
 use zip::ZipArchive;
 
 use crate::error::SJMCLResult;
-use crate::instance::helpers::modpack::misc::{ModpackManifest, ModpackMetaInfo};
+use crate::instance::helpers::modpack::misc::{
+  ModpackManifest, ModpackMetaInfo, ModpackOptionalFile,
+};
+use crate::instance::helpers::modpack::update::InstalledModpack;
 use crate::instance::models::misc::{InstanceError, ModLoader, ModLoaderType};
 use crate::resource::models::OtherResourceSource;
 use crate::tasks::download::DownloadParam;
@@ -33,6 +36,16 @@ pub struct ModrinthFile {
 }
 }
 
+impl ModrinthFile {
+  // files without `env` are required on both sides
+  fn get_client_env(&self) -> &str {
+    self
+      .env
+      .as_ref()
+      .map_or("required", |env| env.client.as_str())
+  }
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ModrinthManifest {
@@ -79,6 +92,7 @@ impl ModpackManifest for ModrinthManifest {
       modpack_source: OtherResourceSource::Modrinth,
       client_version,
       mod_loader,
+      optional_files: self.get_optional_files(),
     })
   }
 
@@ -99,6 +113,7 @@ impl ModpackManifest for ModrinthManifest {
         "forge" => return Ok((ModLoaderType::Forge, val.to_string())),
         "fabric-loader" => return Ok((ModLoaderType::Fabric, val.to_string())),
         "neoforge" => return Ok((ModLoaderType::NeoForge, val.to_string())),
+        "quilt-loader" => return Ok((ModLoaderType::Quilt, val.to_string())),
         _ => return Err(InstanceError::UnsupportedModLoader.into()),
       }
     }
@@ -113,6 +128,7 @@ impl ModpackManifest for ModrinthManifest {
     self
       .files
       .iter()
+      .filter(|file| file.get_client_env() != "unsupported")
       .map(|file| {
         let download_url = file
           .downloads
@@ -123,6 +139,14 @@ impl ModpackManifest for ModrinthManifest {
           sha1: Some(file.hashes.sha1.clone()),
           dest: instance_path.join(&file.path),
           filename: None,
+          // the manifest may list mirrors of the same file
+          alt_srcs: file
+            .downloads
+            .iter()
+            .skip(1)
+            .filter_map(|url| url::Url::parse(url).ok())
+            .collect(),
+          served_src: None,
         }))
       })
       .collect::<SJMCLResult<Vec<_>>>()
@@ -131,4 +155,39 @@ impl ModpackManifest for ModrinthManifest {
   fn get_overrides_path(&self) -> String {
     "overrides/".to_string()
   }
+
+  fn get_overrides_paths(&self) -> Vec<String> {
+    // `server-overrides` is for dedicated servers only
+    vec![self.get_overrides_path(), "client-overrides/".to_string()]
+  }
+
+  fn get_sha512_hashes(&self) -> HashMap<String, String> {
+    self
+      .files
+      .iter()
+      .map(|file| (file.path.clone(), file.hashes.sha512.clone()))
+      .collect()
+  }
+
+  fn get_optional_files(&self) -> Vec<ModpackOptionalFile> {
+    self
+      .files
+      .iter()
+      .filter(|file| file.get_client_env() == "optional")
+      .map(|file| ModpackOptionalFile {
+        path: file.path.clone(),
+        description: None,
+        enabled_by_default: true,
+      })
+      .collect()
+  }
+  fn to_installed_modpack(&self) -> SJMCLResult<InstalledModpack> {
+    Ok(InstalledModpack {
+      source: OtherResourceSource::Modrinth,
+      name: self.name.clone(),
+      version: self.version_id.clone(),
+      manifest: serde_json::to_string(self)?,
+      ..Default::default()
+    })
+  }
 }
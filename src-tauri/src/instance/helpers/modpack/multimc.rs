@@ -1,5 +1,6 @@
 use crate::error::SJMCLResult;
 use crate::instance::helpers::modpack::misc::{ModpackManifest, ModpackMetaInfo};
+use crate::instance::helpers::modpack::update::InstalledModpack;
 use crate::instance::models::misc::{InstanceError, ModLoader, ModLoaderType};
 use crate::resource::models::OtherResourceSource;
 use crate::tasks::PTaskParam;
@@ -120,6 +121,7 @@ impl ModpackManifest for MultiMcManifest {
       modpack_source: OtherResourceSource::MultiMc,
       client_version,
       mod_loader,
+      optional_files: Vec::new(),
     })
   }
 
@@ -142,6 +144,7 @@ impl ModpackManifest for MultiMcManifest {
           return Ok((ModLoaderType::Fabric, get_version(component)?))
         }
         "net.neoforged" => return Ok((ModLoaderType::NeoForge, get_version(component)?)),
+        "org.quiltmc.quilt-loader" => return Ok((ModLoaderType::Quilt, get_version(component)?)),
         _ => continue,
       }
     }
@@ -160,6 +163,15 @@ impl ModpackManifest for MultiMcManifest {
   fn get_overrides_path(&self) -> String {
     format!("{}.minecraft/", self.base_path)
   }
+
+  fn to_installed_modpack(&self) -> SJMCLResult<InstalledModpack> {
+    Ok(InstalledModpack {
+      source: OtherResourceSource::MultiMc,
+      name: self.cfg.get("name").cloned().unwrap_or_default(),
+      manifest: serde_json::to_string(self)?,
+      ..Default::default()
+    })
+  }
 }
 
 fn get_version(component: &MultiMcComponent) -> SJMCLResult<String> {
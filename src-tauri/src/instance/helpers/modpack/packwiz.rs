@@ -0,0 +1,797 @@
+// https://packwiz.infra.link/reference/pack-format/
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::path::{Component, Path, PathBuf};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_http::reqwest;
+use url::Url;
+
+use crate::error::{SJMCLError, SJMCLResult};
+use crate::instance::helpers::modpack::misc::{
+  is_safe_relative_path, ModpackManifest, ModpackMetaInfo, ModpackOptionalFile,
+};
+use crate::instance::helpers::modpack::update::{
+  get_relative_path, is_modified_by_user, InstalledModpack,
+};
+use crate::instance::helpers::mods::common::{get_cached_remote_match, load_local_mod_infos};
+use crate::instance::models::misc::{Instance, InstanceError, ModLoader, ModLoaderType};
+use crate::resource::helpers::provider::get_resource_provider;
+use crate::resource::models::{OtherResourceFileInfo, OtherResourceSource};
+use crate::tasks::download::DownloadParam;
+use crate::tasks::PTaskParam;
+use crate::utils::fs::{calculate_sha1, calculate_sha256, calculate_sha512};
+
+const PACK_FILE_NAME: &str = "pack.toml";
+const INDEX_FILE_NAME: &str = "index.toml";
+const PACK_FORMAT: &str = "packwiz:1.1.0";
+// directories exported as plain files besides the mods
+const EXPORT_DIRS: &[&str] = &["config", "resourcepacks", "shaderpacks"];
+
+structstruck::strike! {
+#[strikethrough[derive(Deserialize, Serialize, Debug, Clone, Default)]]
+#[strikethrough[serde(rename_all = "kebab-case")]]
+  pub struct PackwizPack {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pack_format: Option<String>,
+    pub index: pub struct PackwizIndexRef {
+      pub file: String,
+      pub hash_format: String,
+      pub hash: String,
+    },
+    pub versions: HashMap<String, String>,
+  }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct PackwizIndexFile {
+  pub file: String,
+  pub hash: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub hash_format: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub alias: Option<String>,
+  #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+  pub metafile: bool,
+  #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+  pub preserve: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct PackwizIndex {
+  pub hash_format: String,
+  #[serde(default)]
+  pub files: Vec<PackwizIndexFile>,
+}
+
+structstruck::strike! {
+#[strikethrough[derive(Deserialize, Serialize, Debug, Clone, Default)]]
+#[strikethrough[serde(rename_all = "kebab-case")]]
+  pub struct PackwizMetafile {
+    pub name: String,
+    pub filename: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub side: Option<String>,
+    pub download: pub struct PackwizDownload {
+      #[serde(default, skip_serializing_if = "Option::is_none")]
+      pub url: Option<String>,
+      pub hash_format: String,
+      pub hash: String,
+      #[serde(default, skip_serializing_if = "Option::is_none")]
+      pub mode: Option<String>,
+    },
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub option: Option<pub struct PackwizOption {
+      #[serde(default)]
+      pub optional: bool,
+      #[serde(default)]
+      pub default: bool,
+      #[serde(default, skip_serializing_if = "Option::is_none")]
+      pub description: Option<String>,
+    }>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update: Option<pub struct PackwizUpdate {
+      #[serde(default, skip_serializing_if = "Option::is_none")]
+      pub modrinth: Option<pub struct PackwizModrinthUpdate {
+        pub mod_id: String,
+        pub version: String,
+      }>,
+      #[serde(default, skip_serializing_if = "Option::is_none")]
+      pub curseforge: Option<pub struct PackwizCurseForgeUpdate {
+        pub file_id: u64,
+        pub project_id: u64,
+      }>,
+    }>,
+  }
+}
+
+// where the files of a pack are read from, a local directory or the url of a file in the pack.
+// relative paths are resolved against the directory in both cases.
+#[derive(Debug, Clone)]
+pub enum PackwizSource {
+  Dir(PathBuf),
+  Url(Url),
+}
+
+impl PackwizSource {
+  fn join(&self, relative: &str) -> SJMCLResult<Self> {
+    Ok(match self {
+      PackwizSource::Dir(dir) => PackwizSource::Dir(
+        dir
+          .join(relative)
+          .parent()
+          .map(Path::to_path_buf)
+          .unwrap_or_default(),
+      ),
+      PackwizSource::Url(url) => PackwizSource::Url(
+        url
+          .join(relative)
+          .map_err(|_| InstanceError::InvalidSourcePath)?,
+      ),
+    })
+  }
+
+  async fn read_to_string(&self, app: &AppHandle, relative: &str) -> SJMCLResult<String> {
+    match self {
+      PackwizSource::Dir(dir) => {
+        fs::read_to_string(dir.join(relative)).map_err(|_| InstanceError::FileNotFoundError.into())
+      }
+      PackwizSource::Url(url) => {
+        let url = url
+          .join(relative)
+          .map_err(|_| InstanceError::InvalidSourcePath)?;
+        let client = app.state::<reqwest::Client>();
+        let response = client
+          .get(url)
+          .send()
+          .await
+          .map_err(|_| InstanceError::NetworkError)?;
+        if !response.status().is_success() {
+          return Err(InstanceError::NetworkError.into());
+        }
+        response
+          .text()
+          .await
+          .map_err(|_| InstanceError::NetworkError.into())
+      }
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct PackwizManifest {
+  pub pack: PackwizPack,
+  pub index: PackwizIndex,
+  // parsed metafiles, keyed by their paths in the index
+  pub metafiles: HashMap<String, PackwizMetafile>,
+  // location of the index file
+  pub source: PackwizSource,
+}
+
+// packwiz packs are loaded from a directory or a `pack.toml` url instead of an archive.
+pub fn is_packwiz_source(path: &str) -> bool {
+  if path.starts_with("http://") || path.starts_with("https://") {
+    return true;
+  }
+  let path = Path::new(path);
+  path.join(PACK_FILE_NAME).is_file()
+    || (path.is_file() && path.file_name().is_some_and(|name| name == PACK_FILE_NAME))
+}
+
+fn parse_toml<T: serde::de::DeserializeOwned>(content: &str) -> SJMCLResult<T> {
+  toml::from_str(content).map_err(|e| {
+    log::error!("Failed to parse packwiz file: {}", e);
+    InstanceError::ModpackManifestParseError.into()
+  })
+}
+
+fn sha256_hex(content: &[u8]) -> String {
+  format!("{:x}", Sha256::digest(content))
+}
+
+fn is_safe_file_name(name: &str) -> bool {
+  let mut components = Path::new(name).components();
+  matches!(components.next(), Some(Component::Normal(_))) && components.next().is_none()
+}
+
+// md5 and murmur2 are also allowed by packwiz, they are not checked
+fn is_hash_matched(path: &Path, hash_format: &str, hash: &str) -> SJMCLResult<bool> {
+  let cur = match hash_format {
+    "sha1" => calculate_sha1(path)?,
+    "sha256" => calculate_sha256(path)?,
+    "sha512" => calculate_sha512(path)?,
+    _ => return Ok(true),
+  };
+  Ok(cur.eq_ignore_ascii_case(hash))
+}
+
+impl PackwizManifest {
+  pub async fn from_source(app: &AppHandle, path: &str) -> SJMCLResult<Self> {
+    let (pack_source, pack_file_name) =
+      if path.starts_with("http://") || path.starts_with("https://") {
+        let url = Url::parse(path).map_err(|_| InstanceError::InvalidSourcePath)?;
+        let file_name = url
+          .path_segments()
+          .and_then(|mut segments| segments.next_back())
+          .filter(|name| !name.is_empty())
+          .unwrap_or(PACK_FILE_NAME)
+          .to_string();
+        (PackwizSource::Url(url), file_name)
+      } else {
+        let path = Path::new(path);
+        if path.is_dir() {
+          (
+            PackwizSource::Dir(path.to_path_buf()),
+            PACK_FILE_NAME.to_string(),
+          )
+        } else {
+          let dir = path.parent().ok_or(InstanceError::InvalidSourcePath)?;
+          (
+            PackwizSource::Dir(dir.to_path_buf()),
+            PACK_FILE_NAME.to_string(),
+          )
+        }
+      };
+
+    let pack: PackwizPack = parse_toml(&pack_source.read_to_string(app, &pack_file_name).await?)?;
+    if !is_safe_relative_path(&pack.index.file) {
+      return Err(InstanceError::InvalidSourcePath.into());
+    }
+    let index_content = pack_source.read_to_string(app, &pack.index.file).await?;
+    if pack.index.hash_format == "sha256" && sha256_hex(index_content.as_bytes()) != pack.index.hash
+    {
+      log::warn!("Hash of packwiz index mismatched, the pack may be partially updated");
+    }
+    let index: PackwizIndex = parse_toml(&index_content)?;
+    for file in &index.files {
+      let alias_safe = file.alias.as_deref().is_none_or(is_safe_relative_path);
+      if !is_safe_relative_path(&file.file) || !alias_safe {
+        log::error!("Unsafe path in packwiz index: {}", file.file);
+        return Err(InstanceError::InvalidSourcePath.into());
+      }
+    }
+    let source = pack_source.join(&pack.index.file)?;
+
+    let tasks = index.files.iter().filter(|f| f.metafile).map(|f| {
+      let source = source.clone();
+      async move {
+        let content = source.read_to_string(app, &f.file).await?;
+        Ok::<_, SJMCLError>((f.file.clone(), parse_toml::<PackwizMetafile>(&content)?))
+      }
+    });
+    let metafiles = futures::future::join_all(tasks)
+      .await
+      .into_iter()
+      .collect::<SJMCLResult<HashMap<String, PackwizMetafile>>>()?;
+    if let Some(metafile) = metafiles.values().find(|m| !is_safe_file_name(&m.filename)) {
+      log::error!(
+        "Unsafe file name in packwiz metafile: {}",
+        metafile.filename
+      );
+      return Err(InstanceError::InvalidSourcePath.into());
+    }
+
+    Ok(PackwizManifest {
+      pack,
+      index,
+      metafiles,
+      source,
+    })
+  }
+
+  // copy the plain files of a local pack, those of a remote pack are downloaded in `get_download_params`.
+  // returns the sha1 of the copied files keyed by relative path, see `extract_overrides`.
+  pub fn copy_local_files(
+    &self,
+    instance_path: &Path,
+    recorded: Option<&HashMap<String, String>>,
+  ) -> SJMCLResult<HashMap<String, String>> {
+    let mut applied = HashMap::new();
+    let PackwizSource::Dir(dir) = &self.source else {
+      return Ok(applied);
+    };
+    for file in self.index.files.iter().filter(|f| !f.metafile) {
+      let dest = instance_path.join(file.alias.as_ref().unwrap_or(&file.file));
+      let Some(relative_path) = get_relative_path(instance_path, &dest) else {
+        continue;
+      };
+      if let Some(recorded) = recorded {
+        if is_modified_by_user(instance_path, &relative_path, recorded.get(&relative_path)) {
+          if let Some(sha1) = recorded.get(&relative_path) {
+            applied.insert(relative_path, sha1.clone());
+          }
+          continue;
+        }
+      }
+      let src = dir.join(&file.file);
+      let hash_format = file.hash_format.as_ref().unwrap_or(&self.index.hash_format);
+      if !is_hash_matched(&src, hash_format, &file.hash)? {
+        log::error!("Hash of packwiz file {} mismatched", file.file);
+        return Err(InstanceError::ModpackFileHashMismatch.into());
+      }
+      if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)?;
+      }
+      fs::copy(&src, &dest).map_err(|_| InstanceError::FileCopyFailed)?;
+      applied.insert(relative_path, calculate_sha1(&dest)?);
+    }
+    Ok(applied)
+  }
+
+  // hashes of the files to download in the given format, keyed by relative path
+  fn get_hashes_by_format(&self, format: &str) -> HashMap<String, String> {
+    let mut hashes = HashMap::new();
+    for file in &self.index.files {
+      let dest_relative = Path::new(file.alias.as_ref().unwrap_or(&file.file));
+      let (dest, hash_format, hash) = if file.metafile {
+        let Some(metafile) = self.metafiles.get(&file.file) else {
+          continue;
+        };
+        let dest_dir = dest_relative.parent().unwrap_or(Path::new(""));
+        (
+          dest_dir.join(&metafile.filename),
+          &metafile.download.hash_format,
+          &metafile.download.hash,
+        )
+      } else {
+        let hash_format = file.hash_format.as_ref().unwrap_or(&self.index.hash_format);
+        (dest_relative.to_path_buf(), hash_format, &file.hash)
+      };
+      if hash_format == format {
+        if let Some(path) = get_relative_path(Path::new(""), &dest) {
+          hashes.insert(path, hash.clone());
+        }
+      }
+    }
+    hashes
+  }
+}
+
+#[async_trait]
+impl ModpackManifest for PackwizManifest {
+  fn from_archive(_file: &File) -> SJMCLResult<Self> {
+    // packwiz has no archive format of its own, see `PackwizManifest::from_source`
+    Err(InstanceError::ModpackManifestParseError.into())
+  }
+
+  async fn get_meta_info(&self, app: &AppHandle) -> SJMCLResult<ModpackMetaInfo> {
+    let client_version = self.get_client_version()?;
+    let mod_loader = match self.get_mod_loader_type_version()? {
+      (ModLoaderType::Unknown, _) => None,
+      (loader_type, version) => Some(
+        ModLoader {
+          loader_type,
+          version,
+          ..Default::default()
+        }
+        .with_branch(app, client_version.clone())
+        .await?,
+      ),
+    };
+    Ok(ModpackMetaInfo {
+      name: self.pack.name.clone(),
+      version: self.pack.version.clone().unwrap_or_default(),
+      description: self.pack.description.clone(),
+      author: self.pack.author.clone(),
+      modpack_source: OtherResourceSource::Packwiz,
+      client_version,
+      mod_loader,
+      optional_files: self.get_optional_files(),
+    })
+  }
+
+  fn get_client_version(&self) -> SJMCLResult<String> {
+    Ok(
+      self
+        .pack
+        .versions
+        .get("minecraft")
+        .ok_or(InstanceError::ModpackManifestParseError)?
+        .to_string(),
+    )
+  }
+
+  fn get_mod_loader_type_version(&self) -> SJMCLResult<(ModLoaderType, String)> {
+    // other keys in `versions` are not loaders, e.g. `minecraft`
+    let loaders = [
+      ("forge", ModLoaderType::Forge),
+      ("neoforge", ModLoaderType::NeoForge),
+      ("fabric", ModLoaderType::Fabric),
+      ("quilt", ModLoaderType::Quilt),
+      ("liteloader", ModLoaderType::LiteLoader),
+    ];
+    Ok(
+      loaders
+        .into_iter()
+        .find_map(|(key, loader_type)| {
+          let version = self.pack.versions.get(key)?;
+          Some((loader_type, version.to_string()))
+        })
+        .unwrap_or((ModLoaderType::Unknown, String::new())),
+    )
+  }
+
+  async fn get_download_params(
+    &self,
+    _app: &AppHandle,
+    instance_path: &Path,
+  ) -> SJMCLResult<Vec<PTaskParam>> {
+    let mut params = Vec::new();
+    for file in &self.index.files {
+      let dest_relative = file.alias.as_ref().unwrap_or(&file.file);
+      if !file.metafile {
+        let PackwizSource::Url(url) = &self.source else {
+          continue;
+        };
+        let hash_format = file.hash_format.as_ref().unwrap_or(&self.index.hash_format);
+        params.push(PTaskParam::Download(DownloadParam {
+          src: url
+            .join(&file.file)
+            .map_err(|_| InstanceError::InvalidSourcePath)?,
+          dest: instance_path.join(dest_relative),
+          filename: None,
+          sha1: (hash_format == "sha1").then(|| file.hash.clone()),
+          alt_srcs: vec![],
+          served_src: None,
+        }));
+        continue;
+      }
+
+      let Some(metafile) = self.metafiles.get(&file.file) else {
+        continue;
+      };
+      if metafile.side.as_deref() == Some("server") {
+        continue;
+      }
+      let src = match (&metafile.download.url, &metafile.update) {
+        (Some(url), _) => url.clone(),
+        // `metadata:curseforge` mode, the url is not allowed to be distributed in the metafile
+        (
+          None,
+          Some(PackwizUpdate {
+            curseforge: Some(cf),
+            ..
+          }),
+        ) => format!(
+          "https://edge.forgecdn.net/files/{}/{}/{}",
+          cf.file_id / 1000,
+          cf.file_id % 1000,
+          urlencoding::encode(&metafile.filename)
+        ),
+        _ => {
+          log::warn!("No download url for packwiz metafile {}", file.file);
+          continue;
+        }
+      };
+      let dest_dir = Path::new(dest_relative).parent().unwrap_or(Path::new(""));
+      params.push(PTaskParam::Download(DownloadParam {
+        src: url::Url::parse(&src).map_err(|_| InstanceError::InvalidSourcePath)?,
+        dest: instance_path.join(dest_dir).join(&metafile.filename),
+        filename: Some(metafile.filename.clone()),
+        sha1: (metafile.download.hash_format == "sha1").then(|| metafile.download.hash.clone()),
+        alt_srcs: vec![],
+        served_src: None,
+      }));
+    }
+    Ok(params)
+  }
+
+  fn get_overrides_path(&self) -> String {
+    // there are no overrides in packwiz, plain files are listed in the index along with metafiles
+    String::new()
+  }
+
+  fn get_sha512_hashes(&self) -> HashMap<String, String> {
+    self.get_hashes_by_format("sha512")
+  }
+
+  fn get_sha256_hashes(&self) -> HashMap<String, String> {
+    self.get_hashes_by_format("sha256")
+  }
+
+  fn get_optional_files(&self) -> Vec<ModpackOptionalFile> {
+    self
+      .index
+      .files
+      .iter()
+      .filter(|file| file.metafile)
+      .filter_map(|file| {
+        let metafile = self.metafiles.get(&file.file)?;
+        let option = metafile.option.as_ref().filter(|option| option.optional)?;
+        if metafile.side.as_deref() == Some("server") {
+          return None;
+        }
+        let dest_relative = file.alias.as_ref().unwrap_or(&file.file);
+        let dest_dir = Path::new(dest_relative).parent().unwrap_or(Path::new(""));
+        Some(ModpackOptionalFile {
+          path: get_relative_path(Path::new(""), &dest_dir.join(&metafile.filename))?,
+          description: option.description.clone(),
+          enabled_by_default: option.default,
+        })
+      })
+      .collect()
+  }
+
+  fn to_installed_modpack(&self) -> SJMCLResult<InstalledModpack> {
+    Ok(InstalledModpack {
+      source: OtherResourceSource::Packwiz,
+      name: self.pack.name.clone(),
+      version: self.pack.version.clone().unwrap_or_default(),
+      manifest: toml::to_string(&self.pack)
+        .map_err(|_| InstanceError::ModpackManifestParseError)?,
+      ..Default::default()
+    })
+  }
+
+  fn apply_overrides(
+    &self,
+    _path: &str,
+    instance_path: &Path,
+    recorded: Option<&HashMap<String, String>>,
+  ) -> SJMCLResult<HashMap<String, String>> {
+    self.copy_local_files(instance_path, recorded)
+  }
+}
+
+// ids in the cdn urls, e.g. https://cdn.modrinth.com/data/{project_id}/versions/{version_id}/{file}
+pub fn get_modrinth_version_id(download_url: &str) -> Option<String> {
+  let url = Url::parse(download_url).ok()?;
+  let segments: Vec<&str> = url.path_segments()?.collect();
+  let pos = segments.iter().position(|s| *s == "versions")?;
+  segments.get(pos + 1).map(|s| s.to_string())
+}
+
+// e.g. https://edge.forgecdn.net/files/{file_id / 1000}/{file_id % 1000}/{file}
+pub fn get_curseforge_file_id(download_url: &str) -> Option<u64> {
+  let url = Url::parse(download_url).ok()?;
+  let segments: Vec<&str> = url.path_segments()?.collect();
+  let pos = segments.iter().position(|s| *s == "files")?;
+  let high: u64 = segments.get(pos + 1)?.parse().ok()?;
+  let low: u64 = segments.get(pos + 2)?.parse().ok()?;
+  Some(high * 1000 + low)
+}
+
+fn build_metafile(
+  name: &str,
+  sha1: String,
+  source: &OtherResourceSource,
+  file: &OtherResourceFileInfo,
+) -> Option<PackwizMetafile> {
+  let (download, update) = match source {
+    OtherResourceSource::Modrinth => (
+      PackwizDownload {
+        url: Some(file.download_url.clone()),
+        hash_format: "sha1".to_string(),
+        hash: sha1,
+        mode: None,
+      },
+      PackwizUpdate {
+        modrinth: Some(PackwizModrinthUpdate {
+          mod_id: file.resource_id.clone(),
+          version: get_modrinth_version_id(&file.download_url)?,
+        }),
+        curseforge: None,
+      },
+    ),
+    OtherResourceSource::CurseForge => (
+      PackwizDownload {
+        url: None,
+        hash_format: "sha1".to_string(),
+        hash: sha1,
+        mode: Some("metadata:curseforge".to_string()),
+      },
+      PackwizUpdate {
+        modrinth: None,
+        curseforge: Some(PackwizCurseForgeUpdate {
+          file_id: get_curseforge_file_id(&file.download_url)?,
+          project_id: file.resource_id.parse().ok()?,
+        }),
+      },
+    ),
+    _ => return None,
+  };
+  Some(PackwizMetafile {
+    name: name.to_string(),
+    filename: file.file_name.clone(),
+    side: Some("both".to_string()),
+    download,
+    option: None,
+    update: Some(update),
+  })
+}
+
+fn get_metafile_slug(file_name: &str, used: &mut HashSet<String>) -> String {
+  let stem = file_name
+    .strip_suffix(".jar")
+    .or(file_name.strip_suffix(".zip"))
+    .unwrap_or(file_name);
+  let base: String = stem
+    .to_lowercase()
+    .chars()
+    .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+    .collect();
+  let mut slug = base.clone();
+  let mut i = 1;
+  while !used.insert(slug.clone()) {
+    i += 1;
+    slug = format!("{}-{}", base, i);
+  }
+  slug
+}
+
+fn list_files_recursively(dir: &Path, files: &mut Vec<PathBuf>) {
+  let Ok(entries) = fs::read_dir(dir) else {
+    return;
+  };
+  for entry in entries.flatten() {
+    let path = entry.path();
+    if path.is_dir() {
+      list_files_recursively(&path, files);
+    } else if path.is_file() {
+      files.push(path);
+    }
+  }
+}
+
+async fn resolve_remote_file(
+  app: &AppHandle,
+  path: &Path,
+) -> Option<(OtherResourceSource, OtherResourceFileInfo)> {
+  if let Some(remote) = get_cached_remote_match(app, path) {
+    return Some((remote.source, remote.file));
+  }
+  let path_str = path.to_string_lossy();
+  for source in [
+    OtherResourceSource::Modrinth,
+    OtherResourceSource::CurseForge,
+  ] {
+    let provider = get_resource_provider(&source).ok()?;
+    if let Ok(file) = provider.fetch_by_local_file(app, &path_str).await {
+      return Some((source, file));
+    }
+  }
+  None
+}
+
+// export the instance as a packwiz pack, mods found on Modrinth or CurseForge are exported as
+// metafiles, and the others as plain files.
+pub async fn export_instance_to_packwiz(
+  app: &AppHandle,
+  instance: &Instance,
+  game_dir: &Path,
+  mods_dir: &Path,
+  dest_dir: &Path,
+) -> SJMCLResult<()> {
+  fs::create_dir_all(dest_dir).map_err(|_| InstanceError::FolderCreationFailed)?;
+  let mut index_files = Vec::new();
+  let mut used_slugs = HashSet::new();
+
+  let add_plain_file = |src: &Path, relative: String, index_files: &mut Vec<PackwizIndexFile>| {
+    let dest = dest_dir.join(&relative);
+    if let Some(parent) = dest.parent() {
+      fs::create_dir_all(parent).map_err(|_| InstanceError::FolderCreationFailed)?;
+    }
+    fs::copy(src, &dest).map_err(|_| InstanceError::FileCopyFailed)?;
+    index_files.push(PackwizIndexFile {
+      file: relative,
+      hash: calculate_sha256(&dest)?,
+      ..Default::default()
+    });
+    Ok::<(), SJMCLError>(())
+  };
+
+  let mut mod_infos = load_local_mod_infos(app, mods_dir).await?;
+  mod_infos.retain(|info| info.enabled && info.file_path.is_file());
+  mod_infos.sort_by(|a, b| a.file_path.cmp(&b.file_path));
+  for info in mod_infos {
+    let file_name = info
+      .file_path
+      .file_name()
+      .unwrap_or_default()
+      .to_string_lossy()
+      .to_string();
+    let metafile = match resolve_remote_file(app, &info.file_path).await {
+      Some((source, file)) => {
+        build_metafile(&info.name, calculate_sha1(&info.file_path)?, &source, &file)
+      }
+      None => None,
+    };
+    match metafile {
+      Some(mut metafile) => {
+        // keep the local file name, the remote one may be renamed by translated prefix
+        metafile.filename = file_name.clone();
+        let relative = format!(
+          "mods/{}.pw.toml",
+          get_metafile_slug(&file_name, &mut used_slugs)
+        );
+        let content = toml::to_string(&metafile).map_err(|_| InstanceError::FileCreationFailed)?;
+        let dest = dest_dir.join(&relative);
+        fs::create_dir_all(dest.parent().unwrap())
+          .map_err(|_| InstanceError::FolderCreationFailed)?;
+        fs::write(&dest, &content).map_err(|_| InstanceError::FileCreationFailed)?;
+        index_files.push(PackwizIndexFile {
+          file: relative,
+          hash: sha256_hex(content.as_bytes()),
+          metafile: true,
+          ..Default::default()
+        });
+      }
+      None => add_plain_file(
+        &info.file_path,
+        format!("mods/{}", file_name),
+        &mut index_files,
+      )?,
+    }
+  }
+
+  for dir_name in EXPORT_DIRS {
+    let mut files = Vec::new();
+    list_files_recursively(&game_dir.join(dir_name), &mut files);
+    files.sort();
+    for path in files {
+      let Ok(relative) = path.strip_prefix(game_dir) else {
+        continue;
+      };
+      // paths in packwiz index are always separated by `/`
+      let relative = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+      add_plain_file(&path, relative, &mut index_files)?;
+    }
+  }
+
+  let index = PackwizIndex {
+    hash_format: "sha256".to_string(),
+    files: index_files,
+  };
+  let index_content = toml::to_string(&index).map_err(|_| InstanceError::FileCreationFailed)?;
+  fs::write(dest_dir.join(INDEX_FILE_NAME), &index_content)
+    .map_err(|_| InstanceError::FileCreationFailed)?;
+
+  let mut versions = HashMap::new();
+  versions.insert("minecraft".to_string(), instance.version.clone());
+  let loader_key = match instance.mod_loader.loader_type {
+    ModLoaderType::Fabric => Some("fabric"),
+    ModLoaderType::Forge | ModLoaderType::LegacyForge => Some("forge"),
+    ModLoaderType::NeoForge => Some("neoforge"),
+    ModLoaderType::Quilt => Some("quilt"),
+    ModLoaderType::LiteLoader => Some("liteloader"),
+    // not a loader known by packwiz
+    ModLoaderType::Cleanroom | ModLoaderType::Unknown => None,
+  };
+  if let Some(key) = loader_key {
+    versions.insert(key.to_string(), instance.mod_loader.version.clone());
+  }
+  let pack = PackwizPack {
+    name: instance.name.clone(),
+    author: None,
+    version: None,
+    description: (!instance.description.is_empty()).then(|| instance.description.clone()),
+    pack_format: Some(PACK_FORMAT.to_string()),
+    index: PackwizIndexRef {
+      file: INDEX_FILE_NAME.to_string(),
+      hash_format: "sha256".to_string(),
+      hash: sha256_hex(index_content.as_bytes()),
+    },
+    versions,
+  };
+  let pack_content = toml::to_string(&pack).map_err(|_| InstanceError::FileCreationFailed)?;
+  fs::write(dest_dir.join(PACK_FILE_NAME), pack_content)
+    .map_err(|_| InstanceError::FileCreationFailed)?;
+
+  Ok(())
+}
@@ -0,0 +1,355 @@
+use crate::error::SJMCLResult;
+use crate::instance::helpers::modpack::misc::{
+  exclude_optional_files, load_modpack_manifest, ManifestBox,
+};
+use crate::instance::helpers::modpack::packwiz::{
+  get_curseforge_file_id, get_modrinth_version_id, is_packwiz_source,
+};
+use crate::instance::helpers::modpack::verify::is_record_file_valid;
+use crate::instance::models::misc::{InstanceError, ModLoader, ModLoaderType};
+use crate::resource::helpers::provider::get_resource_provider;
+use crate::resource::models::OtherResourceSource;
+use crate::tasks::PTaskParam;
+use crate::utils::fs::calculate_sha1;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ModpackFileRecord {
+  // relative to the game directory of the instance, separated by `/`
+  pub path: String,
+  pub url: String,
+  pub sha1: Option<String>,
+  pub sha256: Option<String>,
+  pub sha512: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct InstalledModpack {
+  pub source: OtherResourceSource,
+  pub project_id: Option<String>,
+  pub file_id: Option<String>,
+  pub name: String,
+  pub version: String,
+  // the original manifest of the installed pack, as it is in the archive
+  pub manifest: String,
+  pub files: Vec<ModpackFileRecord>,
+  // sha1 of the override files when they were applied, keyed by relative path
+  pub overrides: HashMap<String, String>,
+  // optional files which the user chose not to install
+  pub disabled_optional_files: Vec<String>,
+}
+
+pub fn get_relative_path(instance_path: &Path, path: &Path) -> Option<String> {
+  let relative = path.strip_prefix(instance_path).ok()?;
+  Some(
+    relative
+      .components()
+      .map(|c| c.as_os_str().to_string_lossy().to_string())
+      .collect::<Vec<_>>()
+      .join("/"),
+  )
+}
+
+// the hashes are keyed by relative path, see `ModpackManifest::get_sha512_hashes`
+pub fn build_file_records(
+  instance_path: &Path,
+  task_params: &[PTaskParam],
+  sha256_hashes: &HashMap<String, String>,
+  sha512_hashes: &HashMap<String, String>,
+) -> Vec<ModpackFileRecord> {
+  task_params
+    .iter()
+    .filter_map(|param| match param {
+      PTaskParam::Download(param) => {
+        let path = get_relative_path(instance_path, &param.dest)?;
+        Some(ModpackFileRecord {
+          url: param.src.to_string(),
+          sha1: param.sha1.clone(),
+          sha256: sha256_hashes.get(&path).cloned(),
+          sha512: sha512_hashes.get(&path).cloned(),
+          path,
+        })
+      }
+      _ => None,
+    })
+    .collect()
+}
+
+// an override file should not be written if the user changed or deleted it since it was applied,
+// or if it is a file of the user which was never applied by the pack.
+pub fn is_modified_by_user(
+  instance_path: &Path,
+  relative_path: &str,
+  recorded_sha1: Option<&String>,
+) -> bool {
+  let path = instance_path.join(relative_path);
+  match (path.is_file(), recorded_sha1) {
+    (true, Some(sha1)) => calculate_sha1(&path).map_or(true, |cur| !cur.eq_ignore_ascii_case(sha1)),
+    (true, None) => true,
+    (false, Some(_)) => true,
+    (false, None) => false,
+  }
+}
+
+fn is_same_file(old: &ModpackFileRecord, new: &ModpackFileRecord) -> bool {
+  match (&old.sha1, &new.sha1) {
+    (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+    _ => old.url == new.url,
+  }
+}
+
+// keep the download params of the added and changed files only, returns the dropped files as well.
+fn diff_modpack_files(
+  instance_path: &Path,
+  old_files: &[ModpackFileRecord],
+  new_params: Vec<PTaskParam>,
+) -> (Vec<PTaskParam>, Vec<String>) {
+  let old_map: HashMap<&str, &ModpackFileRecord> =
+    old_files.iter().map(|f| (f.path.as_str(), f)).collect();
+  let mut new_paths = HashSet::new();
+  let mut task_params = Vec::new();
+
+  for param in new_params {
+    let record = build_file_records(
+      instance_path,
+      std::slice::from_ref(&param),
+      &HashMap::new(),
+      &HashMap::new(),
+    )
+    .pop();
+    if let Some(record) = record {
+      let unchanged = old_map
+        .get(record.path.as_str())
+        .is_some_and(|old| is_same_file(old, &record));
+      new_paths.insert(record.path);
+      if unchanged {
+        continue;
+      }
+    }
+    task_params.push(param);
+  }
+
+  let removed = old_files
+    .iter()
+    .filter(|f| !new_paths.contains(&f.path))
+    .map(|f| f.path.clone())
+    .collect();
+  (task_params, removed)
+}
+
+fn remove_instance_file(instance_path: &Path, relative_path: &str) {
+  // the file may have been disabled by the user
+  for path in [
+    instance_path.join(relative_path),
+    instance_path.join(format!("{}.disabled", relative_path)),
+  ] {
+    if path.is_file() {
+      if let Err(e) = fs::remove_file(&path) {
+        log::error!("Failed to remove {}: {}", path.display(), e);
+      }
+    }
+  }
+}
+
+// look up the archive on the platform it comes from, packs imported from elsewhere have no ids.
+pub async fn resolve_modpack_ids(app: &AppHandle, path: &str, installed: &mut InstalledModpack) {
+  if is_packwiz_source(path) {
+    return;
+  }
+  let Ok(provider) = get_resource_provider(&installed.source) else {
+    return;
+  };
+  let result = provider.fetch_by_local_file(app, path).await.map(|info| {
+    // the file id is only found in the download url
+    let file_id = match installed.source {
+      OtherResourceSource::Modrinth => get_modrinth_version_id(&info.download_url),
+      _ => get_curseforge_file_id(&info.download_url).map(|id| id.to_string()),
+    };
+    (info.resource_id, file_id)
+  });
+  match result {
+    Ok((project_id, file_id)) => {
+      installed.project_id = Some(project_id);
+      installed.file_id = file_id;
+    }
+    Err(e) => {
+      log::info!("Failed to resolve the modpack {} remotely: {}", path, e.0);
+    }
+  }
+}
+
+// the downloads of an update are kept here until all of them have succeeded, so that a failed or
+// cancelled update leaves the instance as it was.
+const MODPACK_UPDATE_STAGING_DIR: &str = ".modpack-update";
+
+fn get_staging_dir(instance_path: &Path) -> PathBuf {
+  instance_path.join(MODPACK_UPDATE_STAGING_DIR)
+}
+
+pub fn discard_modpack_update(instance_path: &Path) {
+  let staging_dir = get_staging_dir(instance_path);
+  if staging_dir.exists() {
+    if let Err(e) = fs::remove_dir_all(&staging_dir) {
+      log::error!("Failed to remove {}: {}", staging_dir.display(), e);
+    }
+  }
+}
+
+// the changes to apply the new version of the installed pack, computed without touching the files.
+pub struct ModpackUpdatePlan {
+  manifest: ManifestBox,
+  // files to download into the instance
+  task_params: Vec<PTaskParam>,
+  // relative paths of the files dropped by the new version
+  removed: Vec<String>,
+  pub updated: InstalledModpack,
+}
+
+impl ModpackUpdatePlan {
+  // the downloads of the plan, written into the staging dir instead of the instance
+  pub fn get_staged_task_params(&self, instance_path: &Path) -> Vec<PTaskParam> {
+    let staging_dir = get_staging_dir(instance_path);
+    self
+      .task_params
+      .iter()
+      .cloned()
+      .map(|param| match param {
+        PTaskParam::Download(mut param) => {
+          if let Ok(relative_path) = param.dest.strip_prefix(instance_path) {
+            param.dest = staging_dir.join(relative_path);
+          }
+          PTaskParam::Download(param)
+        }
+        param => param,
+      })
+      .collect()
+  }
+
+  // move the staged downloads into the instance, nothing is moved if any of them is missing or
+  // does not match the hashes of the new version.
+  fn commit_staged_files(&self, instance_path: &Path) -> SJMCLResult<()> {
+    let staging_dir = get_staging_dir(instance_path);
+    let mut moves = Vec::new();
+    for param in &self.task_params {
+      let PTaskParam::Download(param) = param else {
+        continue;
+      };
+      let Some(relative_path) = get_relative_path(instance_path, &param.dest) else {
+        continue;
+      };
+      let staged = staging_dir.join(&relative_path);
+      let is_valid = match self.updated.files.iter().find(|f| f.path == relative_path) {
+        Some(record) => staged.is_file() && is_record_file_valid(&staged, record),
+        None => staged.is_file(),
+      };
+      if !is_valid {
+        log::error!("Download of modpack file {} failed", relative_path);
+        return Err(InstanceError::NetworkError.into());
+      }
+      moves.push((staged, param.dest.clone()));
+    }
+
+    for (staged, dest) in moves {
+      if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)?;
+      }
+      // the old file may be disabled by the user, the new one replaces it
+      let _ = fs::remove_file(format!("{}.disabled", dest.display()));
+      fs::rename(&staged, &dest)?;
+    }
+    Ok(())
+  }
+}
+
+pub async fn plan_modpack_update(
+  app: &AppHandle,
+  path: &str,
+  instance_path: &Path,
+  installed: &InstalledModpack,
+  client_version: &str,
+  mod_loader: &ModLoader,
+) -> SJMCLResult<ModpackUpdatePlan> {
+  let manifest = load_modpack_manifest(app, path).await?;
+  // the instance is not reinstalled, so the game and the loader must stay the same
+  let (new_loader_type, new_loader_version) = manifest
+    .get_mod_loader_type_version()
+    .unwrap_or((ModLoaderType::Unknown, String::new()));
+  if manifest.get_client_version()? != client_version
+    || new_loader_type != mod_loader.loader_type
+    || (new_loader_type != ModLoaderType::Unknown && new_loader_version != mod_loader.version)
+  {
+    return Err(InstanceError::ModpackVersionMismatch.into());
+  }
+
+  // keep the choices of the user, new optional files follow their defaults
+  let disabled_files: Vec<String> = manifest
+    .get_optional_files()
+    .into_iter()
+    .filter(|file| {
+      installed.disabled_optional_files.contains(&file.path)
+        || (!file.enabled_by_default && !installed.files.iter().any(|f| f.path == file.path))
+    })
+    .map(|file| file.path)
+    .collect();
+  let new_params = exclude_optional_files(
+    instance_path,
+    manifest.get_download_params(app, instance_path).await?,
+    &disabled_files,
+  );
+  let new_files = build_file_records(
+    instance_path,
+    &new_params,
+    &manifest.get_sha256_hashes(),
+    &manifest.get_sha512_hashes(),
+  );
+  let (task_params, removed) = diff_modpack_files(instance_path, &installed.files, new_params);
+
+  let mut updated = manifest.to_installed_modpack()?;
+  updated.project_id = installed.project_id.clone();
+  updated.files = new_files;
+  updated.disabled_optional_files = disabled_files;
+  Ok(ModpackUpdatePlan {
+    manifest,
+    task_params,
+    removed,
+    updated,
+  })
+}
+
+// move the downloaded files into place, remove the dropped files and write the overrides.
+// to be called once the staged downloads have ended, returns the updated record of the pack.
+pub fn apply_modpack_update(
+  plan: ModpackUpdatePlan,
+  path: &str,
+  instance_path: &Path,
+  installed: &InstalledModpack,
+) -> SJMCLResult<InstalledModpack> {
+  let committed = plan.commit_staged_files(instance_path);
+  discard_modpack_update(instance_path);
+  committed?;
+
+  let overrides = plan
+    .manifest
+    .apply_overrides(path, instance_path, Some(&installed.overrides))?;
+  for relative_path in &plan.removed {
+    remove_instance_file(instance_path, relative_path);
+  }
+  // overrides dropped by the pack are removed, unless the user has changed them
+  for (relative_path, sha1) in &installed.overrides {
+    if !overrides.contains_key(relative_path)
+      && !is_modified_by_user(instance_path, relative_path, Some(sha1))
+    {
+      remove_instance_file(instance_path, relative_path);
+    }
+  }
+
+  let mut updated = plan.updated;
+  updated.overrides = overrides;
+  Ok(updated)
+}
@@ -0,0 +1,211 @@
+use crate::error::SJMCLResult;
+use crate::instance::helpers::modpack::update::{
+  get_relative_path, InstalledModpack, ModpackFileRecord,
+};
+use crate::instance::models::misc::InstanceError;
+use crate::tasks::download::DownloadParam;
+use crate::tasks::monitor::TaskMonitor;
+use crate::tasks::PTaskParam;
+use crate::utils::fs::{calculate_sha1, calculate_sha256, calculate_sha512};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use tauri::{AppHandle, Emitter, Manager};
+use url::Url;
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ModpackVerificationReport {
+  // relative paths of the files listed in the manifest but not found in the instance
+  pub missing: Vec<String>,
+  // relative paths of the files whose hashes do not match the manifest
+  pub corrupt: Vec<String>,
+  // relative paths of the files in the pack folders which come neither from the manifest nor the overrides
+  pub extra: Vec<String>,
+}
+
+impl ModpackVerificationReport {
+  pub fn is_valid(&self) -> bool {
+    self.missing.is_empty() && self.corrupt.is_empty()
+  }
+}
+
+// the file may have been disabled by the user
+fn find_record_file(instance_path: &Path, record: &ModpackFileRecord) -> Option<PathBuf> {
+  [
+    instance_path.join(&record.path),
+    instance_path.join(format!("{}.disabled", record.path)),
+  ]
+  .into_iter()
+  .find(|path| path.is_file())
+}
+
+// sha512 is preferred, files without any hash are only checked for existence
+pub fn is_record_file_valid(path: &Path, record: &ModpackFileRecord) -> bool {
+  if let Some(sha512) = &record.sha512 {
+    return calculate_sha512(path).is_ok_and(|cur| cur.eq_ignore_ascii_case(sha512));
+  }
+  if let Some(sha256) = &record.sha256 {
+    return calculate_sha256(path).is_ok_and(|cur| cur.eq_ignore_ascii_case(sha256));
+  }
+  if let Some(sha1) = &record.sha1 {
+    return calculate_sha1(path).is_ok_and(|cur| cur.eq_ignore_ascii_case(sha1));
+  }
+  true
+}
+
+pub fn check_modpack_files(
+  instance_path: &Path,
+  installed: &InstalledModpack,
+) -> ModpackVerificationReport {
+  let mut report = ModpackVerificationReport::default();
+  for record in &installed.files {
+    match find_record_file(instance_path, record) {
+      None => report.missing.push(record.path.clone()),
+      Some(path) if !is_record_file_valid(&path, record) => {
+        report.corrupt.push(record.path.clone())
+      }
+      _ => {}
+    }
+  }
+
+  // only the folders containing files of the manifest are checked, e.g. `mods`
+  let known: HashSet<&str> = installed
+    .files
+    .iter()
+    .map(|f| f.path.as_str())
+    .chain(installed.overrides.keys().map(|p| p.as_str()))
+    .collect();
+  let dirs: HashSet<PathBuf> = installed
+    .files
+    .iter()
+    .filter_map(|f| instance_path.join(&f.path).parent().map(Path::to_path_buf))
+    .collect();
+  for dir in dirs {
+    let Ok(entries) = fs::read_dir(&dir) else {
+      continue;
+    };
+    for entry in entries.flatten() {
+      let path = entry.path();
+      if !path.is_file() {
+        continue;
+      }
+      let Some(relative_path) = get_relative_path(instance_path, &path) else {
+        continue;
+      };
+      let enabled_path = relative_path
+        .strip_suffix(".disabled")
+        .unwrap_or(&relative_path);
+      if !known.contains(enabled_path) {
+        report.extra.push(relative_path);
+      }
+    }
+  }
+  report.extra.sort();
+  report
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModpackFilesCheckedPayload {
+  pub instance_id: String,
+  pub report: ModpackVerificationReport,
+}
+
+// the download tasks only check sha1, so every file is checked again by its strongest hash once
+// downloaded. the corrupt ones are removed, so that they are not loaded before being repaired.
+fn check_downloaded_modpack_files(
+  instance_path: &Path,
+  installed: &InstalledModpack,
+) -> ModpackVerificationReport {
+  let report = check_modpack_files(instance_path, installed);
+  for record in installed
+    .files
+    .iter()
+    .filter(|f| report.corrupt.contains(&f.path))
+  {
+    log::error!("Hash of modpack file {} mismatched", record.path);
+    if let Some(path) = find_record_file(instance_path, record) {
+      let _ = fs::remove_file(&path);
+    }
+  }
+  report
+}
+
+// check the files of the pack after the task group has ended, the report is sent to the frontend.
+pub fn spawn_modpack_files_check(
+  app: AppHandle,
+  task_group: String,
+  instance_id: String,
+  instance_path: PathBuf,
+  installed: InstalledModpack,
+) {
+  tauri::async_runtime::spawn(async move {
+    let monitor = app.state::<Pin<Box<TaskMonitor>>>();
+    if monitor.wait_for_group(&task_group).await {
+      emit_modpack_files_check(&app, instance_id, instance_path, installed).await;
+    }
+  });
+}
+
+pub async fn emit_modpack_files_check(
+  app: &AppHandle,
+  instance_id: String,
+  instance_path: PathBuf,
+  installed: InstalledModpack,
+) {
+  let report = match tauri::async_runtime::spawn_blocking(move || {
+    check_downloaded_modpack_files(&instance_path, &installed)
+  })
+  .await
+  {
+    Ok(report) => report,
+    Err(e) => {
+      log::error!("Failed to check the modpack files: {:?}", e);
+      return;
+    }
+  };
+  let _ = app.emit(
+    "instance:modpack-files-checked",
+    ModpackFilesCheckedPayload {
+      instance_id,
+      report,
+    },
+  );
+}
+
+// remove the corrupt files, returns the download params of the missing and corrupt files
+pub fn get_repair_params(
+  instance_path: &Path,
+  installed: &InstalledModpack,
+  report: &ModpackVerificationReport,
+) -> SJMCLResult<Vec<PTaskParam>> {
+  let mut params = Vec::new();
+  for record in &installed.files {
+    let is_missing = report.missing.contains(&record.path);
+    let is_corrupt = report.corrupt.contains(&record.path);
+    if !is_missing && !is_corrupt {
+      continue;
+    }
+    // a disabled file is repaired in place, so that it stays disabled
+    let dest = if is_corrupt {
+      let dest =
+        find_record_file(instance_path, record).unwrap_or_else(|| instance_path.join(&record.path));
+      fs::remove_file(&dest)?;
+      dest
+    } else {
+      instance_path.join(&record.path)
+    };
+    params.push(PTaskParam::Download(DownloadParam {
+      src: Url::parse(&record.url).map_err(|_| InstanceError::InvalidSourcePath)?,
+      dest,
+      filename: None,
+      sha1: record.sha1.clone(),
+      alt_srcs: vec![],
+      served_src: None,
+    }));
+  }
+  Ok(params)
+}
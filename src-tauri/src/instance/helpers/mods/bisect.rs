@@ -0,0 +1,194 @@
+use crate::instance::helpers::mods::profile::ModProfile;
+use crate::instance::models::misc::LocalModInfo;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ModBisectUnit {
+  pub mod_id: String,
+  // file names of the mod and its dependency closure, without the `.disabled` suffix
+  pub files: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ModBisectStep {
+  // indexes of the units disabled in this run
+  pub disabled_units: Vec<usize>,
+  // None if the run has not finished yet, true if the game reached `READY_FLAG` and exited normally
+  pub reached_ready: Option<bool>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ModBisectSession {
+  pub original: ModProfile,
+  pub units: Vec<ModBisectUnit>,
+  pub suspects: Vec<usize>,
+  pub steps: Vec<ModBisectStep>,
+  pub culprit: Option<ModBisectUnit>,
+  pub finished: bool,
+}
+
+// a library mod is an enabled mod required by another enabled mod. only non-library mods take part
+// in bisecting directly, each of them carries its dependency closure, so a library is disabled only
+// when all the mods requiring it are disabled.
+pub fn build_bisect_units(mod_infos: &[LocalModInfo]) -> Vec<ModBisectUnit> {
+  let enabled_mods: Vec<&LocalModInfo> = mod_infos
+    .iter()
+    .filter(|info| info.enabled && info.file_path.is_file())
+    .collect();
+
+  let file_name_of = |info: &LocalModInfo| {
+    info
+      .file_path
+      .file_name()
+      .unwrap_or_default()
+      .to_string_lossy()
+      .to_string()
+  };
+  // mods without a parsed id are keyed by their file names
+  let key_of = |info: &LocalModInfo| {
+    if info.mod_id.is_empty() {
+      file_name_of(info)
+    } else {
+      info.mod_id.to_lowercase()
+    }
+  };
+
+  // nested (jar-in-jar) mods are provided by the file containing them
+  let mut file_by_id: HashMap<String, String> = HashMap::new();
+  for info in &enabled_mods {
+    for id in info.provided_mod_ids() {
+      file_by_id.insert(id.to_lowercase(), file_name_of(info));
+    }
+    file_by_id.insert(key_of(info), file_name_of(info));
+  }
+  let deps_by_id: HashMap<String, Vec<String>> = enabled_mods
+    .iter()
+    .map(|info| {
+      let key = key_of(info);
+      let deps = info
+        .dependencies
+        .iter()
+        .map(|dep| dep.to_lowercase())
+        .filter(|dep| file_by_id.contains_key(dep) && file_by_id[dep] != file_name_of(info))
+        .collect();
+      (key, deps)
+    })
+    .collect();
+
+  let library_ids: HashSet<&String> = deps_by_id.values().flatten().collect();
+
+  let collect_closure = |root: &String| -> Vec<String> {
+    let mut visited = HashSet::new();
+    let mut stack = vec![root.clone()];
+    while let Some(id) = stack.pop() {
+      if !visited.insert(id.clone()) {
+        continue;
+      }
+      stack.extend(deps_by_id.get(&id).cloned().unwrap_or_default());
+    }
+    let mut files: Vec<String> = visited
+      .iter()
+      .filter_map(|id| file_by_id.get(id).cloned())
+      .collect();
+    files.sort();
+    files.dedup();
+    files
+  };
+
+  let mut units = Vec::new();
+  let mut covered = HashSet::new();
+  let mut ids: Vec<String> = enabled_mods.iter().map(|info| key_of(info)).collect();
+  ids.sort();
+  for id in ids.iter().filter(|id| !library_ids.contains(id)) {
+    let files = collect_closure(id);
+    covered.extend(files.clone());
+    units.push(ModBisectUnit {
+      mod_id: id.clone(),
+      files,
+    });
+  }
+  // libraries only required by each other (dependency cycles) are not reachable from any unit
+  for id in ids {
+    if !covered.contains(&file_by_id[&id]) {
+      let files = collect_closure(&id);
+      covered.extend(files.clone());
+      units.push(ModBisectUnit { mod_id: id, files });
+    }
+  }
+  units
+}
+
+impl ModBisectSession {
+  pub fn new(original: ModProfile, units: Vec<ModBisectUnit>) -> Self {
+    ModBisectSession {
+      original,
+      suspects: (0..units.len()).collect(),
+      units,
+      steps: Vec::new(),
+      culprit: None,
+      finished: false,
+    }
+  }
+
+  // prepare the next run by disabling half of the suspects, returns None if bisecting is finished.
+  pub fn next_profile(&mut self) -> Option<ModProfile> {
+    if self.suspects.len() <= 1 {
+      self.culprit = self.suspects.first().map(|i| self.units[*i].clone());
+      self.finished = true;
+      return None;
+    }
+
+    let disabled_units = self.suspects[..self.suspects.len() / 2].to_vec();
+    let kept_files: HashSet<&String> = self
+      .units
+      .iter()
+      .enumerate()
+      .filter(|(i, _)| !disabled_units.contains(i))
+      .flat_map(|(_, unit)| &unit.files)
+      .collect();
+    let disabled_files: HashSet<&String> = disabled_units
+      .iter()
+      .flat_map(|i| &self.units[*i].files)
+      .filter(|file| !kept_files.contains(file))
+      .collect();
+
+    let enabled_mods = self
+      .original
+      .enabled_mods
+      .iter()
+      .filter(|file| !disabled_files.contains(file))
+      .cloned()
+      .collect();
+    self.steps.push(ModBisectStep {
+      disabled_units,
+      reached_ready: None,
+    });
+    Some(ModProfile {
+      name: format!("bisect-{}", self.steps.len()),
+      enabled_mods,
+    })
+  }
+
+  // narrow the suspects by the result of the pending run, returns false if no run is pending.
+  pub fn record_result(&mut self, reached_ready: bool) -> bool {
+    let Some(step) = self
+      .steps
+      .last_mut()
+      .filter(|step| step.reached_ready.is_none())
+    else {
+      return false;
+    };
+    step.reached_ready = Some(reached_ready);
+    if reached_ready {
+      // the culprit is among the disabled units
+      self.suspects = step.disabled_units.clone();
+    } else {
+      self.suspects.retain(|i| !step.disabled_units.contains(i));
+    }
+    true
+  }
+}
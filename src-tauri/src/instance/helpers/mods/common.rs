@@ -1,33 +1,36 @@
 use crate::error::{SJMCLError, SJMCLResult};
 use crate::instance::constants::{
-  COMPRESSED_ICON_SIZE, TRANSLATION_CACHE_EXPIRY_HOURS, TRANSLATION_CACHE_FILE_NAME,
+  COMPRESSED_ICON_SIZE, METADATA_CACHE_FILE_NAME, TRANSLATION_CACHE_EXPIRY_HOURS,
+  TRANSLATION_CACHE_FILE_NAME,
 };
+use crate::instance::helpers::mods::jarjar::get_nested_mod_infos;
 use crate::instance::helpers::mods::{fabric, forge, legacy_forge, liteloader, quilt};
-use crate::instance::models::misc::{LocalModInfo, ModLoaderType};
-use crate::resource::helpers::curseforge::{
-  fetch_remote_resource_by_id_curseforge, fetch_remote_resource_by_local_curseforge,
-};
-use crate::resource::helpers::modrinth::{
-  fetch_remote_resource_by_id_modrinth, fetch_remote_resource_by_local_modrinth,
-};
+use crate::instance::models::misc::{InstanceError, LocalModInfo, ModLoaderType};
+use crate::resource::helpers::provider::get_resource_provider;
+use crate::resource::models::{OtherResourceFileInfo, OtherResourceSource};
 use crate::storage::Storage;
+use crate::utils::fs::{calculate_sha1, get_files_with_regex};
 use crate::utils::image::{load_image_from_dir_async, load_image_from_jar, ImageWrapper};
 use crate::APP_DATA_DIR;
+use futures::future::join_all;
 use image::imageops::FilterType;
 use log::info;
+use regex::RegexBuilder;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::io::Cursor;
 use std::path::{Path, PathBuf};
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::time::{SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Manager};
+use tokio::sync::Semaphore;
 use zip::ZipArchive;
 
 // Cache structure for local mod translations
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct LocalModTranslationsCache {
   #[serde(flatten)]
-  pub translations: std::collections::HashMap<String, LocalModTranslationEntry>,
+  pub translations: HashMap<String, LocalModTranslationEntry>,
 }
 
 impl Storage for LocalModTranslationsCache {
@@ -67,6 +70,92 @@ impl LocalModTranslationEntry {
   }
 }
 
+// Cache structure for parsed local mod metadata, keyed by the mod file path without `.disabled` suffix
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LocalModMetadataCache {
+  #[serde(flatten)]
+  pub entries: HashMap<String, LocalModMetadataEntry>,
+}
+
+impl Storage for LocalModMetadataCache {
+  fn file_path() -> PathBuf {
+    APP_DATA_DIR.get().unwrap().join(METADATA_CACHE_FILE_NAME)
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalModMetadataEntry {
+  pub size: u64,
+  pub modified: u64,
+  pub sha1: String,
+  // with compressed icon
+  pub info: LocalModInfo,
+  pub remote: Option<LocalModRemoteMatch>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalModRemoteMatch {
+  pub source: OtherResourceSource,
+  pub file: OtherResourceFileInfo,
+}
+
+fn get_metadata_cache_key(path: &Path) -> String {
+  let path = path.to_string_lossy();
+  let mut key = path.as_ref();
+  while let Some(stripped) = key.strip_suffix(".disabled") {
+    key = stripped;
+  }
+  key.to_string()
+}
+
+fn get_file_size_and_mtime(path: &Path) -> Option<(u64, u64)> {
+  let metadata = std::fs::metadata(path).ok()?;
+  let modified = metadata
+    .modified()
+    .ok()?
+    .duration_since(UNIX_EPOCH)
+    .ok()?
+    .as_secs();
+  Some((metadata.len(), modified))
+}
+
+// the cached info is shared by the enabled and disabled file, so refresh the path related fields.
+fn restore_cached_mod_info(mut info: LocalModInfo, path: &Path) -> LocalModInfo {
+  let file_name = path.file_name().unwrap().to_string_lossy().to_string();
+  info.enabled = !file_name.ends_with(".disabled");
+  info.file_path = path.to_path_buf();
+  info
+}
+
+pub fn get_cached_sha1(app: &AppHandle, path: &Path) -> Option<String> {
+  let (size, modified) = get_file_size_and_mtime(path)?;
+  let cache_state = app.state::<Mutex<LocalModMetadataCache>>();
+  let cache = cache_state.lock().ok()?;
+  cache
+    .entries
+    .get(&get_metadata_cache_key(path))
+    .filter(|entry| entry.size == size && entry.modified == modified)
+    .map(|entry| entry.sha1.clone())
+}
+
+pub fn get_cached_remote_match(app: &AppHandle, path: &Path) -> Option<LocalModRemoteMatch> {
+  let cache_state = app.state::<Mutex<LocalModMetadataCache>>();
+  let cache = cache_state.lock().ok()?;
+  cache
+    .entries
+    .get(&get_metadata_cache_key(path))
+    .and_then(|entry| entry.remote.clone())
+}
+
+pub fn set_cached_remote_match(app: &AppHandle, path: &Path, remote: LocalModRemoteMatch) {
+  let cache_state = app.state::<Mutex<LocalModMetadataCache>>();
+  if let Ok(mut cache) = cache_state.lock() {
+    if let Some(entry) = cache.entries.get_mut(&get_metadata_cache_key(path)) {
+      entry.remote = Some(remote);
+    }
+  };
+}
+
 pub fn compress_icon(wrapper: ImageWrapper) -> ImageWrapper {
   let resized_image = image::imageops::resize(
     &wrapper.image,
@@ -91,6 +180,7 @@ pub async fn get_mod_info_from_jar(path: &PathBuf) -> SJMCLResult<LocalModInfo>
   let enabled = !file_name.ends_with(".disabled");
   let mut jar = ZipArchive::new(file)?;
   if let Ok(meta) = fabric::get_mod_metadata_from_jar(&mut jar) {
+    let dependencies = meta.required_dependencies();
     let icon_src = if let Some(icon) = meta.icon {
       load_image_from_jar(&mut jar, &icon)
         .map(ImageWrapper::from)
@@ -102,6 +192,8 @@ pub async fn get_mod_info_from_jar(path: &PathBuf) -> SJMCLResult<LocalModInfo>
     return Ok(LocalModInfo {
       icon_src,
       enabled,
+      mod_id: meta.id,
+      dependencies,
       name: meta.name.unwrap_or_default(),
       translated_name: None,
       version: meta.version,
@@ -109,15 +201,19 @@ pub async fn get_mod_info_from_jar(path: &PathBuf) -> SJMCLResult<LocalModInfo>
       description: meta.description.unwrap_or_default(),
       translated_description: None,
       potential_incompatibility: false, // not assigned yet
+      children: get_nested_mod_infos(&mut jar, 0),
       loader_type: ModLoaderType::Fabric,
       file_path,
     });
   };
   if let Ok(mut meta) = forge::get_mod_metadata_from_jar(&mut jar) {
     let first_mod = meta.mods.remove(0);
+    let dependencies = meta.required_dependencies(&first_mod.mod_id);
     return Ok(LocalModInfo {
       icon_src: meta.valid_logo_file.map(compress_icon).unwrap_or_default(),
       enabled,
+      mod_id: first_mod.mod_id,
+      dependencies,
       name: first_mod.display_name.unwrap_or_default(),
       translated_name: None,
       version: first_mod.version.unwrap_or_default(),
@@ -125,11 +221,13 @@ pub async fn get_mod_info_from_jar(path: &PathBuf) -> SJMCLResult<LocalModInfo>
       description: first_mod.description.unwrap_or_default(),
       translated_description: None,
       potential_incompatibility: false,
+      children: get_nested_mod_infos(&mut jar, 0),
       loader_type: meta.loader_type, // Forge or NeoForge
       file_path,
     });
   }
   if let Ok(meta) = legacy_forge::get_mod_metadata_from_jar(&mut jar) {
+    let dependencies = meta.required_dependencies();
     let icon_src = if let Some(icon) = meta.logo_file {
       load_image_from_jar(&mut jar, &icon)
         .map(ImageWrapper::from)
@@ -141,6 +239,8 @@ pub async fn get_mod_info_from_jar(path: &PathBuf) -> SJMCLResult<LocalModInfo>
     return Ok(LocalModInfo {
       icon_src,
       enabled,
+      mod_id: meta.modid,
+      dependencies,
       name: meta.name.unwrap_or_default(),
       translated_name: None,
       version: meta.version.unwrap_or_default(),
@@ -148,6 +248,7 @@ pub async fn get_mod_info_from_jar(path: &PathBuf) -> SJMCLResult<LocalModInfo>
       description: meta.description.unwrap_or_default(),
       translated_description: None,
       potential_incompatibility: false,
+      children: get_nested_mod_infos(&mut jar, 0),
       loader_type: ModLoaderType::Forge,
       file_path,
     });
@@ -156,6 +257,8 @@ pub async fn get_mod_info_from_jar(path: &PathBuf) -> SJMCLResult<LocalModInfo>
     return Ok(LocalModInfo {
       icon_src: Default::default(),
       enabled,
+      mod_id: meta.name.clone().unwrap_or_default(),
+      dependencies: Vec::new(),
       name: meta.name.unwrap_or_default(),
       translated_name: None,
       version: meta.version.unwrap_or_default(),
@@ -163,11 +266,13 @@ pub async fn get_mod_info_from_jar(path: &PathBuf) -> SJMCLResult<LocalModInfo>
       description: meta.description.unwrap_or_default(),
       translated_description: None,
       potential_incompatibility: false,
+      children: get_nested_mod_infos(&mut jar, 0),
       loader_type: ModLoaderType::LiteLoader,
       file_path,
     });
   }
   if let Ok(meta) = quilt::get_mod_metadata_from_jar(&mut jar) {
+    let dependencies = meta.required_dependencies();
     let icon_src = if let Some(icon) = meta.metadata.icon {
       load_image_from_jar(&mut jar, &icon)
         .map(ImageWrapper::from)
@@ -179,6 +284,8 @@ pub async fn get_mod_info_from_jar(path: &PathBuf) -> SJMCLResult<LocalModInfo>
     return Ok(LocalModInfo {
       icon_src,
       enabled,
+      mod_id: meta.id,
+      dependencies,
       name: meta.metadata.name.unwrap_or_default(),
       translated_name: None,
       version: meta.version,
@@ -186,6 +293,7 @@ pub async fn get_mod_info_from_jar(path: &PathBuf) -> SJMCLResult<LocalModInfo>
       description: meta.metadata.description.unwrap_or_default(),
       translated_description: None,
       potential_incompatibility: false,
+      children: get_nested_mod_infos(&mut jar, 0),
       loader_type: ModLoaderType::Quilt,
       file_path,
     });
@@ -205,6 +313,7 @@ pub async fn get_mod_info_from_dir(path: &Path) -> SJMCLResult<LocalModInfo> {
     .to_string();
   let enabled = !dir_name.ends_with(".disabled");
   if let Ok(meta) = fabric::get_mod_metadata_from_dir(path).await {
+    let dependencies = meta.required_dependencies();
     let icon_src = if let Some(icon) = meta.icon {
       load_image_from_dir_async(&path.join(icon))
         .await
@@ -217,6 +326,8 @@ pub async fn get_mod_info_from_dir(path: &Path) -> SJMCLResult<LocalModInfo> {
     return Ok(LocalModInfo {
       icon_src,
       enabled,
+      mod_id: meta.id,
+      dependencies,
       name: meta.name.unwrap_or_default(),
       translated_name: None,
       version: meta.version,
@@ -224,15 +335,19 @@ pub async fn get_mod_info_from_dir(path: &Path) -> SJMCLResult<LocalModInfo> {
       description: meta.description.unwrap_or_default(),
       translated_description: None,
       potential_incompatibility: false,
+      children: Vec::new(),
       loader_type: ModLoaderType::Fabric,
       file_path: path.to_path_buf(),
     });
   };
   if let Ok(mut meta) = forge::get_mod_metadata_from_dir(path).await {
     let first_mod = meta.mods.remove(0);
+    let dependencies = meta.required_dependencies(&first_mod.mod_id);
     return Ok(LocalModInfo {
       icon_src: meta.valid_logo_file.map(compress_icon).unwrap_or_default(),
       enabled,
+      mod_id: first_mod.mod_id,
+      dependencies,
       name: first_mod.display_name.unwrap_or_default(),
       translated_name: None,
       version: first_mod.version.unwrap_or_default(),
@@ -240,11 +355,13 @@ pub async fn get_mod_info_from_dir(path: &Path) -> SJMCLResult<LocalModInfo> {
       description: first_mod.description.unwrap_or_default(),
       translated_description: None,
       potential_incompatibility: false,
+      children: Vec::new(),
       loader_type: meta.loader_type, // Forge or NeoForge
       file_path: path.to_path_buf(),
     });
   }
   if let Ok(meta) = legacy_forge::get_mod_metadata_from_dir(path).await {
+    let dependencies = meta.required_dependencies();
     let icon_src = if let Some(icon) = meta.logo_file {
       load_image_from_dir_async(&path.join(icon))
         .await
@@ -257,6 +374,8 @@ pub async fn get_mod_info_from_dir(path: &Path) -> SJMCLResult<LocalModInfo> {
     return Ok(LocalModInfo {
       icon_src,
       enabled,
+      mod_id: meta.modid,
+      dependencies,
       name: meta.name.unwrap_or_default(),
       translated_name: None,
       version: meta.version.unwrap_or_default(),
@@ -264,6 +383,7 @@ pub async fn get_mod_info_from_dir(path: &Path) -> SJMCLResult<LocalModInfo> {
       description: meta.description.unwrap_or_default(),
       translated_description: None,
       potential_incompatibility: false,
+      children: Vec::new(),
       loader_type: ModLoaderType::Forge,
       file_path: path.to_path_buf(),
     });
@@ -272,6 +392,8 @@ pub async fn get_mod_info_from_dir(path: &Path) -> SJMCLResult<LocalModInfo> {
     return Ok(LocalModInfo {
       icon_src: Default::default(),
       enabled,
+      mod_id: meta.name.clone().unwrap_or_default(),
+      dependencies: Vec::new(),
       name: meta.name.unwrap_or_default(),
       translated_name: None,
       version: meta.version.unwrap_or_default(),
@@ -279,11 +401,13 @@ pub async fn get_mod_info_from_dir(path: &Path) -> SJMCLResult<LocalModInfo> {
       description: meta.description.unwrap_or_default(),
       translated_description: None,
       potential_incompatibility: false,
+      children: Vec::new(),
       loader_type: ModLoaderType::LiteLoader,
       file_path: path.to_path_buf(),
     });
   }
   if let Ok(meta) = quilt::get_mod_metadata_from_dir(path).await {
+    let dependencies = meta.required_dependencies();
     let icon_src = if let Some(icon) = meta.metadata.icon {
       load_image_from_dir_async(&path.join(icon))
         .await
@@ -296,6 +420,8 @@ pub async fn get_mod_info_from_dir(path: &Path) -> SJMCLResult<LocalModInfo> {
     return Ok(LocalModInfo {
       icon_src,
       enabled,
+      mod_id: meta.id,
+      dependencies,
       name: meta.metadata.name.unwrap_or_default(),
       translated_name: None,
       version: meta.version,
@@ -303,6 +429,7 @@ pub async fn get_mod_info_from_dir(path: &Path) -> SJMCLResult<LocalModInfo> {
       description: meta.metadata.description.unwrap_or_default(),
       translated_description: None,
       potential_incompatibility: false,
+      children: Vec::new(),
       loader_type: ModLoaderType::Quilt,
       file_path: path.to_path_buf(),
     });
@@ -314,6 +441,120 @@ pub async fn get_mod_info_from_dir(path: &Path) -> SJMCLResult<LocalModInfo> {
   )))
 }
 
+pub async fn load_local_mod_infos(
+  app: &AppHandle,
+  mods_dir: &Path,
+) -> SJMCLResult<Vec<LocalModInfo>> {
+  let valid_extensions = RegexBuilder::new(r"\.(jar|zip)(\.disabled)*$")
+    .case_insensitive(true)
+    .build()
+    .unwrap();
+
+  let mod_paths = get_files_with_regex(mods_dir, &valid_extensions).unwrap_or_default();
+  let mut mod_infos = Vec::new();
+  let mut tasks = Vec::new();
+  let semaphore = Arc::new(Semaphore::new(
+    std::thread::available_parallelism().unwrap().into(),
+  ));
+  for path in mod_paths {
+    let Some((size, modified)) = get_file_size_and_mtime(&path) else {
+      continue;
+    };
+    // hit by (path, size, mtime)
+    let cached = {
+      let cache_state = app.state::<Mutex<LocalModMetadataCache>>();
+      let cache = cache_state.lock()?;
+      cache
+        .entries
+        .get(&get_metadata_cache_key(&path))
+        .filter(|entry| entry.size == size && entry.modified == modified)
+        .map(|entry| entry.info.clone())
+    };
+    if let Some(info) = cached {
+      mod_infos.push(restore_cached_mod_info(info, &path));
+      continue;
+    }
+
+    let permit = semaphore
+      .clone()
+      .acquire_owned()
+      .await
+      .map_err(|_| InstanceError::SemaphoreAcquireFailed)?;
+    let app = app.clone();
+    let task = tokio::spawn(async move {
+      log::debug!("Load mod info from dir: {}", path.display());
+      let sha1 = calculate_sha1(&path).ok()?;
+      // hit by sha1, e.g. the same file copied from another instance
+      let cached = {
+        let cache_state = app.state::<Mutex<LocalModMetadataCache>>();
+        let cache = cache_state.lock().ok()?;
+        cache
+          .entries
+          .values()
+          .find(|entry| entry.sha1 == sha1)
+          .cloned()
+      };
+      let (info, remote) = match cached {
+        Some(entry) => (restore_cached_mod_info(entry.info, &path), entry.remote),
+        None => (get_mod_info_from_jar(&path).await.ok()?, None),
+      };
+      {
+        let cache_state = app.state::<Mutex<LocalModMetadataCache>>();
+        let mut cache = cache_state.lock().ok()?;
+        cache.entries.insert(
+          get_metadata_cache_key(&path),
+          LocalModMetadataEntry {
+            size,
+            modified,
+            sha1,
+            info: info.clone(),
+            remote,
+          },
+        );
+      }
+      drop(permit);
+      Some(info)
+    });
+    tasks.push(task);
+  }
+  #[cfg(debug_assertions)]
+  {
+    // mod information detection from folders is only used for debugging.
+    let mod_paths = crate::utils::fs::get_subdirectories(mods_dir).unwrap_or_default();
+    for path in mod_paths {
+      let permit = semaphore
+        .clone()
+        .acquire_owned()
+        .await
+        .map_err(|_| InstanceError::SemaphoreAcquireFailed)?;
+      let task = tokio::spawn(async move {
+        log::debug!("Load mod info from dir: {}", path.display());
+        let info = get_mod_info_from_dir(&path).await.ok();
+        drop(permit);
+        info
+      });
+      tasks.push(task);
+    }
+  }
+  for task in tasks {
+    if let Ok(Some(mod_info)) = task.await {
+      mod_infos.push(mod_info);
+    }
+  }
+
+  // drop the entries of mods removed from this directory
+  let cache_state = app.state::<Mutex<LocalModMetadataCache>>();
+  let mut cache = cache_state.lock()?;
+  cache.entries.retain(|key, _| {
+    Path::new(key).parent() != Some(mods_dir)
+      || Path::new(key).exists()
+      || Path::new(&format!("{}.disabled", key)).exists()
+  });
+  cache.save()?;
+
+  Ok(mod_infos)
+}
+
 pub async fn add_local_mod_translations(
   app: &AppHandle,
   mod_info: &mut LocalModInfo,
@@ -335,40 +576,54 @@ pub async fn add_local_mod_translations(
     }
   }
 
-  // Try both services concurrently and use the fastest successful response
-  let modrinth_result = {
-    let app_clone = app.clone();
-    let file_path_clone = file_path.clone();
-    tokio::spawn(async move {
-      let file_info = fetch_remote_resource_by_local_modrinth(&app_clone, &file_path_clone).await?;
-      let resource_info =
-        fetch_remote_resource_by_id_modrinth(&app_clone, &file_info.resource_id).await?;
-      Ok::<_, SJMCLError>(resource_info)
-    })
-  };
+  // Use the remote match recorded in metadata cache to skip looking up by local file
+  if let Some(remote) = get_cached_remote_match(app, &mod_info.file_path) {
+    let resource_info = match get_resource_provider(&remote.source) {
+      Ok(provider) => provider.fetch_by_id(app, &remote.file.resource_id).await,
+      Err(e) => Err(e),
+    };
+    if let Ok(resource_info) = resource_info {
+      info!("Fetched translation for mod: {}", file_name);
+      mod_info.translated_name = resource_info.translated_name.clone();
+      mod_info.translated_description = resource_info.translated_description.clone();
+      return Ok(());
+    }
+  }
 
-  let curseforge_result = {
+  // Try both services concurrently, Modrinth is preferred if both are successful
+  let lookups = [
+    OtherResourceSource::Modrinth,
+    OtherResourceSource::CurseForge,
+  ]
+  .into_iter()
+  .map(|source| {
     let app_clone = app.clone();
     let file_path_clone = file_path.clone();
     tokio::spawn(async move {
-      let file_info =
-        fetch_remote_resource_by_local_curseforge(&app_clone, &file_path_clone).await?;
-      let resource_info =
-        fetch_remote_resource_by_id_curseforge(&app_clone, &file_info.resource_id).await?;
-      Ok::<_, SJMCLError>(resource_info)
+      let provider = get_resource_provider(&source)?;
+      let file_info = provider
+        .fetch_by_local_file(&app_clone, &file_path_clone)
+        .await?;
+      let resource_info = provider
+        .fetch_by_id(&app_clone, &file_info.resource_id)
+        .await?;
+      Ok::<_, SJMCLError>((source, file_info, resource_info))
     })
-  };
-
-  let (modrinth_res, curseforge_res) = tokio::join!(modrinth_result, curseforge_result);
-
-  // Prefer Modrinth result if both are successful
-  let final_result = match (modrinth_res, curseforge_res) {
-    (Ok(Ok(modrinth_data)), _) => Some(modrinth_data),
-    (_, Ok(Ok(curseforge_data))) => Some(curseforge_data),
-    _ => None,
-  };
+  });
+  let final_result = join_all(lookups)
+    .await
+    .into_iter()
+    .find_map(|res| res.ok()?.ok());
 
-  if let Some(resource_info) = final_result {
+  if let Some((source, file_info, resource_info)) = final_result {
+    set_cached_remote_match(
+      app,
+      &mod_info.file_path,
+      LocalModRemoteMatch {
+        source,
+        file: file_info,
+      },
+    );
     info!("Fetched translation for mod: {}", file_name);
     mod_info.translated_name = resource_info.translated_name.clone();
     mod_info.translated_description = resource_info.translated_description.clone();
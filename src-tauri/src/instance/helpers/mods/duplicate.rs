@@ -0,0 +1,71 @@
+use crate::instance::models::misc::LocalModInfo;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fs;
+use std::time::SystemTime;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateModGroup {
+  pub mod_id: String,
+  pub winner: LocalModInfo,
+  pub duplicates: Vec<LocalModInfo>,
+}
+
+fn get_modified_time(mod_info: &LocalModInfo) -> SystemTime {
+  fs::metadata(&mod_info.file_path)
+    .and_then(|metadata| metadata.modified())
+    .unwrap_or(SystemTime::UNIX_EPOCH)
+}
+
+// the numbers in the version, most mod versions are not semver, e.g. `1.20.1-0.5.3`, `mc1.20-2.1`
+fn parse_version_numbers(version: &str) -> Vec<u64> {
+  version
+    .split(|c: char| !c.is_ascii_digit())
+    .filter_map(|part| part.parse().ok())
+    .collect()
+}
+
+fn compare_mod_versions(a: &str, b: &str) -> Ordering {
+  match (Version::parse(a), Version::parse(b)) {
+    (Ok(va), Ok(vb)) => va.cmp(&vb),
+    _ => parse_version_numbers(a).cmp(&parse_version_numbers(b)),
+  }
+}
+
+// the higher version wins, then the most recently modified file, whether enabled or not.
+fn compare_mod_priority(a: &LocalModInfo, b: &LocalModInfo) -> Ordering {
+  compare_mod_versions(&a.version, &b.version)
+    .then_with(|| get_modified_time(a).cmp(&get_modified_time(b)))
+}
+
+pub fn find_duplicate_mods(mod_infos: &[LocalModInfo]) -> Vec<DuplicateModGroup> {
+  let mut groups: HashMap<String, Vec<LocalModInfo>> = HashMap::new();
+  for mod_info in mod_infos {
+    if mod_info.mod_id.is_empty() {
+      continue;
+    }
+    groups
+      .entry(mod_info.mod_id.to_lowercase())
+      .or_default()
+      .push(mod_info.clone());
+  }
+
+  let mut duplicate_groups: Vec<DuplicateModGroup> = groups
+    .into_values()
+    .filter(|group| group.len() > 1)
+    .map(|mut group| {
+      group.sort_by(|a, b| compare_mod_priority(b, a));
+      let winner = group.remove(0);
+      DuplicateModGroup {
+        mod_id: winner.mod_id.clone(),
+        winner,
+        duplicates: group,
+      }
+    })
+    .collect();
+  duplicate_groups.sort_by(|a, b| a.mod_id.cmp(&b.mod_id));
+  duplicate_groups
+}
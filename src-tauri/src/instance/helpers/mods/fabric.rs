@@ -18,6 +18,14 @@ pub struct FabricModMetadata {
   pub icon: Option<String>,
   pub authors: Option<Value>,
   pub contact: Option<HashMap<String, String>>,
+  #[serde(default)]
+  pub depends: HashMap<String, Value>,
+}
+
+impl FabricModMetadata {
+  pub fn required_dependencies(&self) -> Vec<String> {
+    self.depends.keys().cloned().collect()
+  }
 }
 
 pub fn get_mod_metadata_from_jar<R: Read + Seek>(
@@ -6,6 +6,7 @@ use crate::instance::models::misc::ModLoaderType;
 use crate::utils::image::{load_image_from_dir_async, load_image_from_jar, ImageWrapper};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::io::{Cursor, Read, Seek};
 use std::path::Path;
 use zip::ZipArchive;
@@ -21,10 +22,31 @@ pub struct ForgeModMetadata {
   pub mods: Vec<ForgeModSubItem>,
   // some non-standard mods write logo_file field in toml meta section.
   pub logo_file: Option<String>,
+  // keyed by the mod id which declares the dependencies
+  pub dependencies: HashMap<String, Value>,
   // not in file, added by sjmcl
   pub valid_logo_file: Option<ImageWrapper>,
 }
 
+impl ForgeModMetadata {
+  pub fn required_dependencies(&self, mod_id: &str) -> Vec<String> {
+    let Some(Value::Array(deps)) = self.dependencies.get(mod_id) else {
+      return Vec::new();
+    };
+    deps
+      .iter()
+      .filter(|dep| {
+        // forge uses `mandatory`, neoforge uses `type`
+        dep["mandatory"].as_bool().unwrap_or(false)
+          || dep["type"]
+            .as_str()
+            .is_some_and(|t| t.eq_ignore_ascii_case("required"))
+      })
+      .filter_map(|dep| dep["modId"].as_str().map(|id| id.to_string()))
+      .collect()
+  }
+}
+
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase", default)]
 pub struct ForgeModSubItem {
@@ -70,6 +92,7 @@ pub fn get_mod_metadata_from_jar<R: Read + Seek>(
           license: String::new(),
           mods: vec![ForgeModSubItem::default()],
           logo_file: None,
+          dependencies: HashMap::new(),
           valid_logo_file: None,
         })
       } else {
@@ -139,6 +162,7 @@ pub async fn get_mod_metadata_from_dir(dir_path: &Path) -> SJMCLResult<ForgeModM
           license: String::new(),
           mods: vec![ForgeModSubItem::default()],
           logo_file: None,
+          dependencies: HashMap::new(),
           valid_logo_file: None,
         })
       } else {
@@ -0,0 +1,143 @@
+// Read mods nested in a mod jar (Jar-in-Jar)
+// https://wiki.fabricmc.net/documentation:fabric_mod_json_spec#jars
+// https://docs.neoforged.net/docs/gettingstarted/modfiles/#jar-in-jar
+use crate::instance::helpers::mods::{fabric, forge, legacy_forge, quilt};
+use crate::instance::models::misc::NestedModInfo;
+use serde_json::Value;
+use std::io::{Cursor, Read, Seek};
+use std::path::Path;
+use zip::ZipArchive;
+
+const MAX_NESTED_DEPTH: usize = 4;
+
+struct NestedJarEntry {
+  path: String,
+  // fallback id and version from forge jarjar metadata, for library jars without mod metadata
+  artifact: Option<String>,
+  version: Option<String>,
+}
+
+fn read_json_entry<R: Read + Seek>(jar: &mut ZipArchive<R>, name: &str) -> Option<Value> {
+  let file = jar.by_name(name).ok()?;
+  serde_json::from_reader(file).ok()
+}
+
+fn get_nested_jar_entries<R: Read + Seek>(jar: &mut ZipArchive<R>) -> Vec<NestedJarEntry> {
+  let mut entries = Vec::new();
+  // fabric: "jars": [{ "file": "META-INF/jars/xxx.jar" }]
+  if let Some(Value::Array(jars)) =
+    read_json_entry(jar, "fabric.mod.json").map(|v| v["jars"].clone())
+  {
+    entries.extend(jars.iter().filter_map(|j| {
+      j["file"].as_str().map(|path| NestedJarEntry {
+        path: path.to_string(),
+        artifact: None,
+        version: None,
+      })
+    }));
+  }
+  // quilt: "quilt_loader": { "jars": ["META-INF/jars/xxx.jar"] }
+  if let Some(Value::Array(jars)) =
+    read_json_entry(jar, "quilt.mod.json").map(|v| v["quilt_loader"]["jars"].clone())
+  {
+    entries.extend(jars.iter().filter_map(|j| {
+      j.as_str().map(|path| NestedJarEntry {
+        path: path.to_string(),
+        artifact: None,
+        version: None,
+      })
+    }));
+  }
+  // forge and neoforge: META-INF/jarjar/metadata.json
+  if let Some(Value::Array(jars)) =
+    read_json_entry(jar, "META-INF/jarjar/metadata.json").map(|v| v["jars"].clone())
+  {
+    entries.extend(jars.iter().filter_map(|j| {
+      j["path"].as_str().map(|path| NestedJarEntry {
+        path: path.to_string(),
+        artifact: j["identifier"]["artifact"].as_str().map(|s| s.to_string()),
+        version: j["version"]["artifactVersion"]
+          .as_str()
+          .map(|s| s.to_string()),
+      })
+    }));
+  }
+  entries
+}
+
+fn get_nested_mod_info<R: Read + Seek>(
+  jar: &mut ZipArchive<R>,
+  entry: NestedJarEntry,
+  depth: usize,
+) -> Option<NestedModInfo> {
+  let mut buf = Vec::new();
+  jar.by_name(&entry.path).ok()?.read_to_end(&mut buf).ok()?;
+  let mut nested_jar = ZipArchive::new(Cursor::new(buf)).ok()?;
+
+  let (mod_id, name, version) = if let Ok(meta) = fabric::get_mod_metadata_from_jar(&mut nested_jar)
+  {
+    (meta.id, meta.name.unwrap_or_default(), meta.version)
+  } else if let Ok(meta) = quilt::get_mod_metadata_from_jar(&mut nested_jar) {
+    (
+      meta.id,
+      meta.metadata.name.unwrap_or_default(),
+      meta.version,
+    )
+  } else if let Ok(mut meta) = forge::get_mod_metadata_from_jar(&mut nested_jar) {
+    let first_mod = meta.mods.remove(0);
+    (
+      first_mod.mod_id,
+      first_mod.display_name.unwrap_or_default(),
+      first_mod.version.unwrap_or_default(),
+    )
+  } else if let Ok(meta) = legacy_forge::get_mod_metadata_from_jar(&mut nested_jar) {
+    (
+      meta.modid,
+      meta.name.unwrap_or_default(),
+      meta.version.unwrap_or_default(),
+    )
+  } else {
+    Default::default()
+  };
+
+  let file_stem = Path::new(&entry.path)
+    .file_stem()
+    .unwrap_or_default()
+    .to_string_lossy()
+    .to_string();
+  let mod_id = if !mod_id.is_empty() {
+    mod_id
+  } else {
+    entry.artifact.unwrap_or(file_stem)
+  };
+  let version = if !version.is_empty() && version != "${file.jarVersion}" {
+    version
+  } else {
+    entry.version.unwrap_or_default()
+  };
+
+  Some(NestedModInfo {
+    name: if name.is_empty() {
+      mod_id.clone()
+    } else {
+      name
+    },
+    mod_id,
+    version,
+    file_path: entry.path,
+    children: get_nested_mod_infos(&mut nested_jar, depth + 1),
+  })
+}
+
+pub fn get_nested_mod_infos<R: Read + Seek>(
+  jar: &mut ZipArchive<R>,
+  depth: usize,
+) -> Vec<NestedModInfo> {
+  if depth >= MAX_NESTED_DEPTH {
+    return Vec::new();
+  }
+  get_nested_jar_entries(jar)
+    .into_iter()
+    .filter_map(|entry| get_nested_mod_info(jar, entry, depth))
+    .collect()
+}
@@ -20,6 +20,19 @@ pub struct LegacyForgeModMetadata {
   pub update_url: Option<String>,
   pub credits: Option<String>,
   pub author_list: Option<Vec<Value>>,
+  pub required_mods: Option<Vec<String>>,
+}
+
+impl LegacyForgeModMetadata {
+  pub fn required_dependencies(&self) -> Vec<String> {
+    // entries may carry a version range, e.g. `Forge@[10.13,)`
+    self
+      .required_mods
+      .iter()
+      .flatten()
+      .map(|dep| dep.split('@').next().unwrap_or_default().to_string())
+      .collect()
+  }
 }
 
 pub fn get_mod_metadata_from_jar<R: Read + Seek>(
@@ -1,6 +1,11 @@
+pub mod bisect;
 pub mod common;
+pub mod duplicate;
 pub mod fabric;
 pub mod forge;
+pub mod jarjar;
 pub mod legacy_forge;
 pub mod liteloader;
+pub mod profile;
 pub mod quilt;
+pub mod safe_mode;
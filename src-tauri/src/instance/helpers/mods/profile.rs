@@ -0,0 +1,89 @@
+use crate::error::SJMCLResult;
+use crate::instance::models::misc::InstanceError;
+use crate::utils::fs::get_files_with_regex;
+use regex::RegexBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ModProfile {
+  pub name: String,
+  // file names of enabled mods, without the `.disabled` suffix
+  pub enabled_mods: Vec<String>,
+}
+
+// returns (path, file name without `.disabled` suffix, enabled) of each mod file
+fn list_mod_files(mods_dir: &Path) -> SJMCLResult<Vec<(PathBuf, String, bool)>> {
+  let valid_extensions = RegexBuilder::new(r"\.(jar|zip)(\.disabled)*$")
+    .case_insensitive(true)
+    .build()
+    .unwrap();
+
+  let mut mod_files = Vec::new();
+  for path in get_files_with_regex(mods_dir, &valid_extensions).unwrap_or_default() {
+    let file_name = path.file_name().unwrap().to_string_lossy().to_string();
+    let mut base_name = file_name.as_str();
+    while let Some(stripped) = base_name.strip_suffix(".disabled") {
+      base_name = stripped;
+    }
+    let enabled = base_name.len() == file_name.len();
+    mod_files.push((path, base_name.to_string(), enabled));
+  }
+  Ok(mod_files)
+}
+
+pub fn capture_mod_profile(mods_dir: &Path, name: String) -> SJMCLResult<ModProfile> {
+  let mut enabled_mods: Vec<String> = list_mod_files(mods_dir)?
+    .into_iter()
+    .filter(|(_, _, enabled)| *enabled)
+    .map(|(_, base_name, _)| base_name)
+    .collect();
+  enabled_mods.sort();
+  Ok(ModProfile { name, enabled_mods })
+}
+
+// toggle all mod files to match the profile. if any rename fails,
+// the renames already done are reverted so the mods dir is left untouched.
+pub fn apply_mod_profile(mods_dir: &Path, profile: &ModProfile) -> SJMCLResult<()> {
+  let enabled_set: HashSet<&String> = profile.enabled_mods.iter().collect();
+
+  let mut renames = Vec::new();
+  for (path, base_name, enabled) in list_mod_files(mods_dir)? {
+    let should_enable = enabled_set.contains(&base_name);
+    if should_enable == enabled {
+      continue;
+    }
+    let new_name = if should_enable {
+      base_name
+    } else {
+      format!("{}.disabled", base_name)
+    };
+    let new_path = path.with_file_name(new_name);
+    renames.push((path, new_path));
+  }
+
+  let mut done: Vec<(PathBuf, PathBuf)> = Vec::new();
+  for (from, to) in renames {
+    if to.exists() {
+      log::error!(
+        "Failed to apply mod profile: {} already exists",
+        to.display()
+      );
+    } else if let Err(e) = fs::rename(&from, &to) {
+      log::error!("Failed to rename {}: {}", from.display(), e);
+    } else {
+      done.push((from, to));
+      continue;
+    }
+    for (from, to) in done.iter().rev() {
+      if let Err(e) = fs::rename(to, from) {
+        log::error!("Failed to roll back {}: {}", to.display(), e);
+      }
+    }
+    return Err(InstanceError::ModProfileApplyFailed.into());
+  }
+  Ok(())
+}
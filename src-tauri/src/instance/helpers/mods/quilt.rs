@@ -21,6 +21,31 @@ pub struct QuiltLoader {
   pub id: String,
   pub version: String,
   pub metadata: QuiltLoaderMetadata,
+  pub depends: Vec<Value>,
+}
+
+impl QuiltLoader {
+  pub fn required_dependencies(&self) -> Vec<String> {
+    self
+      .depends
+      .iter()
+      .filter_map(|dep| match dep {
+        Value::String(id) => Some(id.clone()),
+        Value::Object(obj)
+          if !obj
+            .get("optional")
+            .and_then(Value::as_bool)
+            .unwrap_or(false) =>
+        {
+          obj
+            .get("id")
+            .and_then(Value::as_str)
+            .map(|id| id.to_string())
+        }
+        _ => None,
+      })
+      .collect()
+  }
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
@@ -0,0 +1,103 @@
+use crate::error::SJMCLResult;
+use crate::instance::commands::RENAME_LOCK;
+use crate::instance::helpers::misc::get_instance_subdir_path_by_id;
+use crate::instance::helpers::mods::profile::{apply_mod_profile, capture_mod_profile, ModProfile};
+use crate::instance::models::misc::{Instance, InstanceError, InstanceSubdirType};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+fn get_safe_mode_profile(app: &AppHandle, instance_id: &str) -> SJMCLResult<Option<ModProfile>> {
+  let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+  let state = binding.lock()?;
+  Ok(
+    state
+      .get(instance_id)
+      .and_then(|instance| instance.safe_mode_profile.clone()),
+  )
+}
+
+async fn save_safe_mode_profile(
+  app: &AppHandle,
+  instance_id: &str,
+  profile: Option<ModProfile>,
+) -> SJMCLResult<()> {
+  let instance = {
+    let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+    let mut state = binding.lock()?;
+    let instance = state
+      .get_mut(instance_id)
+      .ok_or(InstanceError::InstanceNotFoundByID)?;
+    instance.safe_mode_profile = profile;
+    instance.clone()
+  };
+  instance.save_json_cfg().await?;
+  Ok(())
+}
+
+// disable all mods for a safe mode launch. the enabled mods are saved in the instance config
+// first, so that they are restored at the next startup if the launcher exits before the game.
+pub async fn enter_safe_mode(app: &AppHandle, instance_id: &String) -> SJMCLResult<()> {
+  let mods_dir = get_instance_subdir_path_by_id(app, instance_id, &InstanceSubdirType::Mods)
+    .ok_or(InstanceError::InstanceNotFoundByID)?;
+  // still in safe mode from a run not restored yet, the mods enabled now are not the original ones
+  let original = match get_safe_mode_profile(app, instance_id)? {
+    Some(profile) => profile,
+    None => {
+      let original = {
+        let _lock = RENAME_LOCK.lock().expect("Failed to acquire lock");
+        capture_mod_profile(&mods_dir, "safe-mode".to_string())?
+      };
+      save_safe_mode_profile(app, instance_id, Some(original.clone())).await?;
+      original
+    }
+  };
+
+  let _lock = RENAME_LOCK.lock().expect("Failed to acquire lock");
+  apply_mod_profile(
+    &mods_dir,
+    &ModProfile {
+      enabled_mods: Vec::new(),
+      ..original
+    },
+  )
+}
+
+// restore the mods disabled by `enter_safe_mode`, does nothing if the instance is not in safe mode.
+pub async fn exit_safe_mode(app: &AppHandle, instance_id: &String) -> SJMCLResult<()> {
+  let Some(profile) = get_safe_mode_profile(app, instance_id)? else {
+    return Ok(());
+  };
+
+  let mods_dir = get_instance_subdir_path_by_id(app, instance_id, &InstanceSubdirType::Mods)
+    .ok_or(InstanceError::InstanceNotFoundByID)?;
+  {
+    let _lock = RENAME_LOCK.lock().expect("Failed to acquire lock");
+    apply_mod_profile(&mods_dir, &profile)?;
+  }
+  save_safe_mode_profile(app, instance_id, None).await
+}
+
+// restore the instances left in safe mode by a launcher exiting before the game, called at startup.
+pub async fn exit_interrupted_safe_modes(app: &AppHandle) {
+  let instance_ids: Vec<String> = {
+    let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+    let Ok(state) = binding.lock() else {
+      return;
+    };
+    state
+      .values()
+      .filter(|instance| instance.safe_mode_profile.is_some())
+      .map(|instance| instance.id.clone())
+      .collect()
+  };
+  for instance_id in instance_ids {
+    if let Err(e) = exit_safe_mode(app, &instance_id).await {
+      log::error!(
+        "Failed to restore the mods of {} after safe mode: {:?}",
+        instance_id,
+        e
+      );
+    }
+  }
+}
@@ -1,6 +1,10 @@
 use crate::instance::constants::INSTANCE_CFG_FILE_NAME;
 use crate::instance::helpers::game_version::{compare_game_versions, get_major_game_version};
+use crate::instance::helpers::modpack::update::InstalledModpack;
+use crate::instance::helpers::mods::bisect::ModBisectSession;
+use crate::instance::helpers::mods::profile::ModProfile;
 use crate::launcher_config::models::GameConfig;
+use crate::resource::models::{ModUpdateHistoryEntry, OtherResourceSource};
 use crate::storage::{load_json_async, save_json_async};
 use crate::utils::image::ImageWrapper;
 use serde::{Deserialize, Serialize};
@@ -35,6 +39,7 @@ pub enum ModLoaderType {
   NeoForge,
   LiteLoader,
   Quilt,
+  Cleanroom,
 }
 
 impl FromStr for ModLoaderType {
@@ -49,6 +54,7 @@ impl FromStr for ModLoaderType {
       "neoforge" => Ok(ModLoaderType::NeoForge),
       "liteloader" => Ok(ModLoaderType::LiteLoader),
       "quilt" => Ok(ModLoaderType::Quilt),
+      "cleanroom" => Ok(ModLoaderType::Cleanroom),
       _ => Err(format!("Unsupported ModLoaderType: {}", input)),
     }
   }
@@ -59,12 +65,23 @@ impl ModLoaderType {
     match self {
       &ModLoaderType::Unknown => "/images/icons/JEIcon_Release.png",
       &ModLoaderType::Fabric => "/images/icons/Fabric.png",
-      &ModLoaderType::Forge | &ModLoaderType::LegacyForge => "/images/icons/Anvil.png",
+      &ModLoaderType::Forge | &ModLoaderType::LegacyForge | &ModLoaderType::Cleanroom => {
+        "/images/icons/Anvil.png"
+      }
       &ModLoaderType::NeoForge => "/images/icons/NeoForge.png",
       &ModLoaderType::LiteLoader => "/images/icons/LiteLoader.png",
       &ModLoaderType::Quilt => "/images/icons/Quilt.png",
     }
   }
+
+  // whether mods made for `mod_loader_type` can be loaded, Cleanroom is a fork of Forge 1.12.2
+  pub fn can_load(&self, mod_loader_type: &ModLoaderType) -> bool {
+    self == mod_loader_type
+      || matches!(
+        (self, mod_loader_type),
+        (ModLoaderType::Cleanroom, ModLoaderType::Forge)
+      )
+  }
 }
 
 #[derive(Debug, PartialEq, Eq, Deserialize, Clone, Serialize, Default)]
@@ -102,6 +119,13 @@ structstruck::strike! {
     pub use_spec_game_config: bool,
     // if use_spec_game_config is false, this field is ignored
     pub spec_game_config: Option<GameConfig>,
+    pub mod_profiles: Vec<ModProfile>,
+    pub mod_bisect: Option<ModBisectSession>,
+    // enabled mods before a safe mode launch, restored when the game exits or at the next startup
+    pub safe_mode_profile: Option<ModProfile>,
+    pub mod_update_history: Vec<ModUpdateHistoryEntry>,
+    // the modpack which the instance is installed from
+    pub modpack: Option<InstalledModpack>,
   }
 }
 
@@ -139,6 +163,7 @@ pub struct InstanceSummary {
   pub support_quick_play: bool,
   pub use_spec_game_config: bool,
   pub is_version_isolated: bool,
+  pub modpack_source: Option<OtherResourceSource>,
 }
 
 impl InstanceSummary {
@@ -167,6 +192,10 @@ impl InstanceSummary {
         .is_ge(),
       use_spec_game_config: instance.use_spec_game_config,
       is_version_isolated,
+      modpack_source: instance
+        .modpack
+        .as_ref()
+        .map(|modpack| modpack.source.clone()),
     }
   }
 }
@@ -176,6 +205,8 @@ impl InstanceSummary {
 pub struct LocalModInfo {
   pub icon_src: ImageWrapper,
   pub enabled: bool,
+  pub mod_id: String,
+  pub dependencies: Vec<String>,
   pub name: String,
   pub translated_name: Option<String>,
   pub version: String,
@@ -185,11 +216,50 @@ pub struct LocalModInfo {
   pub description: String,
   pub translated_description: Option<String>,
   pub potential_incompatibility: bool,
+  // mods bundled in this jar (Jar-in-Jar)
+  pub children: Vec<NestedModInfo>,
+}
+
+impl LocalModInfo {
+  // ids of this mod and all the mods nested in it
+  pub fn provided_mod_ids(&self) -> Vec<String> {
+    fn collect(children: &[NestedModInfo], ids: &mut Vec<String>) {
+      for child in children {
+        ids.push(child.mod_id.clone());
+        collect(&child.children, ids);
+      }
+    }
+    let mut ids = vec![self.mod_id.clone()];
+    collect(&self.children, &mut ids);
+    ids
+  }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NestedModInfo {
+  pub mod_id: String,
+  pub name: String,
+  pub version: String,
+  // path inside the parent jar
+  pub file_path: String,
+  pub children: Vec<NestedModInfo>,
+}
+
+impl LocalModInfo {
+  // mods are identified by their metadata mod id, the name is only used for jars without one
+  fn identity_key(&self) -> String {
+    if self.mod_id.is_empty() {
+      self.name.to_lowercase()
+    } else {
+      self.mod_id.to_lowercase()
+    }
+  }
 }
 
 impl PartialEq for LocalModInfo {
   fn eq(&self, other: &Self) -> bool {
-    self.name.to_lowercase() == other.name.to_lowercase() && self.version == other.version
+    self.identity_key() == other.identity_key() && self.version == other.version
   }
 }
 
@@ -202,10 +272,13 @@ impl PartialOrd for LocalModInfo {
 }
 impl Ord for LocalModInfo {
   fn cmp(&self, other: &Self) -> Ordering {
-    match self.name.to_lowercase().cmp(&other.name.to_lowercase()) {
-      Ordering::Equal => self.version.cmp(&other.version),
-      order => order,
-    }
+    // sorted by name for display, equal only if the mod id and version are equal
+    self
+      .name
+      .to_lowercase()
+      .cmp(&other.name.to_lowercase())
+      .then_with(|| self.identity_key().cmp(&other.identity_key()))
+      .then_with(|| self.version.cmp(&other.version))
   }
 }
 
@@ -273,6 +346,13 @@ pub enum InstanceError {
   ProcessorExecutionFailed,
   SemaphoreAcquireFailed,
   LoaderInstallerNotFound,
+  ModProfileNotFound,
+  ModProfileApplyFailed,
+  ModBisectInProgress,
+  ModBisectNotInProgress,
+  NotModpackInstance,
+  ModpackVersionMismatch,
+  ModpackFileHashMismatch,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
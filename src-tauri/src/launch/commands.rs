@@ -5,6 +5,7 @@ use crate::account::models::PlayerType;
 use crate::error::SJMCLResult;
 use crate::instance::helpers::client_json::{replace_native_libraries, McClientInfo};
 use crate::instance::helpers::misc::{get_instance_game_config, get_instance_subdir_paths};
+use crate::instance::helpers::mods::safe_mode::{enter_safe_mode, exit_safe_mode};
 use crate::instance::models::misc::{Instance, InstanceError, InstanceSubdirType, ModLoaderStatus};
 use crate::launch::helpers::command_generator::{
   export_full_launch_command, generate_launch_command, LaunchCommand,
@@ -23,7 +24,8 @@ use crate::launcher_config::helpers::java::refresh_and_update_javas;
 use crate::launcher_config::models::{
   FileValidatePolicy, JavaInfo, LauncherConfig, LauncherVisiablity,
 };
-use crate::resource::helpers::misc::get_source_priority_list;
+use crate::resource::helpers::misc::{get_first_source_for, get_source_priority_list};
+use crate::resource::models::ResourceType;
 use crate::storage::load_json_async;
 use crate::tasks::commands::schedule_progressive_task_group;
 use crate::utils::fs::{create_zip_from_dirs, manage_permissions_unix, PermissionOperation};
@@ -77,6 +79,7 @@ pub async fn select_suitable_jre(
       .java_version
       .as_ref()
       .map_or(0i32, |v| v.major_version),
+    &client_info.release_time,
   )
   .await?;
 
@@ -163,18 +166,20 @@ pub async fn validate_game_files(
     let launcher_config = launcher_config_state.lock()?;
     get_source_priority_list(&launcher_config)
   };
+  let libraries_source = get_first_source_for(&priority_list, ResourceType::Libraries);
+  let assets_source = get_first_source_for(&priority_list, ResourceType::Assets);
 
   // validate game files
   let incomplete_files = match validate_policy {
     FileValidatePolicy::Disable => return Ok(()), // skip
     FileValidatePolicy::Normal => [
-      get_invalid_library_files(priority_list[0], libraries_dir, &client_info, false).await?,
-      get_invalid_assets(&app, &client_info, priority_list[0], assets_dir, false).await?,
+      get_invalid_library_files(libraries_source, libraries_dir, &client_info, false).await?,
+      get_invalid_assets(&app, &client_info, assets_source, assets_dir, false).await?,
     ]
     .concat(),
     FileValidatePolicy::Full => [
-      get_invalid_library_files(priority_list[0], libraries_dir, &client_info, true).await?,
-      get_invalid_assets(&app, &client_info, priority_list[0], assets_dir, true).await?,
+      get_invalid_library_files(libraries_source, libraries_dir, &client_info, true).await?,
+      get_invalid_assets(&app, &client_info, assets_source, assets_dir, true).await?,
     ]
     .concat(),
   };
@@ -248,6 +253,8 @@ pub async fn launch_game(
   launching_queue_state: State<'_, Mutex<Vec<LaunchingState>>>,
   quick_play_singleplayer: Option<String>,
   quick_play_multiplayer: Option<String>,
+  safe_mode: Option<bool>,
+  mod_bisect: Option<bool>,
 ) -> SJMCLResult<()> {
   let (id, selected_java, game_config, instance) = {
     let mut launching_queue = launching_queue_state.lock()?;
@@ -255,6 +262,8 @@ pub async fn launch_game(
       .last_mut()
       .ok_or(LaunchError::LaunchingStateNotFound)?;
     launching.current_step = 4;
+    launching.safe_mode = safe_mode.unwrap_or(false);
+    launching.mod_bisect = mod_bisect.unwrap_or(false);
     (
       launching.id,
       launching.selected_java.clone(),
@@ -292,58 +301,76 @@ pub async fn launch_game(
   let full_cmd = export_full_launch_command(&class_paths, &cmd_args, &selected_java.exec_path);
   println!("[Launch Command] {}", full_cmd);
 
-  let precall_cmd = game_config.advanced.custom_commands.precall_command.clone();
-  if !precall_cmd.trim().is_empty() {
-    let _ = execute_command_line(&precall_cmd);
+  // safe mode: disable all mods for this run only, they are restored in `monitor_process`,
+  // below if the launch fails before the game process is monitored, or at the next startup.
+  let safe_mode = safe_mode.unwrap_or(false);
+  if safe_mode {
+    enter_safe_mode(&app, &instance_id).await?;
   }
 
-  // execute launch command
-  #[cfg(target_os = "windows")]
-  cmd_base.creation_flags(0x08000000);
-
-  let child = cmd_base
-    .current_dir(&work_dir)
-    .env("CLASSPATH", class_paths.join(get_separator()))
-    .args(cmd_args)
-    .stdout(Stdio::piped())
-    .stderr(Stdio::piped())
-    .spawn()?;
-
-  let pid = child.id();
+  let launch_result: SJMCLResult<()> = async {
+    let precall_cmd = game_config.advanced.custom_commands.precall_command.clone();
+    if !precall_cmd.trim().is_empty() {
+      let _ = execute_command_line(&precall_cmd);
+    }
 
-  // set process priority (if error, keep silent)
-  let _ = set_process_priority(pid, &game_config.performance.process_priority);
+    // execute launch command
+    #[cfg(target_os = "windows")]
+    cmd_base.creation_flags(0x08000000);
+
+    let child = cmd_base
+      .current_dir(&work_dir)
+      .env("CLASSPATH", class_paths.join(get_separator()))
+      .args(cmd_args)
+      .stdout(Stdio::piped())
+      .stderr(Stdio::piped())
+      .spawn()?;
+
+    let pid = child.id();
+
+    // set process priority (if error, keep silent)
+    let _ = set_process_priority(pid, &game_config.performance.process_priority);
+
+    {
+      let mut launching_queue = launching_queue_state.lock()?;
+      let launching = launching_queue
+        .last_mut()
+        .ok_or(LaunchError::LaunchingStateNotFound)?;
+      launching.pid = pid;
+      launching.full_command = full_cmd;
+    }
 
-  {
-    let mut launching_queue = launching_queue_state.lock()?;
-    let launching = launching_queue
-      .last_mut()
-      .ok_or(LaunchError::LaunchingStateNotFound)?;
-    launching.pid = pid;
-    launching.full_command = full_cmd;
+    // wait for the game window, create log window if needed
+    let (tx, rx) = mpsc::channel();
+    monitor_process(
+      app.clone(),
+      id,
+      child,
+      instance_id,
+      game_config.display_game_log,
+      &game_config.game_window.custom_title,
+      game_config.launcher_visibility.clone(),
+      tx,
+      Some(
+        game_config
+          .advanced
+          .custom_commands
+          .post_exit_command
+          .clone(),
+      ),
+    )
+    .await?;
+    let _ = rx.recv();
+    Ok(())
   }
+  .await;
 
-  // wait for the game window, create log window if needed
-  let (tx, rx) = mpsc::channel();
-  monitor_process(
-    app.clone(),
-    id,
-    child,
-    instance_id,
-    game_config.display_game_log,
-    &game_config.game_window.custom_title,
-    game_config.launcher_visibility.clone(),
-    tx,
-    Some(
-      game_config
-        .advanced
-        .custom_commands
-        .post_exit_command
-        .clone(),
-    ),
-  )
-  .await?;
-  let _ = rx.recv();
+  if let Err(e) = launch_result {
+    if safe_mode {
+      let _ = exit_safe_mode(&app, &instance_id).await;
+    }
+    return Err(e);
+  }
 
   if game_config.launcher_visibility != LauncherVisiablity::Always {
     let _ = app
@@ -1,3 +1,4 @@
 pub const GAME_PROCESS_OUTPUT_EVENT: &str = "launch:game-process-output";
+pub const MOD_BISECT_RUN_EXITED_EVENT: &str = "launch:mod-bisect-run-exited";
 
 pub const READY_FLAG: &[&str] = &["render thread", "lwjgl version", "lwjgl openal"];
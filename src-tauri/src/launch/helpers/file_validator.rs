@@ -98,6 +98,8 @@ pub async fn get_invalid_library_files(
         dest: file_path,
         filename: None,
         sha1: Some(artifact.sha1.clone()),
+        alt_srcs: vec![],
+        served_src: None,
       })))
     }
   });
@@ -343,6 +345,8 @@ pub async fn get_invalid_assets(
           dest,
           filename: None,
           sha1: Some(item.hash.clone()),
+          alt_srcs: vec![],
+          served_src: None,
         })))
       }
     }
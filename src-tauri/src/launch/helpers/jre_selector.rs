@@ -1,8 +1,11 @@
 use crate::error::SJMCLResult;
-use crate::instance::helpers::game_version::compare_game_versions;
+use crate::instance::helpers::game_version::{
+  compare_game_versions, get_game_version_release_time,
+};
 use crate::instance::models::misc::Instance;
 use crate::launch::models::LaunchError;
 use crate::launcher_config::models::{GameJava, JavaInfo};
+use chrono::DateTime;
 use std::cmp::Ordering;
 use tauri::AppHandle;
 
@@ -12,6 +15,7 @@ pub async fn select_java_runtime(
   java_list: &[JavaInfo],
   instance: &Instance,
   client_json_req: i32,
+  client_release_time: &str,
   // TODO: pass client and mod loader info to calculate version with more rules, instead of passing require version
   // ref: https://github.com/Hex-Dragon/PCL2/blob/16e09c792ce8c13435fc6827e6da54170aaa3bc0/Plain%20Craft%20Launcher%202/Modules/Minecraft/ModLaunch.vb#L1130
 ) -> SJMCLResult<JavaInfo> {
@@ -23,7 +27,8 @@ pub async fn select_java_runtime(
       .ok_or_else(|| LaunchError::SelectedJavaUnavailable.into());
   }
 
-  let mut min_version_req = get_minimum_java_version_by_game(app, instance).await;
+  let mut min_version_req =
+    get_minimum_java_version_by_game(app, instance, client_release_time).await;
 
   if client_json_req > min_version_req {
     min_version_req = client_json_req;
@@ -46,31 +51,43 @@ pub async fn select_java_runtime(
   }
 }
 
+// the first version requiring each java version, from new to old
+const JAVA_REQUIREMENTS: [(&str, i32); 5] = [
+  ("26.1-snapshot-1", 25), // 26.1+
+  ("24w14a", 21),          // 1.20.5+
+  ("1.18-pre2", 17),       // 1.18+
+  ("21w19a", 16),          // 1.17+
+  ("17w13a", 8),           // 1.12+
+];
+
 /// Get minimum java version requirement by game client version
 /// ref: https://zh.minecraft.wiki/w/Java%E7%89%88?variant=zh-cn#%E8%BD%AF%E4%BB%B6%E9%9C%80%E6%B1%82
-async fn get_minimum_java_version_by_game(app: &AppHandle, instance: &Instance) -> i32 {
+async fn get_minimum_java_version_by_game(
+  app: &AppHandle,
+  instance: &Instance,
+  client_release_time: &str,
+) -> i32 {
+  // compare by release time in the local version manifest first, which needs no network.
+  // versions not listed there (e.g. custom builds) use the release time in their client json.
+  let release_time = get_game_version_release_time(app, &instance.version)
+    .or_else(|| DateTime::parse_from_rfc3339(client_release_time).ok());
+  let thresholds: Option<Vec<_>> = JAVA_REQUIREMENTS
+    .iter()
+    .map(|(version, java)| get_game_version_release_time(app, version).map(|time| (time, *java)))
+    .collect();
+  if let (Some(release_time), Some(thresholds)) = (release_time, thresholds) {
+    return thresholds
+      .into_iter()
+      .find(|(time, _)| release_time >= *time)
+      .map_or(0, |(_, java)| java);
+  }
+
   // only allow fallback remote fetch here in the launch process, as Java selection and command generation are used sequentially.
   // ref: https://github.com/UNIkeEN/SJMCL/pull/799
-  // 26.1(26.1-snapshot-1)+
-  if compare_game_versions(app, &instance.version, "26.1-snapshot-1", true).await >= Ordering::Equal
-  {
-    return 25;
-  }
-  // 1.20.5(24w14a)+
-  if compare_game_versions(app, &instance.version, "24w14a", false).await >= Ordering::Equal {
-    return 21;
-  }
-  // 1.18(1.18-pre2)+
-  if compare_game_versions(app, &instance.version, "1.18-pre2", false).await >= Ordering::Equal {
-    return 17;
-  }
-  // 1.17(21w19a)+
-  if compare_game_versions(app, &instance.version, "21w19a", false).await >= Ordering::Equal {
-    return 16;
-  }
-  // 1.12(17w13a)+
-  if compare_game_versions(app, &instance.version, "17w13a", false).await >= Ordering::Equal {
-    return 8;
+  for (i, (version, java)) in JAVA_REQUIREMENTS.iter().enumerate() {
+    if compare_game_versions(app, &instance.version, version, i == 0).await >= Ordering::Equal {
+      return *java;
+    }
   }
   0
 }
@@ -1,7 +1,8 @@
 use crate::error::SJMCLResult;
+use crate::instance::helpers::mods::safe_mode::exit_safe_mode;
 use crate::instance::models::misc::Instance;
 use crate::launch::constants::*;
-use crate::launch::models::{LaunchError, LaunchingState};
+use crate::launch::models::{LaunchError, LaunchingState, ModBisectRunExitedPayload};
 use crate::launcher_config::models::{LauncherVisiablity, ProcessPriority};
 use crate::utils::shell::execute_command_line;
 use crate::utils::window::create_webview_window;
@@ -225,7 +226,30 @@ pub async fn monitor_process(
 
     let start_time_lock = *start_time.lock().unwrap();
     if let Some(start_time) = start_time_lock {
-      record_play_time(app.clone(), start_time, instance_id_clone).await;
+      record_play_time(app.clone(), start_time, instance_id_clone.clone()).await;
+    }
+
+    let launching_option = {
+      let launching_queue_state = app.state::<Mutex<Vec<LaunchingState>>>();
+      let launching_queue = launching_queue_state.lock().unwrap();
+      launching_queue.iter().find(|s| s.id == id).cloned()
+    };
+
+    if launching_option.as_ref().is_some_and(|l| l.safe_mode) {
+      // restore the mods disabled for the safe mode run
+      let _ = exit_safe_mode(&app, &instance_id_clone).await;
+    }
+    if let Some(launching) = launching_option.as_ref().filter(|l| l.mod_bisect) {
+      if launching.current_step != 0 {
+        // let the user confirm the result of the bisect run, the exit code is only a hint
+        let _ = app.emit(
+          MOD_BISECT_RUN_EXITED_EVENT,
+          ModBisectRunExitedPayload {
+            instance_id: instance_id_clone.clone(),
+            exit_ok,
+          },
+        );
+      }
     }
 
     if exit_ok {
@@ -236,24 +260,16 @@ pub async fn monitor_process(
       let launching_queue_state = app.state::<Mutex<Vec<LaunchingState>>>();
       let mut launching_queue = launching_queue_state.lock().unwrap();
       launching_queue.retain(|state| state.id != id);
-    } else {
-      let launching_option = {
+    } else if let Some(launching) = launching_option {
+      if launching.current_step == 0 {
+        // it was marked as manually cancelled, then remove from launching_queue and not show game error window
         let launching_queue_state = app.state::<Mutex<Vec<LaunchingState>>>();
-        let launching_queue = launching_queue_state.lock().unwrap();
-        launching_queue.iter().find(|s| s.id == id).cloned()
-      };
-
-      if let Some(launching) = launching_option {
-        if launching.current_step == 0 {
-          // it was marked as manually cancelled, then remove from launching_queue and not show game error window
-          let launching_queue_state = app.state::<Mutex<Vec<LaunchingState>>>();
-          let mut launching_queue = launching_queue_state.lock().unwrap();
-          launching_queue.retain(|state| state.id != id);
-        } else {
-          let _ = create_webview_window(&app, &format!("game_error_{id}"), "game_error", None)
-            .await
-            .unwrap();
-        }
+        let mut launching_queue = launching_queue_state.lock().unwrap();
+        launching_queue.retain(|state| state.id != id);
+      } else {
+        let _ = create_webview_window(&app, &format!("game_error_{id}"), "game_error", None)
+          .await
+          .unwrap();
       }
     }
 
@@ -39,4 +39,15 @@ pub struct LaunchingState {
   pub full_command: String, // for export and debug
   #[default = 0] // default means not set yet
   pub pid: u32,
+  // all mods are disabled for this run, see `enter_safe_mode`
+  pub safe_mode: bool,
+  // launched by the mod bisect flow, the user is asked for the result of the run when it exits
+  pub mod_bisect: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModBisectRunExitedPayload {
+  pub instance_id: String,
+  pub exit_ok: bool,
 }
@@ -11,6 +11,8 @@ use crate::launcher_config::helpers::updater::{
 use crate::launcher_config::models::{
   GameDirectory, JavaInfo, LauncherConfig, LauncherConfigError, VersionMetaInfo,
 };
+use crate::resource::helpers::misc::register_custom_sources;
+use crate::resource::helpers::provider::register_resource_providers;
 use crate::storage::Storage;
 use crate::tasks::{commands::schedule_progressive_task_group, monitor::TaskMonitor};
 use crate::utils::fs::{generate_unique_filename, get_subdirectories};
@@ -39,6 +41,8 @@ pub fn update_launcher_config(app: AppHandle, key_path: String, value: String) -
   let key_path = camel_to_snake_case(key_path.as_str());
   config_state.partial_update(&app, &key_path, &value)?;
   config_state.save()?;
+  register_custom_sources(&config_state);
+  register_resource_providers(&config_state);
   Ok(())
 }
 
@@ -53,6 +57,8 @@ pub fn restore_launcher_config(app: AppHandle) -> SJMCLResult<LauncherConfig> {
   let preserved_fields = &["run_count"];
   state.replace_with_preserved(default_config, preserved_fields);
   state.save()?;
+  register_custom_sources(&state);
+  register_resource_providers(&state);
   Ok(state.clone())
 }
 
@@ -1,6 +1,6 @@
 use crate::error::{SJMCLError, SJMCLResult};
 use crate::launcher_config::models::{JavaInfo, LauncherConfig};
-use crate::resource::helpers::misc::{get_download_api, get_source_priority_list};
+use crate::resource::helpers::misc::{get_download_api, get_source_priority_list_for};
 use crate::resource::models::ResourceType;
 use crate::tasks::{download::DownloadParam, PTaskParam};
 use serde_json::Value;
@@ -480,7 +480,7 @@ pub async fn build_mojang_java_download_params(
     _ => "java-runtime-gamma",
   };
 
-  let priority_list = get_source_priority_list(&config);
+  let priority_list = get_source_priority_list_for(&config, ResourceType::MojangJava);
   let mut json: Option<Value> = None;
 
   for source_type in priority_list.iter() {
@@ -519,6 +519,8 @@ pub async fn build_mojang_java_download_params(
         dest: runtime_dir.join(path),
         filename: None,
         sha1: Some(sha1.into()),
+        alt_srcs: vec![],
+        served_src: None,
       }))
     })
     .collect();
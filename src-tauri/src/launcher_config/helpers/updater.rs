@@ -148,6 +148,8 @@ pub async fn download_target_version(
             dest: download_cache_dir.join(&fname),
             filename: Some(fname),
             sha1: None,
+            alt_srcs: vec![],
+            served_src: None,
           })],
           true,
         )
@@ -1,5 +1,6 @@
 use crate::launcher_config::constants::{CONFIG_PARTIAL_UPDATE_EVENT, LAUNCHER_CFG_FILE_NAME};
 use crate::partial::PartialUpdate;
+use crate::resource::models::ResourceType;
 use crate::storage::Storage;
 use crate::utils::string::snake_to_camel_case;
 use crate::utils::sys_info;
@@ -9,6 +10,7 @@ use serde::de::Deserializer;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use smart_default::SmartDefault;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use strum_macros::Display;
 use tauri::{AppHandle, Emitter};
@@ -154,6 +156,17 @@ pub struct GameDirectory {
   pub dir: PathBuf,
 }
 
+// a user-defined mirror following the official layout, resource types without a base url
+// are downloaded from the next source in the priority list.
+#[derive(Partial, Debug, PartialEq, Eq, Clone, Deserialize, Serialize, SmartDefault)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CustomDownloadSource {
+  pub name: String,
+  #[default = true]
+  pub enabled: bool,
+  pub base_urls: HashMap<ResourceType, String>,
+}
+
 // see java.net.proxy
 // https://github.com/HMCL-dev/HMCL/blob/d9e3816b8edf9e7275e4349d4fc67a5ef2e3c6cf/HMCLCore/src/main/java/org/jackhuang/hmcl/launch/DefaultLauncher.java#L114
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
@@ -221,6 +234,12 @@ structstruck::strike! {
       pub source: struct {
         #[default = "auto"]
         pub strategy: String,
+        pub custom_sources: Vec<CustomDownloadSource>,
+      },
+      // api roots of the resource platforms, empty for the official ones
+      pub resource_api: struct {
+        pub curseforge: String,
+        pub modrinth: String,
       },
       pub transmission: struct {
         #[default = true]
@@ -14,12 +14,18 @@ use account::helpers::authlib_injector::info::refresh_and_update_auth_servers;
 use account::helpers::offline::yggdrasil_server::YggdrasilServer;
 use account::models::AccountInfo;
 use instance::helpers::misc::refresh_and_update_instances;
-use instance::helpers::mods::common::LocalModTranslationsCache;
+use instance::helpers::mods::common::{LocalModMetadataCache, LocalModTranslationsCache};
+use instance::helpers::mods::safe_mode::exit_interrupted_safe_modes;
 use instance::models::misc::Instance;
 use launch::models::LaunchingState;
 use launcher_config::helpers::java::refresh_and_update_javas;
 use launcher_config::models::{JavaInfo, LauncherConfig};
-use resource::helpers::mod_db::{initialize_mod_db, ModDataBase};
+use resource::constants::HTTP_CACHE_MAX_AGE;
+use resource::helpers::http_cache::purge_http_cache;
+use resource::helpers::misc::register_custom_sources;
+use resource::helpers::mod_db::{initialize_mod_db, update_mod_db, ModDataBase};
+use resource::helpers::provider::register_resource_providers;
+use resource::helpers::source_ranking::monitor_source_ranking;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::{LazyLock, Mutex, OnceLock};
@@ -115,11 +121,26 @@ pub async fn run() {
       instance::commands::retrieve_shader_pack_list,
       instance::commands::retrieve_screenshot_list,
       instance::commands::toggle_mod_by_extension,
+      instance::commands::retrieve_duplicate_mod_list,
+      instance::commands::cleanup_duplicate_mods,
+      instance::commands::retrieve_mod_profile_list,
+      instance::commands::save_mod_profile,
+      instance::commands::delete_mod_profile,
+      instance::commands::apply_mod_profile,
+      instance::commands::retrieve_mod_bisect_session,
+      instance::commands::start_mod_bisect,
+      instance::commands::submit_mod_bisect_result,
+      instance::commands::stop_mod_bisect,
       instance::commands::create_launch_desktop_shortcut,
       instance::commands::finish_mod_loader_install,
       instance::commands::check_change_mod_loader_availablity,
       instance::commands::change_mod_loader,
+      instance::commands::remove_optifine,
       instance::commands::retrieve_modpack_meta_info,
+      instance::commands::export_packwiz_pack,
+      instance::commands::update_modpack,
+      instance::commands::verify_modpack_files,
+      instance::commands::repair_modpack_files,
       instance::commands::add_custom_instance_icon,
       launch::commands::select_suitable_jre,
       launch::commands::validate_game_files,
@@ -135,11 +156,16 @@ pub async fn run() {
       resource::commands::fetch_mod_loader_version_list,
       resource::commands::fetch_optifine_version_list,
       resource::commands::fetch_resource_list_by_name,
+      resource::commands::fetch_merged_resource_list_by_name,
       resource::commands::fetch_resource_version_packs,
       resource::commands::download_game_server,
       resource::commands::fetch_remote_resource_by_local,
+      resource::commands::check_mod_updates,
       resource::commands::update_mods,
+      resource::commands::retrieve_mod_update_history,
+      resource::commands::rollback_mod_update,
       resource::commands::fetch_remote_resource_by_id,
+      resource::commands::resolve_resource_dependencies,
       discover::commands::fetch_news_sources_info,
       discover::commands::fetch_news_post_summaries,
       tasks::commands::schedule_progressive_task_group,
@@ -175,6 +201,8 @@ pub async fn run() {
       let mut launcher_config: LauncherConfig = LauncherConfig::load().unwrap_or_default();
       launcher_config.setup_with_app(app.handle()).unwrap();
       launcher_config.save().unwrap();
+      register_custom_sources(&launcher_config);
+      register_resource_providers(&launcher_config);
       let version = launcher_config.basic_info.launcher_version.clone();
       let os = launcher_config.basic_info.platform.clone();
       let exe_sha256 = launcher_config.basic_info.exe_sha256.clone();
@@ -202,6 +230,9 @@ pub async fn run() {
       let local_mod_translations = LocalModTranslationsCache::load().unwrap_or_default();
       app.manage(Mutex::new(local_mod_translations));
 
+      let local_mod_metadata = LocalModMetadataCache::load().unwrap_or_default();
+      app.manage(Mutex::new(local_mod_metadata));
+
       let client = build_sjmcl_client(app.handle(), true, false);
       app.manage(client);
 
@@ -235,6 +266,7 @@ pub async fn run() {
       let app_handle = app.handle().clone();
       tauri::async_runtime::spawn(async move {
         refresh_and_update_instances(&app_handle, true).await;
+        exit_interrupted_safe_modes(&app_handle).await;
       });
 
       // Refresh all javas
@@ -243,10 +275,11 @@ pub async fn run() {
         refresh_and_update_javas(&app_handle).await;
       });
 
-      // Initialize mod database
+      // Initialize mod database, then replace it if a newer one is published
       let app_handle = app.handle().clone();
       tauri::async_runtime::spawn(async move {
         initialize_mod_db(&app_handle).await.unwrap_or_default();
+        update_mod_db(&app_handle).await.unwrap_or_default();
       });
 
       let app_handle = app.handle().clone();
@@ -254,6 +287,20 @@ pub async fn run() {
         tasks::background::monitor_background_process(app_handle).await;
       });
 
+      // Probe the download sources at startup and periodically for the auto strategy
+      let app_handle = app.handle().clone();
+      tauri::async_runtime::spawn(async move {
+        monitor_source_ranking(app_handle).await;
+      });
+
+      // Purge the cached responses not refreshed for a long time
+      let app_handle = app.handle().clone();
+      tauri::async_runtime::spawn(async move {
+        purge_http_cache(&app_handle, HTTP_CACHE_MAX_AGE)
+          .await
+          .unwrap_or_default();
+      });
+
       // Send statistics
       tokio::spawn(async move {
         utils::sys_info::send_statistics(version, os, exe_sha256).await;
@@ -1,32 +1,41 @@
 use crate::error::SJMCLResult;
 use crate::instance::helpers::client_json::McClientInfo;
 use crate::instance::helpers::misc::get_instance_subdir_path_by_id;
-use crate::instance::models::misc::{InstanceSubdirType, ModLoaderType};
+use crate::instance::helpers::mods::common::load_local_mod_infos;
+use crate::instance::models::misc::{Instance, InstanceError, InstanceSubdirType, ModLoaderType};
 use crate::launcher_config::models::LauncherConfig;
-use crate::resource::helpers::curseforge::{
-  fetch_remote_resource_by_id_curseforge, fetch_remote_resource_by_local_curseforge,
-  fetch_resource_list_by_name_curseforge, fetch_resource_version_packs_curseforge,
-};
+use crate::resource::helpers::dependency::resolve_required_dependencies;
+use crate::resource::helpers::loader_meta::cleanroom::get_cleanroom_meta_by_game_version;
 use crate::resource::helpers::loader_meta::fabric::get_fabric_meta_by_game_version;
 use crate::resource::helpers::loader_meta::forge::get_forge_meta_by_game_version;
+use crate::resource::helpers::loader_meta::liteloader::get_liteloader_meta_by_game_version;
 use crate::resource::helpers::loader_meta::neoforge::get_neoforge_meta_by_game_version;
 use crate::resource::helpers::loader_meta::optifine::get_optifine_meta_by_game_version;
-use crate::resource::helpers::misc::get_source_priority_list;
-use crate::resource::helpers::modrinth::{
-  fetch_remote_resource_by_id_modrinth, fetch_remote_resource_by_local_modrinth,
-  fetch_resource_list_by_name_modrinth, fetch_resource_version_packs_modrinth,
+use crate::resource::helpers::loader_meta::quilt::get_quilt_meta_by_game_version;
+use crate::resource::helpers::merged_search::fetch_resource_list_by_name_merged;
+use crate::resource::helpers::misc::get_source_priority_list_for;
+use crate::resource::helpers::mod_update::{
+  backup_mod_files, check_mod_updates_by_hashes, finish_mod_update, get_mod_update_backup_dir,
+  prune_mod_update_history, restore_mod_files,
 };
+use crate::resource::helpers::provider::get_resource_provider;
 use crate::resource::helpers::version_manifest::get_game_version_manifest;
 use crate::resource::models::{
-  GameClientResourceInfo, ModLoaderResourceInfo, ModUpdateQuery, OptiFineResourceInfo,
-  OtherResourceFileInfo, OtherResourceInfo, OtherResourceSearchQuery, OtherResourceSearchRes,
-  OtherResourceSource, OtherResourceVersionPack, OtherResourceVersionPackQuery, ResourceError,
+  GameClientResourceInfo, ModLoaderResourceInfo, ModUpdateHistoryEntry, ModUpdateHistoryItem,
+  ModUpdateInfo, ModUpdateQuery, OptiFineResourceInfo, OtherResourceFileInfo, OtherResourceInfo,
+  OtherResourceSearchQuery, OtherResourceSearchRes, OtherResourceSource, OtherResourceVersionPack,
+  OtherResourceVersionPackQuery, ResourceDependencyPlanItem, ResourceError, ResourceType,
 };
 use crate::tasks::commands::schedule_progressive_task_group;
 use crate::tasks::download::DownloadParam;
+use crate::tasks::monitor::TaskMonitor;
 use crate::tasks::PTaskParam;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
 use std::sync::Mutex;
-use tauri::{AppHandle, Manager, State};
+use tauri::{AppHandle, Emitter, Manager, State};
 use tauri_plugin_http::reqwest;
 
 #[tauri::command]
@@ -36,7 +45,7 @@ pub async fn fetch_game_version_list(
 ) -> SJMCLResult<Vec<GameClientResourceInfo>> {
   let priority_list = {
     let state = state.lock()?;
-    get_source_priority_list(&state)
+    get_source_priority_list_for(&state, ResourceType::VersionManifestV2)
   };
   get_game_version_manifest(&app, &priority_list).await
 }
@@ -61,10 +70,19 @@ pub async fn fetch_mod_loader_version_list(
   game_version: String,
   mod_loader_type: ModLoaderType,
 ) -> SJMCLResult<Vec<ModLoaderResourceInfo>> {
+  let meta_resource_type = match mod_loader_type {
+    ModLoaderType::Forge | ModLoaderType::LegacyForge => ResourceType::ForgeMeta,
+    ModLoaderType::Fabric => ResourceType::FabricMeta,
+    ModLoaderType::NeoForge => ResourceType::NeoforgeMetaNeoforge,
+    ModLoaderType::Quilt => ResourceType::QuiltMeta,
+    ModLoaderType::LiteLoader => ResourceType::Liteloader,
+    ModLoaderType::Cleanroom => ResourceType::CleanroomMaven,
+    _ => return Err(ResourceError::NoDownloadApi.into()),
+  };
   let priority_list = {
     let launcher_config_state = app.state::<Mutex<LauncherConfig>>();
     let launcher_config = launcher_config_state.lock()?;
-    get_source_priority_list(&launcher_config)
+    get_source_priority_list_for(&launcher_config, meta_resource_type)
   };
   match mod_loader_type {
     ModLoaderType::Forge | ModLoaderType::LegacyForge => {
@@ -76,6 +94,15 @@ pub async fn fetch_mod_loader_version_list(
     ModLoaderType::NeoForge => {
       Ok(get_neoforge_meta_by_game_version(&app, &priority_list, &game_version).await?)
     }
+    ModLoaderType::Quilt => {
+      Ok(get_quilt_meta_by_game_version(&app, &priority_list, &game_version).await?)
+    }
+    ModLoaderType::LiteLoader => {
+      Ok(get_liteloader_meta_by_game_version(&app, &priority_list, &game_version).await?)
+    }
+    ModLoaderType::Cleanroom => {
+      Ok(get_cleanroom_meta_by_game_version(&app, &priority_list, &game_version).await?)
+    }
     // TODO here
     _ => Err(ResourceError::NoDownloadApi.into()),
   }
@@ -89,7 +116,7 @@ pub async fn fetch_optifine_version_list(
   let priority_list = {
     let launcher_config_state = app.state::<Mutex<LauncherConfig>>();
     let launcher_config = launcher_config_state.lock()?;
-    get_source_priority_list(&launcher_config)
+    get_source_priority_list_for(&launcher_config, ResourceType::OptiFine)
   };
   get_optifine_meta_by_game_version(&app, &priority_list, &game_version).await
 }
@@ -100,13 +127,17 @@ pub async fn fetch_resource_list_by_name(
   download_source: OtherResourceSource,
   query: OtherResourceSearchQuery,
 ) -> SJMCLResult<OtherResourceSearchRes> {
-  match download_source {
-    OtherResourceSource::CurseForge => {
-      Ok(fetch_resource_list_by_name_curseforge(&app, &query).await?)
-    }
-    OtherResourceSource::Modrinth => Ok(fetch_resource_list_by_name_modrinth(&app, &query).await?),
-    _ => Err(ResourceError::NoDownloadApi.into()),
-  }
+  get_resource_provider(&download_source)?
+    .search(&app, &query)
+    .await
+}
+
+#[tauri::command]
+pub async fn fetch_merged_resource_list_by_name(
+  app: AppHandle,
+  query: OtherResourceSearchQuery,
+) -> SJMCLResult<OtherResourceSearchRes> {
+  fetch_resource_list_by_name_merged(&app, &query).await
 }
 
 #[tauri::command]
@@ -115,13 +146,9 @@ pub async fn fetch_resource_version_packs(
   download_source: OtherResourceSource,
   query: OtherResourceVersionPackQuery,
 ) -> SJMCLResult<Vec<OtherResourceVersionPack>> {
-  match download_source {
-    OtherResourceSource::CurseForge => {
-      Ok(fetch_resource_version_packs_curseforge(&app, &query).await?)
-    }
-    OtherResourceSource::Modrinth => Ok(fetch_resource_version_packs_modrinth(&app, &query).await?),
-    _ => Err(ResourceError::NoDownloadApi.into()),
-  }
+  get_resource_provider(&download_source)?
+    .fetch_version_packs(&app, &query)
+    .await
 }
 
 #[tauri::command]
@@ -153,6 +180,8 @@ pub async fn download_game_server(
       dest: dest.clone().into(),
       filename: None,
       sha1: Some(download_info.sha1.clone()),
+      alt_srcs: vec![],
+      served_src: None,
     })],
     true,
   )
@@ -167,15 +196,32 @@ pub async fn fetch_remote_resource_by_local(
   download_source: OtherResourceSource,
   file_path: String,
 ) -> SJMCLResult<OtherResourceFileInfo> {
-  match download_source {
-    OtherResourceSource::CurseForge => {
-      Ok(fetch_remote_resource_by_local_curseforge(&app, &file_path).await?)
-    }
-    OtherResourceSource::Modrinth => {
-      Ok(fetch_remote_resource_by_local_modrinth(&app, &file_path).await?)
-    }
-    _ => Err(ResourceError::NoDownloadApi.into()),
-  }
+  get_resource_provider(&download_source)?
+    .fetch_by_local_file(&app, &file_path)
+    .await
+}
+
+#[tauri::command]
+pub async fn check_mod_updates(
+  app: AppHandle,
+  instance_id: String,
+) -> SJMCLResult<Vec<ModUpdateInfo>> {
+  let (game_version, loader_type) = {
+    let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+    let state = binding.lock()?;
+    let instance = state
+      .get(&instance_id)
+      .ok_or(InstanceError::InstanceNotFoundByID)?;
+    (
+      instance.version.clone(),
+      instance.mod_loader.loader_type.clone(),
+    )
+  };
+  let mods_dir = get_instance_subdir_path_by_id(&app, &instance_id, &InstanceSubdirType::Mods)
+    .ok_or(InstanceError::InstanceNotFoundByID)?;
+
+  let mod_infos = load_local_mod_infos(&app, &mods_dir).await?;
+  check_mod_updates_by_hashes(&app, &mod_infos, &game_version, &loader_type).await
 }
 
 #[tauri::command]
@@ -193,6 +239,40 @@ pub async fn update_mods(
     Some(path) => path,
     None => return Ok(()),
   };
+  let version_path = {
+    let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+    let state = binding.lock()?;
+    match state.get(&instance_id) {
+      Some(instance) => instance.version_path.clone(),
+      None => return Ok(()),
+    }
+  };
+
+  // copy the replaced files into the backup dir of this update, so that it can be rolled back.
+  let now = chrono::Utc::now();
+  let mut entry = ModUpdateHistoryEntry {
+    id: now.timestamp_millis().to_string(),
+    time: now.timestamp(),
+    items: queries
+      .iter()
+      .filter(|query| Path::new(&query.old_file_path).is_file())
+      .map(|query| ModUpdateHistoryItem {
+        old_file_name: Path::new(&query.old_file_path)
+          .file_name()
+          .unwrap_or_default()
+          .to_string_lossy()
+          .to_string(),
+        new_file_name: query.file_name.clone(),
+      })
+      .collect(),
+  };
+  let backup_dir = get_mod_update_backup_dir(&version_path, &entry.id);
+  let old_file_paths: Vec<PathBuf> = queries
+    .iter()
+    .map(|query| PathBuf::from(&query.old_file_path))
+    .filter(|path| path.is_file())
+    .collect();
+  backup_mod_files(&backup_dir, &old_file_paths)?;
 
   let mut download_tasks = Vec::new();
   for query in &queries {
@@ -202,41 +282,160 @@ pub async fn update_mods(
       dest: file_path,
       filename: None,
       sha1: Some(query.sha1.clone()),
+      alt_srcs: vec![],
+      served_src: None,
     };
     download_tasks.push(PTaskParam::Download(download_param));
   }
 
-  schedule_progressive_task_group(app, "mod-update".to_string(), download_tasks, true).await?;
+  let task_group = match schedule_progressive_task_group(
+    app.clone(),
+    "mod-update".to_string(),
+    download_tasks,
+    true,
+  )
+  .await
+  {
+    Ok(desc) => desc.task_group,
+    Err(e) => {
+      let _ = fs::remove_dir_all(&backup_dir);
+      return Err(e);
+    }
+  };
 
-  for query in &queries {
-    let old_file_path = &query.old_file_path;
-    let new_file_path = mods_dir.join(&query.file_name);
-
-    if old_file_path != &new_file_path.to_string_lossy().to_string() {
-      let old_backup_path = format!("{}.old", old_file_path);
-      if let Err(e) = std::fs::rename(old_file_path, &old_backup_path) {
-        log::error!("Failed to rename old mod file: {}", e);
-        return Err(ResourceError::FileOperationError.into());
+  // the old files are removed and the update is recorded only after the downloads have ended
+  tauri::async_runtime::spawn(async move {
+    let monitor = app.state::<Pin<Box<TaskMonitor>>>();
+    monitor.wait_for_group(&task_group).await;
+    finish_mod_update(&mods_dir, &backup_dir, &mut entry, &queries);
+    if !entry.items.is_empty() {
+      let instance = {
+        let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+        let Ok(mut state) = binding.lock() else {
+          return;
+        };
+        let Some(instance) = state.get_mut(&instance_id) else {
+          return;
+        };
+        instance.mod_update_history.push(entry);
+        prune_mod_update_history(&version_path, &mut instance.mod_update_history);
+        instance.clone()
+      };
+      if let Err(e) = instance.save_json_cfg().await {
+        log::error!("Failed to save the mod update history: {:?}", e);
       }
     }
-  }
+    let _ = app.emit("instance:refresh-resource-list", "mod");
+  });
 
   Ok(())
 }
 
+#[tauri::command]
+pub fn retrieve_mod_update_history(
+  app: AppHandle,
+  instance_id: String,
+) -> SJMCLResult<Vec<ModUpdateHistoryEntry>> {
+  let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+  let state = binding.lock()?;
+  let instance = state
+    .get(&instance_id)
+    .ok_or(InstanceError::InstanceNotFoundByID)?;
+  Ok(instance.mod_update_history.clone())
+}
+
+#[tauri::command]
+pub async fn rollback_mod_update(
+  app: AppHandle,
+  instance_id: String,
+  entry_id: String,
+) -> SJMCLResult<()> {
+  let mods_dir = get_instance_subdir_path_by_id(&app, &instance_id, &InstanceSubdirType::Mods)
+    .ok_or(InstanceError::InstanceNotFoundByID)?;
+  // later updates may have replaced the same mods again, roll them back first.
+  let (version_path, entries) = {
+    let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+    let state = binding.lock()?;
+    let instance = state
+      .get(&instance_id)
+      .ok_or(InstanceError::InstanceNotFoundByID)?;
+    let pos = instance
+      .mod_update_history
+      .iter()
+      .position(|entry| entry.id == entry_id)
+      .ok_or(ResourceError::ModUpdateHistoryNotFound)?;
+    (
+      instance.version_path.clone(),
+      instance.mod_update_history[pos..].to_vec(),
+    )
+  };
+
+  let mut result = Ok(());
+  let mut restored_ids = Vec::new();
+  for entry in entries.iter().rev() {
+    let backup_dir = get_mod_update_backup_dir(&version_path, &entry.id);
+    if let Err(e) = restore_mod_files(&mods_dir, &backup_dir, entry) {
+      result = Err(e);
+      break;
+    }
+    restored_ids.push(entry.id.clone());
+  }
+
+  let instance = {
+    let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+    let mut state = binding.lock()?;
+    let instance = state
+      .get_mut(&instance_id)
+      .ok_or(InstanceError::InstanceNotFoundByID)?;
+    instance
+      .mod_update_history
+      .retain(|entry| !restored_ids.contains(&entry.id));
+    instance.clone()
+  };
+  instance.save_json_cfg().await?;
+
+  result
+}
+
 #[tauri::command]
 pub async fn fetch_remote_resource_by_id(
   app: AppHandle,
   download_source: OtherResourceSource,
   resource_id: String,
 ) -> SJMCLResult<OtherResourceInfo> {
-  match download_source {
-    OtherResourceSource::CurseForge => {
-      Ok(fetch_remote_resource_by_id_curseforge(&app, &resource_id).await?)
-    }
-    OtherResourceSource::Modrinth => {
-      Ok(fetch_remote_resource_by_id_modrinth(&app, &resource_id).await?)
-    }
-    _ => Err(ResourceError::NoDownloadApi.into()),
-  }
+  get_resource_provider(&download_source)?
+    .fetch_by_id(&app, &resource_id)
+    .await
+}
+
+#[tauri::command]
+pub async fn resolve_resource_dependencies(
+  app: AppHandle,
+  instance_id: String,
+  download_source: OtherResourceSource,
+  file: OtherResourceFileInfo,
+) -> SJMCLResult<Vec<ResourceDependencyPlanItem>> {
+  let (game_version, loader_type) = {
+    let binding = app.state::<Mutex<HashMap<String, Instance>>>();
+    let state = binding.lock()?;
+    let instance = state
+      .get(&instance_id)
+      .ok_or(InstanceError::InstanceNotFoundByID)?;
+    (
+      instance.version.clone(),
+      instance.mod_loader.loader_type.clone(),
+    )
+  };
+  let mods_dir = get_instance_subdir_path_by_id(&app, &instance_id, &InstanceSubdirType::Mods)
+    .ok_or(InstanceError::InstanceNotFoundByID)?;
+
+  resolve_required_dependencies(
+    &app,
+    &download_source,
+    &file,
+    &game_version,
+    &loader_type,
+    &mods_dir,
+  )
+  .await
 }
@@ -0,0 +1,33 @@
+pub const HTTP_CACHE_DIR_NAME: &str = "http";
+
+// the official api of the resource platforms, used unless overridden in the launcher config
+pub const CURSEFORGE_API_BASE: &str = "https://api.curseforge.com/v1";
+pub const MODRINTH_API_BASE: &str = "https://api.modrinth.com/v2";
+
+// how long a cached response is served without revalidation, in seconds
+pub const SEARCH_CACHE_TTL: u64 = 10 * 60;
+pub const RESOURCE_CACHE_TTL: u64 = 60 * 60;
+pub const UPDATE_CACHE_TTL: u64 = 10 * 60;
+pub const LOADER_META_CACHE_TTL: u64 = 60 * 60;
+pub const VERSION_MANIFEST_CACHE_TTL: u64 = 10 * 60;
+// cached responses not refreshed for this long are purged at startup
+pub const HTTP_CACHE_MAX_AGE: u64 = 30 * 24 * 60 * 60;
+
+// the last version manifest fetched from remote, and the snapshot bundled in app resources
+pub const VERSION_MANIFEST_FILE_NAME: &str = "version_manifest_v2.json";
+pub const BUNDLED_VERSION_MANIFEST_PATH: &str = "assets/game/version_manifest_v2.json";
+
+// the mod database downloaded from remote and its version, and the copy bundled in app resources
+pub const MOD_DB_FILE_NAME: &str = "mod_data.csv";
+pub const MOD_DB_META_FILE_NAME: &str = "mod_data_meta.json";
+pub const BUNDLED_MOD_DB_PATH: &str = "assets/db/mod_data.csv";
+pub const BUNDLED_MOD_DB_META_PATH: &str = "assets/db/mod_data_meta.json";
+
+pub const SOURCE_RANKING_FILE_NAME: &str = "source_ranking.json";
+
+// how often the download sources are probed again while the launcher is running, in seconds
+pub const SOURCE_PROBE_INTERVAL: u64 = 30 * 60;
+pub const SOURCE_PROBE_TIMEOUT: u64 = 5;
+// a probe reads at most this many bytes (or for this long) to sample the throughput
+pub const SOURCE_PROBE_SAMPLE_BYTES: usize = 256 * 1024;
+pub const SOURCE_PROBE_SAMPLE_SECS: u64 = 3;
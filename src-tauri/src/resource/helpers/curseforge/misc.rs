@@ -1,4 +1,6 @@
 use crate::error::{SJMCLError, SJMCLResult};
+use crate::resource::constants::CURSEFORGE_API_BASE;
+use crate::resource::helpers::http_cache::send_with_cache;
 use crate::resource::helpers::misc::version_pack_sort;
 use crate::resource::models::{
   OtherResourceApiEndpoint, OtherResourceDependency, OtherResourceFileInfo, OtherResourceInfo,
@@ -6,6 +8,7 @@ use crate::resource::models::{
   ResourceError,
 };
 use lazy_static::lazy_static;
+use murmur2::murmur2;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::env;
@@ -15,41 +18,38 @@ use tauri_plugin_http::reqwest;
 const CURSEFORGE_API_KEY: &str = env!("SJMCL_CURSEFORGE_API_KEY");
 
 pub async fn make_curseforge_request<T, P>(
-  client: &reqwest::Client,
+  app: &AppHandle,
   url: &str,
   request_type: OtherResourceRequestType<'_, P>,
+  cache_ttl: u64,
 ) -> SJMCLResult<T>
 where
   T: serde::de::DeserializeOwned,
   P: serde::Serialize,
 {
+  let client = app.state::<reqwest::Client>();
   let request_builder = match request_type {
     OtherResourceRequestType::GetWithParams(params) => client.get(url).query(params),
     OtherResourceRequestType::Get => client.get(url),
     OtherResourceRequestType::Post(payload) => client.post(url).json(payload),
   };
 
-  let response = request_builder
-    .header("x-api-key", CURSEFORGE_API_KEY)
-    .send()
-    .await
-    .map_err(|_| ResourceError::NetworkError)?;
-
-  if !response.status().is_success() {
-    return Err(ResourceError::NetworkError.into());
-  }
+  let body = send_with_cache(
+    app,
+    request_builder.header("x-api-key", CURSEFORGE_API_KEY),
+    cache_ttl,
+  )
+  .await?;
 
-  response
-    .json::<T>()
-    .await
-    .map_err(|_| ResourceError::ParseError.into())
+  serde_json::from_str::<T>(&body).map_err(|_| ResourceError::ParseError.into())
 }
 
 pub fn get_curseforge_api(
+  api_base: &str,
   endpoint: OtherResourceApiEndpoint,
   id: Option<&str>,
 ) -> SJMCLResult<String> {
-  let base_url = "https://api.curseforge.com/v1";
+  let base_url = api_base.trim_end_matches('/');
 
   let url_str = match endpoint {
     OtherResourceApiEndpoint::Search => format!("{}/mods/search", base_url),
@@ -57,7 +57,9 @@ pub fn get_curseforge_api(
       let mod_id = id.ok_or(ResourceError::ParseError)?;
       format!("{}/mods/{}/files", base_url, mod_id)
     }
-    OtherResourceApiEndpoint::FromLocal => format!("{}/fingerprints/432", base_url),
+    OtherResourceApiEndpoint::FromLocal | OtherResourceApiEndpoint::BulkFromLocal => {
+      format!("{}/fingerprints/432", base_url)
+    }
     OtherResourceApiEndpoint::ById => {
       let mod_id = id.ok_or(ResourceError::ParseError)?;
       format!("{}/mods/{}", base_url, mod_id)
@@ -66,6 +68,14 @@ pub fn get_curseforge_api(
       let mod_id = id.ok_or(ResourceError::ParseError)?;
       format!("https://mod.mcimirror.top/translate/curseforge/{}", mod_id)
     }
+    OtherResourceApiEndpoint::UpdateFromLocal => format!("{}/fingerprints/432", base_url),
+    OtherResourceApiEndpoint::Changelog => {
+      // id is formatted as `{mod_id}/{file_id}`
+      let (mod_id, file_id) = id
+        .and_then(|id| id.split_once('/'))
+        .ok_or(ResourceError::ParseError)?;
+      format!("{}/mods/{}/files/{}/changelog", base_url, mod_id, file_id)
+    }
   };
 
   Ok(url_str)
@@ -91,6 +101,8 @@ structstruck::strike! {
       pub url: String,
     }>,
     pub date_modified: String,
+    #[serde(default)]
+    pub latest_files: Vec<CurseForgeFileInfo>,
   }
 }
 
@@ -129,6 +141,8 @@ structstruck::strike! {
       pub mod_id: i32,
       pub relation_type: u32,
     }>,
+    #[serde(default)]
+    pub file_fingerprint: u64,
   }
 }
 
@@ -145,6 +159,8 @@ structstruck::strike! {
     pub data: pub struct {
       pub exact_matches: Vec<pub struct {
         pub file: CurseForgeFileInfo,
+        #[serde(default)]
+        pub latest_files: Vec<CurseForgeFileInfo>,
       }>,
     }
   }
@@ -156,11 +172,27 @@ pub struct CurseForgeGetProjectRes {
   pub data: CurseForgeProject,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct CurseForgeChangelogRes {
+  pub data: String,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct CurseForgeTranslationRes {
   pub translated: String,
 }
 
+// murmur2 hash of the file with whitespace bytes removed
+// https://docs.curseforge.com/rest-api/#get-fingerprints-matches
+pub fn calculate_curseforge_fingerprint(file_content: &[u8]) -> u64 {
+  let filtered_bytes: Vec<u8> = file_content
+    .iter()
+    .copied()
+    .filter(|&byte| !matches!(byte, 0x09 | 0x0a | 0x0d | 0x20))
+    .collect();
+  murmur2(&filtered_bytes, 1) as u64
+}
+
 fn extract_versions_and_loaders(game_versions: &[String]) -> (Vec<String>, Vec<String>) {
   let mut versions = Vec::new();
   let mut loaders = Vec::new();
@@ -247,6 +279,14 @@ impl From<CurseForgeProject> for OtherResourceInfo {
       source: OtherResourceSource::CurseForge,
       translated_name: None,
       translated_description: None,
+      linked_sources: vec![],
+      latest_file_hashes: project
+        .latest_files
+        .iter()
+        .flat_map(|file| file.hashes.iter())
+        .filter(|h| h.algo == 1)
+        .map(|h| h.value.clone())
+        .collect(),
     }
   }
 }
@@ -523,7 +563,11 @@ pub async fn translate_description_curseforge(
   resource_id: &str,
 ) -> SJMCLResult<Option<String>> {
   let result = async {
-    let url = get_curseforge_api(OtherResourceApiEndpoint::TranslateDesc, Some(resource_id))?;
+    let url = get_curseforge_api(
+      CURSEFORGE_API_BASE,
+      OtherResourceApiEndpoint::TranslateDesc,
+      Some(resource_id),
+    )?;
     let client = app.state::<reqwest::Client>();
 
     let translation_res = client
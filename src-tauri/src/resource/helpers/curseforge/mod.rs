@@ -1,36 +1,41 @@
 pub mod misc;
 
 use crate::error::SJMCLResult;
+use crate::resource::constants::{
+  CURSEFORGE_API_BASE, RESOURCE_CACHE_TTL, SEARCH_CACHE_TTL, UPDATE_CACHE_TTL,
+};
 use crate::resource::helpers::misc::apply_other_resource_enhancements;
 use crate::resource::helpers::mod_db::handle_search_query;
+use crate::resource::helpers::provider::ResourceProvider;
 use crate::resource::models::{
   OtherResourceApiEndpoint, OtherResourceFileInfo, OtherResourceInfo, OtherResourceRequestType,
-  OtherResourceSearchQuery, OtherResourceSearchRes, OtherResourceVersionPack,
+  OtherResourceSearchQuery, OtherResourceSearchRes, OtherResourceSource, OtherResourceVersionPack,
   OtherResourceVersionPackQuery, ResourceError,
 };
+use async_trait::async_trait;
 use hex;
 use misc::{
-  cvt_category_to_id, cvt_mod_loader_to_id, cvt_sort_by_to_id, cvt_type_to_class_id,
-  cvt_version_to_type_id, get_curseforge_api, make_curseforge_request,
-  map_curseforge_file_to_version_pack, CurseForgeFileInfo, CurseForgeFingerprintRes,
-  CurseForgeGetProjectRes, CurseForgeSearchRes, CurseForgeVersionPackSearchRes,
+  calculate_curseforge_fingerprint, cvt_category_to_id, cvt_mod_loader_to_id, cvt_sort_by_to_id,
+  cvt_type_to_class_id, cvt_version_to_type_id, get_curseforge_api, make_curseforge_request,
+  map_curseforge_file_to_version_pack, translate_description_curseforge, CurseForgeChangelogRes,
+  CurseForgeFileInfo, CurseForgeFingerprintRes, CurseForgeGetProjectRes, CurseForgeSearchRes,
+  CurseForgeVersionPackSearchRes,
 };
-use murmur2::murmur2;
 use serde_json::json;
 use sha1::{Digest, Sha1};
 use std::collections::HashMap;
 use std::path::Path;
-use tauri::{AppHandle, Manager};
-use tauri_plugin_http::reqwest;
+use tauri::AppHandle;
 
 const MINECRAFT_GAME_ID: &str = "432";
 const ALL_FILTER: &str = "All";
 
 pub async fn fetch_resource_list_by_name_curseforge(
   app: &AppHandle,
+  api_base: &str,
   query: &OtherResourceSearchQuery,
 ) -> SJMCLResult<OtherResourceSearchRes> {
-  let url = get_curseforge_api(OtherResourceApiEndpoint::Search, None)?;
+  let url = get_curseforge_api(api_base, OtherResourceApiEndpoint::Search, None)?;
 
   let OtherResourceSearchQuery {
     resource_type,
@@ -71,11 +76,11 @@ pub async fn fetch_resource_list_by_name_curseforge(
   params.insert("index".to_string(), (page * page_size).to_string());
   params.insert("pageSize".to_string(), page_size.to_string());
 
-  let client = app.state::<reqwest::Client>();
   let results = make_curseforge_request::<CurseForgeSearchRes, ()>(
-    &client,
+    app,
     &url,
     OtherResourceRequestType::GetWithParams(&params),
+    SEARCH_CACHE_TTL,
   )
   .await?;
 
@@ -89,6 +94,7 @@ pub async fn fetch_resource_list_by_name_curseforge(
 
 pub async fn fetch_resource_version_packs_curseforge(
   app: &AppHandle,
+  api_base: &str,
   query: &OtherResourceVersionPackQuery,
 ) -> SJMCLResult<Vec<OtherResourceVersionPack>> {
   let mut aggregated_files: Vec<CurseForgeFileInfo> = Vec::new();
@@ -102,7 +108,11 @@ pub async fn fetch_resource_version_packs_curseforge(
   } = query;
 
   loop {
-    let url = get_curseforge_api(OtherResourceApiEndpoint::VersionPack, Some(resource_id))?;
+    let url = get_curseforge_api(
+      api_base,
+      OtherResourceApiEndpoint::VersionPack,
+      Some(resource_id),
+    )?;
 
     let mut params = HashMap::new();
     if mod_loader != ALL_FILTER {
@@ -122,12 +132,11 @@ pub async fn fetch_resource_version_packs_curseforge(
     params.insert("index".to_string(), (page * page_size).to_string());
     params.insert("pageSize".to_string(), page_size.to_string());
 
-    let client = app.state::<reqwest::Client>();
-
     let results = make_curseforge_request::<CurseForgeVersionPackSearchRes, ()>(
-      &client,
+      app,
       &url,
       OtherResourceRequestType::GetWithParams(&params),
+      RESOURCE_CACHE_TTL,
     )
     .await?;
 
@@ -146,6 +155,7 @@ pub async fn fetch_resource_version_packs_curseforge(
 
 pub async fn fetch_remote_resource_by_local_curseforge(
   app: &AppHandle,
+  api_base: &str,
   file_path: &str,
 ) -> SJMCLResult<OtherResourceFileInfo> {
   let file_path = Path::new(file_path);
@@ -160,23 +170,18 @@ pub async fn fetch_remote_resource_by_local_curseforge(
   hasher.update(&file_content);
   let local_sha1 = hex::encode(hasher.finalize());
 
-  let filtered_bytes: Vec<u8> = file_content
-    .into_iter()
-    .filter(|&byte| !matches!(byte, 0x09 | 0x0a | 0x0d | 0x20))
-    .collect();
-
-  let hash = murmur2(&filtered_bytes, 1) as u64;
+  let hash = calculate_curseforge_fingerprint(&file_content);
 
-  let url = get_curseforge_api(OtherResourceApiEndpoint::FromLocal, None)?;
+  let url = get_curseforge_api(api_base, OtherResourceApiEndpoint::FromLocal, None)?;
   let payload = json!({
     "fingerprints": [hash]
   });
 
-  let client = app.state::<reqwest::Client>();
   let fingerprint_response = make_curseforge_request::<CurseForgeFingerprintRes, _>(
-    &client,
+    app,
     &url,
     OtherResourceRequestType::Post(&payload),
+    RESOURCE_CACHE_TTL,
   )
   .await?;
 
@@ -198,17 +203,77 @@ pub async fn fetch_remote_resource_by_local_curseforge(
   }
 }
 
+// resolve local files by fingerprints in one request,
+// returns the matched file and the latest files of its project, keyed by fingerprint.
+pub async fn fetch_latest_files_by_fingerprints_curseforge(
+  app: &AppHandle,
+  api_base: &str,
+  fingerprints: &[u64],
+) -> SJMCLResult<HashMap<u64, (CurseForgeFileInfo, Vec<CurseForgeFileInfo>)>> {
+  if fingerprints.is_empty() {
+    return Ok(HashMap::new());
+  }
+  let url = get_curseforge_api(api_base, OtherResourceApiEndpoint::UpdateFromLocal, None)?;
+  let payload = json!({
+    "fingerprints": fingerprints
+  });
+
+  let fingerprint_response = make_curseforge_request::<CurseForgeFingerprintRes, _>(
+    app,
+    &url,
+    OtherResourceRequestType::Post(&payload),
+    UPDATE_CACHE_TTL,
+  )
+  .await?;
+
+  Ok(
+    fingerprint_response
+      .data
+      .exact_matches
+      .into_iter()
+      .map(|exact_match| {
+        (
+          exact_match.file.file_fingerprint,
+          (exact_match.file, exact_match.latest_files),
+        )
+      })
+      .collect(),
+  )
+}
+
+pub async fn fetch_file_changelog_curseforge(
+  app: &AppHandle,
+  api_base: &str,
+  mod_id: i32,
+  file_id: i32,
+) -> SJMCLResult<String> {
+  let url = get_curseforge_api(
+    api_base,
+    OtherResourceApiEndpoint::Changelog,
+    Some(&format!("{}/{}", mod_id, file_id)),
+  )?;
+  let results = make_curseforge_request::<CurseForgeChangelogRes, ()>(
+    app,
+    &url,
+    OtherResourceRequestType::Get,
+    RESOURCE_CACHE_TTL,
+  )
+  .await?;
+
+  Ok(results.data)
+}
+
 pub async fn fetch_remote_resource_by_id_curseforge(
   app: &AppHandle,
+  api_base: &str,
   resource_id: &str,
 ) -> SJMCLResult<OtherResourceInfo> {
-  let url = get_curseforge_api(OtherResourceApiEndpoint::ById, Some(resource_id))?;
-  let client = app.state::<reqwest::Client>();
-
+  let url = get_curseforge_api(api_base, OtherResourceApiEndpoint::ById, Some(resource_id))?;
   let results = make_curseforge_request::<CurseForgeGetProjectRes, ()>(
-    &client,
+    app,
     &url,
     OtherResourceRequestType::Get,
+    RESOURCE_CACHE_TTL,
   )
   .await?;
 
@@ -217,3 +282,71 @@ pub async fn fetch_remote_resource_by_id_curseforge(
 
   Ok(resource_info)
 }
+
+// the api base defaults to the official one, see `register_resource_providers`
+pub struct CurseForgeProvider {
+  pub api_base: String,
+}
+
+impl Default for CurseForgeProvider {
+  fn default() -> Self {
+    CurseForgeProvider {
+      api_base: CURSEFORGE_API_BASE.to_string(),
+    }
+  }
+}
+
+#[async_trait]
+impl ResourceProvider for CurseForgeProvider {
+  fn source(&self) -> OtherResourceSource {
+    OtherResourceSource::CurseForge
+  }
+
+  fn api_base(&self) -> &str {
+    &self.api_base
+  }
+
+  async fn search(
+    &self,
+    app: &AppHandle,
+    query: &OtherResourceSearchQuery,
+  ) -> SJMCLResult<OtherResourceSearchRes> {
+    fetch_resource_list_by_name_curseforge(app, &self.api_base, query).await
+  }
+
+  async fn fetch_version_packs(
+    &self,
+    app: &AppHandle,
+    query: &OtherResourceVersionPackQuery,
+  ) -> SJMCLResult<Vec<OtherResourceVersionPack>> {
+    fetch_resource_version_packs_curseforge(app, &self.api_base, query).await
+  }
+
+  async fn fetch_by_local_file(
+    &self,
+    app: &AppHandle,
+    file_path: &str,
+  ) -> SJMCLResult<OtherResourceFileInfo> {
+    fetch_remote_resource_by_local_curseforge(app, &self.api_base, file_path).await
+  }
+
+  async fn fetch_by_id(
+    &self,
+    app: &AppHandle,
+    resource_id: &str,
+  ) -> SJMCLResult<OtherResourceInfo> {
+    fetch_remote_resource_by_id_curseforge(app, &self.api_base, resource_id).await
+  }
+
+  async fn translate_description(
+    &self,
+    app: &AppHandle,
+    resource_id: &str,
+  ) -> SJMCLResult<Option<String>> {
+    // the translations are indexed by the project ids on the official curseforge
+    if self.api_base != CURSEFORGE_API_BASE {
+      return Ok(None);
+    }
+    translate_description_curseforge(app, resource_id).await
+  }
+}
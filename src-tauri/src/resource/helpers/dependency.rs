@@ -0,0 +1,232 @@
+use crate::error::SJMCLResult;
+use crate::instance::helpers::mods::common::{
+  get_cached_remote_match, get_cached_sha1, load_local_mod_infos,
+};
+use crate::instance::models::misc::ModLoaderType;
+use crate::resource::helpers::provider::get_resource_provider;
+use crate::resource::models::{
+  OtherResourceFileInfo, OtherResourceInfo, OtherResourceSource, OtherResourceVersionPack,
+  OtherResourceVersionPackQuery, ResourceDependencyPlanItem,
+};
+use crate::utils::fs::calculate_sha1;
+use std::collections::{HashSet, VecDeque};
+use std::path::Path;
+use tauri::AppHandle;
+
+const REQUIRED_RELATION: &str = "required";
+
+// what is already present in the mods dir of the instance
+struct InstalledMods {
+  sha1s: HashSet<String>,
+  mod_ids: HashSet<String>,
+  // (source, resource id) of the files matched to a remote project before
+  remote_ids: HashSet<(OtherResourceSource, String)>,
+}
+
+// mod ids use underscores, while slugs on both platforms usually use dashes
+fn normalize_mod_id(id: &str) -> String {
+  id.to_lowercase().replace('-', "_")
+}
+
+impl InstalledMods {
+  async fn load(app: &AppHandle, mods_dir: &Path) -> SJMCLResult<Self> {
+    let mut installed = InstalledMods {
+      sha1s: HashSet::new(),
+      mod_ids: HashSet::new(),
+      remote_ids: HashSet::new(),
+    };
+    for info in load_local_mod_infos(app, mods_dir).await? {
+      if let Some(sha1) =
+        get_cached_sha1(app, &info.file_path).or_else(|| calculate_sha1(&info.file_path).ok())
+      {
+        installed.sha1s.insert(sha1);
+      }
+      if let Some(remote) = get_cached_remote_match(app, &info.file_path) {
+        installed
+          .remote_ids
+          .insert((remote.source, remote.file.resource_id));
+      }
+      installed.mod_ids.extend(
+        info
+          .provided_mod_ids()
+          .iter()
+          .filter(|id| !id.is_empty())
+          .map(|id| normalize_mod_id(id)),
+      );
+    }
+    Ok(installed)
+  }
+
+  fn contains(&self, resource: &OtherResourceInfo, file: Option<&OtherResourceFileInfo>) -> bool {
+    self
+      .remote_ids
+      .contains(&(resource.source.clone(), resource.id.clone()))
+      || file.is_some_and(|file| self.sha1s.contains(&file.sha1))
+      || (!resource.slug.is_empty() && self.mod_ids.contains(&normalize_mod_id(&resource.slug)))
+  }
+}
+
+// loader names accepted by the version pack queries of both platforms
+fn get_query_loader(loader_type: &ModLoaderType) -> String {
+  match loader_type {
+    ModLoaderType::LegacyForge | ModLoaderType::Cleanroom => "Forge".to_string(),
+    ModLoaderType::Unknown => "All".to_string(),
+    loader_type => loader_type.to_string(),
+  }
+}
+
+fn is_loader_compatible(file: &OtherResourceFileInfo, loader_type: &ModLoaderType) -> bool {
+  let Some(loader) = &file.loader else {
+    return true;
+  };
+  let loader = loader.to_lowercase();
+  match loader_type {
+    ModLoaderType::Unknown => true,
+    // quilt is able to load most fabric mods
+    ModLoaderType::Quilt => loader == "quilt" || loader == "fabric",
+    loader_type => {
+      // loaders not known here are not bound to a mod loader, e.g. datapack or minecraft
+      loader.parse::<ModLoaderType>().is_err()
+        || loader == get_query_loader(loader_type).to_lowercase()
+    }
+  }
+}
+
+// the latest file for the game version, stable releases are preferred.
+fn pick_dependency_file(
+  packs: Vec<OtherResourceVersionPack>,
+  game_version: &str,
+  loader_type: &ModLoaderType,
+) -> Option<OtherResourceFileInfo> {
+  let mut candidates: Vec<OtherResourceFileInfo> = packs
+    .into_iter()
+    .filter(|pack| pack.name == game_version)
+    .flat_map(|pack| pack.items)
+    .filter(|file| is_loader_compatible(file, loader_type))
+    .collect();
+  candidates.sort_by(|a, b| {
+    let is_stable =
+      |file: &OtherResourceFileInfo| matches!(file.release_type.as_str(), "release" | "beta");
+    is_stable(b)
+      .cmp(&is_stable(a))
+      .then_with(|| b.file_date.cmp(&a.file_date))
+  });
+  candidates.into_iter().next()
+}
+
+async fn fetch_dependency(
+  app: &AppHandle,
+  source: &OtherResourceSource,
+  resource_id: &str,
+  game_version: &str,
+  loader_type: &ModLoaderType,
+) -> SJMCLResult<(OtherResourceInfo, Option<OtherResourceFileInfo>)> {
+  let provider = get_resource_provider(source)?;
+  let game_versions = match provider.source() {
+    // curseforge filters the files by the major version only
+    OtherResourceSource::CurseForge => {
+      vec![game_version
+        .split('.')
+        .take(2)
+        .collect::<Vec<_>>()
+        .join(".")]
+    }
+    _ => vec![game_version.to_string()],
+  };
+  let query = OtherResourceVersionPackQuery {
+    resource_id: resource_id.to_string(),
+    mod_loader: get_query_loader(loader_type),
+    game_versions,
+  };
+  let (resource, packs) = tokio::try_join!(
+    provider.fetch_by_id(app, resource_id),
+    provider.fetch_version_packs(app, &query),
+  )?;
+  Ok((
+    resource,
+    pick_dependency_file(packs, game_version, loader_type),
+  ))
+}
+
+/// Resolves the required dependencies of a resource file recursively.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri AppHandle.
+/// * `source` - The platform the file comes from, its dependencies are looked up on the same one.
+/// * `file` - The file to be downloaded.
+/// * `game_version` - The game version of the target instance.
+/// * `loader_type` - The mod loader of the target instance.
+/// * `mods_dir` - The mods dir of the target instance, used to skip installed dependencies.
+///
+/// # Returns
+///
+/// The dependencies in the order they were found. Installed ones are kept with `installed` set,
+/// and their own dependencies are not followed. Those failing to be looked up have no file.
+pub async fn resolve_required_dependencies(
+  app: &AppHandle,
+  source: &OtherResourceSource,
+  file: &OtherResourceFileInfo,
+  game_version: &str,
+  loader_type: &ModLoaderType,
+  mods_dir: &Path,
+) -> SJMCLResult<Vec<ResourceDependencyPlanItem>> {
+  let installed = InstalledMods::load(app, mods_dir).await?;
+
+  let mut plan = Vec::new();
+  let mut visited = HashSet::from([file.resource_id.clone()]);
+  // (dependency resource id, required by)
+  let mut queue: VecDeque<(String, String)> = VecDeque::new();
+  let enqueue = |queue: &mut VecDeque<(String, String)>,
+                 visited: &mut HashSet<String>,
+                 file: &OtherResourceFileInfo| {
+    for dep in &file.dependencies {
+      // modrinth dependencies pinned to a version only come without a project id
+      if dep.resource_id.is_empty() {
+        continue;
+      }
+      if dep.relation == REQUIRED_RELATION && visited.insert(dep.resource_id.clone()) {
+        queue.push_back((dep.resource_id.clone(), file.resource_id.clone()));
+      }
+    }
+  };
+  enqueue(&mut queue, &mut visited, file);
+
+  while let Some((resource_id, required_by)) = queue.pop_front() {
+    // a dependency failing to be looked up is kept as unavailable, the rest are still resolved
+    let (resource, dep_file) =
+      match fetch_dependency(app, source, &resource_id, game_version, loader_type).await {
+        Ok(res) => res,
+        Err(e) => {
+          log::warn!("Failed to fetch dependency {}: {:?}", resource_id, e);
+          let resource = OtherResourceInfo {
+            id: resource_id.clone(),
+            name: resource_id,
+            source: source.clone(),
+            ..Default::default()
+          };
+          plan.push(ResourceDependencyPlanItem {
+            resource,
+            file: None,
+            required_by,
+            installed: false,
+          });
+          continue;
+        }
+      };
+    let is_installed = installed.contains(&resource, dep_file.as_ref());
+    if !is_installed {
+      if let Some(dep_file) = &dep_file {
+        enqueue(&mut queue, &mut visited, dep_file);
+      }
+    }
+    plan.push(ResourceDependencyPlanItem {
+      resource,
+      file: dep_file,
+      required_by,
+      installed: is_installed,
+    });
+  }
+
+  Ok(plan)
+}
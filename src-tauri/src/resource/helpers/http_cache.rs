@@ -0,0 +1,393 @@
+use crate::error::SJMCLResult;
+use crate::launcher_config::models::LauncherConfig;
+use crate::resource::constants::HTTP_CACHE_DIR_NAME;
+use crate::resource::models::ResourceError;
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_http::reqwest::header::{
+  HeaderName, HeaderValue, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+};
+use tauri_plugin_http::reqwest::{RequestBuilder, Response, StatusCode};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct HttpCacheEntry {
+  pub url: String,
+  pub etag: Option<String>,
+  pub last_modified: Option<String>,
+  pub fetched_at: i64,
+  pub body: String,
+}
+
+impl HttpCacheEntry {
+  fn is_fresh(&self, ttl_secs: u64) -> bool {
+    let age = chrono::Utc::now().timestamp() - self.fetched_at;
+    age >= 0 && (age as u64) < ttl_secs
+  }
+}
+
+fn get_cache_dir(app: &AppHandle) -> Option<PathBuf> {
+  let config_binding = app.state::<Mutex<LauncherConfig>>();
+  let config_state = config_binding.lock().ok()?;
+  Some(
+    config_state
+      .download
+      .cache
+      .directory
+      .join(HTTP_CACHE_DIR_NAME),
+  )
+}
+
+fn load_cache_entry(path: &Path) -> Option<HttpCacheEntry> {
+  let content = fs::read_to_string(path).ok()?;
+  serde_json::from_str(&content).ok()
+}
+
+fn save_cache_entry(path: &Path, entry: &HttpCacheEntry) {
+  if let Some(parent) = path.parent() {
+    if fs::create_dir_all(parent).is_err() {
+      return;
+    }
+  }
+  if let Ok(content) = serde_json::to_string(entry) {
+    let _ = fs::write(path, content);
+  }
+}
+
+fn get_header(response: &Response, name: HeaderName) -> Option<String> {
+  response
+    .headers()
+    .get(name)
+    .and_then(|value| value.to_str().ok())
+    .map(String::from)
+}
+
+fn serve_stale(cached: Option<HttpCacheEntry>, url: &str) -> SJMCLResult<String> {
+  match cached {
+    Some(entry) => {
+      log::warn!(
+        "Request to {} failed, using the response cached at {}",
+        url,
+        entry.fetched_at
+      );
+      Ok(entry.body)
+    }
+    None => Err(ResourceError::NetworkError.into()),
+  }
+}
+
+/// Sends the request through the on-disk response cache in the download cache directory,
+/// and returns the response body as text.
+///
+/// A cached response younger than `ttl_secs` is returned without touching the network.
+/// Otherwise it is revalidated by `ETag` / `Last-Modified`, and still served when the
+/// network is down or the server fails, so that metadata lists keep working offline.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri AppHandle.
+/// * `request_builder` - The request to send, keyed in the cache by method, url and body.
+/// * `ttl_secs` - How long a cached response is considered fresh.
+///
+/// # Example
+///
+/// ```rust
+/// let client = app.state::<reqwest::Client>();
+/// let body = send_with_cache(&app, client.get(url), LOADER_META_CACHE_TTL).await?;
+/// ```
+pub async fn send_with_cache(
+  app: &AppHandle,
+  request_builder: RequestBuilder,
+  ttl_secs: u64,
+) -> SJMCLResult<String> {
+  send_with_cache_in(get_cache_dir(app).as_deref(), request_builder, ttl_secs).await
+}
+
+async fn send_with_cache_in(
+  cache_dir: Option<&Path>,
+  request_builder: RequestBuilder,
+  ttl_secs: u64,
+) -> SJMCLResult<String> {
+  let (client, request) = request_builder.build_split();
+  let mut request = request.map_err(|_| ResourceError::NetworkError)?;
+  let url = request.url().to_string();
+
+  let mut hasher = Sha1::new();
+  hasher.update(request.method().as_str());
+  hasher.update(&url);
+  if let Some(body) = request.body().and_then(|body| body.as_bytes()) {
+    hasher.update(body);
+  }
+  let cache_path =
+    cache_dir.map(|dir| dir.join(format!("{}.json", hex::encode(hasher.finalize()))));
+  let cached = cache_path.as_deref().and_then(load_cache_entry);
+
+  if let Some(entry) = &cached {
+    if entry.is_fresh(ttl_secs) {
+      return Ok(entry.body.clone());
+    }
+    let headers = request.headers_mut();
+    if let Some(etag) = entry
+      .etag
+      .as_deref()
+      .and_then(|v| HeaderValue::from_str(v).ok())
+    {
+      headers.insert(IF_NONE_MATCH, etag);
+    }
+    if let Some(last_modified) = entry
+      .last_modified
+      .as_deref()
+      .and_then(|v| HeaderValue::from_str(v).ok())
+    {
+      headers.insert(IF_MODIFIED_SINCE, last_modified);
+    }
+  }
+
+  let response = match client.execute(request).await {
+    Ok(response) => response,
+    Err(_) => return serve_stale(cached, &url),
+  };
+  let status = response.status();
+  let now = chrono::Utc::now().timestamp();
+
+  if status == StatusCode::NOT_MODIFIED {
+    if let Some(mut entry) = cached {
+      entry.fetched_at = now;
+      if let Some(path) = &cache_path {
+        save_cache_entry(path, &entry);
+      }
+      return Ok(entry.body);
+    }
+    return Err(ResourceError::NetworkError.into());
+  }
+  if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+    return serve_stale(cached, &url);
+  }
+  if !status.is_success() {
+    return Err(ResourceError::NetworkError.into());
+  }
+
+  let etag = get_header(&response, ETAG);
+  let last_modified = get_header(&response, LAST_MODIFIED);
+  let body = match response.text().await {
+    Ok(body) => body,
+    Err(_) => return serve_stale(cached, &url),
+  };
+
+  if let Some(path) = &cache_path {
+    save_cache_entry(
+      path,
+      &HttpCacheEntry {
+        url,
+        etag,
+        last_modified,
+        fetched_at: now,
+        body: body.clone(),
+      },
+    );
+  }
+
+  Ok(body)
+}
+
+/// Removes the cached responses fetched more than `max_age_secs` ago, and the unreadable ones,
+/// so that responses of requests no longer sent do not pile up in the cache directory.
+pub async fn purge_http_cache(app: &AppHandle, max_age_secs: u64) -> SJMCLResult<()> {
+  match get_cache_dir(app) {
+    Some(cache_dir) => purge_http_cache_in(&cache_dir, max_age_secs).await,
+    None => Ok(()),
+  }
+}
+
+async fn purge_http_cache_in(cache_dir: &Path, max_age_secs: u64) -> SJMCLResult<()> {
+  if !cache_dir.exists() {
+    return Ok(());
+  }
+  let cutoff = chrono::Utc::now()
+    .timestamp()
+    .saturating_sub(max_age_secs as i64);
+
+  let mut entries = tokio::fs::read_dir(cache_dir).await?;
+  while let Some(entry) = entries.next_entry().await? {
+    let path = entry.path();
+    if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+      continue;
+    }
+    let expired = tokio::fs::read_to_string(&path)
+      .await
+      .ok()
+      .and_then(|content| serde_json::from_str::<HttpCacheEntry>(&content).ok())
+      .is_none_or(|cached| cached.fetched_at < cutoff);
+    if expired {
+      if let Err(e) = tokio::fs::remove_file(&path).await {
+        log::warn!("Failed to remove {}: {}", path.display(), e);
+      }
+    }
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::sync::atomic::{AtomicUsize, Ordering};
+  use std::sync::Arc;
+  use tauri_plugin_http::reqwest::Client;
+  use tokio::io::{AsyncReadExt, AsyncWriteExt};
+  use tokio::net::TcpListener;
+  use tokio::task::JoinHandle;
+  use url::Url;
+
+  const BODY: &str = "{\"versions\":[]}";
+  const TAG: &str = "\"v1\"";
+
+  struct Endpoint {
+    url: Url,
+    status: Arc<Mutex<&'static str>>,
+    hits: Arc<AtomicUsize>,
+    revalidations: Arc<AtomicUsize>,
+    server: JoinHandle<()>,
+  }
+
+  // a local endpoint answering with the current `status`, tagged with `TAG`,
+  // and with 304 when a successful request revalidates that tag
+  async fn serve(status: &'static str) -> Endpoint {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let status = Arc::new(Mutex::new(status));
+    let hits = Arc::new(AtomicUsize::new(0));
+    let revalidations = Arc::new(AtomicUsize::new(0));
+    let (status_ref, hits_ref, revalidations_ref) =
+      (status.clone(), hits.clone(), revalidations.clone());
+    let server = tokio::spawn(async move {
+      while let Ok((mut stream, _)) = listener.accept().await {
+        let mut buf = [0u8; 4096];
+        let len = stream.read(&mut buf).await.unwrap_or(0);
+        let request = String::from_utf8_lossy(&buf[..len]).to_lowercase();
+        hits_ref.fetch_add(1, Ordering::SeqCst);
+
+        let status = *status_ref.lock().unwrap();
+        let (status, body) = if status != "200 OK" {
+          (status, "")
+        } else if request.contains(&format!("if-none-match: {}", TAG)) {
+          revalidations_ref.fetch_add(1, Ordering::SeqCst);
+          ("304 Not Modified", "")
+        } else {
+          (status, BODY)
+        };
+        let response = format!(
+          "HTTP/1.1 {}\r\nETag: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+          status,
+          TAG,
+          body.len(),
+          body
+        );
+        let _ = stream.write_all(response.as_bytes()).await;
+      }
+    });
+    Endpoint {
+      url: Url::parse(&format!("http://{}/", addr)).unwrap(),
+      status,
+      hits,
+      revalidations,
+      server,
+    }
+  }
+
+  fn client() -> Client {
+    Client::builder().no_proxy().build().unwrap()
+  }
+
+  fn cache_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+      "sjmcl-http-cache-test-{}-{}",
+      std::process::id(),
+      name
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+  }
+
+  async fn fetch(dir: &Path, url: &Url, ttl_secs: u64) -> SJMCLResult<String> {
+    send_with_cache_in(Some(dir), client().get(url.clone()), ttl_secs).await
+  }
+
+  #[tokio::test]
+  async fn fresh_response_is_served_without_request() {
+    let dir = cache_dir("fresh");
+    let endpoint = serve("200 OK").await;
+
+    assert_eq!(fetch(&dir, &endpoint.url, 60).await.unwrap(), BODY);
+    assert_eq!(fetch(&dir, &endpoint.url, 60).await.unwrap(), BODY);
+    assert_eq!(endpoint.hits.load(Ordering::SeqCst), 1);
+  }
+
+  #[tokio::test]
+  async fn stale_response_is_revalidated_by_etag() {
+    let dir = cache_dir("revalidate");
+    let endpoint = serve("200 OK").await;
+
+    assert_eq!(fetch(&dir, &endpoint.url, 0).await.unwrap(), BODY);
+    assert_eq!(fetch(&dir, &endpoint.url, 0).await.unwrap(), BODY);
+    assert_eq!(endpoint.hits.load(Ordering::SeqCst), 2);
+    assert_eq!(endpoint.revalidations.load(Ordering::SeqCst), 1);
+  }
+
+  #[tokio::test]
+  async fn stale_response_is_served_on_server_error() {
+    let dir = cache_dir("server-error");
+    let endpoint = serve("200 OK").await;
+
+    assert_eq!(fetch(&dir, &endpoint.url, 0).await.unwrap(), BODY);
+    *endpoint.status.lock().unwrap() = "503 Service Unavailable";
+    assert_eq!(fetch(&dir, &endpoint.url, 0).await.unwrap(), BODY);
+    assert_eq!(endpoint.hits.load(Ordering::SeqCst), 2);
+  }
+
+  #[tokio::test]
+  async fn stale_response_is_served_when_connection_refused() {
+    let dir = cache_dir("refused");
+    let endpoint = serve("200 OK").await;
+
+    assert_eq!(fetch(&dir, &endpoint.url, 0).await.unwrap(), BODY);
+    // dropping the listener makes the port refuse connections
+    endpoint.server.abort();
+    let _ = endpoint.server.await;
+    assert_eq!(fetch(&dir, &endpoint.url, 0).await.unwrap(), BODY);
+  }
+
+  #[tokio::test]
+  async fn failure_without_cache_is_an_error() {
+    let dir = cache_dir("no-cache");
+    let endpoint = serve("503 Service Unavailable").await;
+    assert!(fetch(&dir, &endpoint.url, 60).await.is_err());
+
+    endpoint.server.abort();
+    let _ = endpoint.server.await;
+    assert!(fetch(&dir, &endpoint.url, 60).await.is_err());
+  }
+
+  #[tokio::test]
+  async fn purge_removes_expired_and_unreadable_entries() {
+    let dir = cache_dir("purge");
+    let now = chrono::Utc::now().timestamp();
+    let entry = |fetched_at| HttpCacheEntry {
+      fetched_at,
+      body: BODY.to_string(),
+      ..Default::default()
+    };
+    save_cache_entry(&dir.join("recent.json"), &entry(now));
+    save_cache_entry(&dir.join("expired.json"), &entry(now - 7200));
+    fs::write(dir.join("broken.json"), "not json").unwrap();
+
+    purge_http_cache_in(&dir, 3600).await.unwrap();
+    assert!(dir.join("recent.json").exists());
+    assert!(!dir.join("expired.json").exists());
+    assert!(!dir.join("broken.json").exists());
+  }
+}
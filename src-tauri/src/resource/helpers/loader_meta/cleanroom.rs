@@ -0,0 +1,57 @@
+use crate::error::{SJMCLError, SJMCLResult};
+use crate::instance::models::misc::ModLoaderType;
+use crate::resource::constants::LOADER_META_CACHE_TTL;
+use crate::resource::helpers::http_cache::send_with_cache;
+use crate::resource::helpers::misc::get_download_api;
+use crate::resource::models::{ModLoaderResourceInfo, ResourceType, SourceType};
+use lazy_static::lazy_static;
+use regex::Regex;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_http::reqwest;
+
+// Cleanroom only targets 1.12.2
+const CLEANROOM_GAME_VERSION: &str = "1.12.2";
+
+lazy_static! {
+  static ref MAVEN_VERSION_REGEX: Regex = Regex::new(r"<version>([^<]+)</version>").unwrap();
+}
+
+pub async fn get_cleanroom_meta_by_game_version(
+  app: &AppHandle,
+  priority_list: &[SourceType],
+  game_version: &str,
+) -> SJMCLResult<Vec<ModLoaderResourceInfo>> {
+  if game_version != CLEANROOM_GAME_VERSION {
+    return Ok(Vec::new());
+  }
+
+  let client = app.state::<reqwest::Client>();
+  for source_type in priority_list.iter() {
+    let Ok(api) = get_download_api(*source_type, ResourceType::CleanroomMaven) else {
+      continue;
+    };
+    let url = api.join("com/cleanroommc/cleanroom/maven-metadata.xml")?;
+    match send_with_cache(app, client.get(url), LOADER_META_CACHE_TTL).await {
+      Ok(metadata) => {
+        // versions in the maven metadata are listed from the oldest
+        return Ok(
+          MAVEN_VERSION_REGEX
+            .captures_iter(&metadata)
+            .map(|caps| caps[1].to_string())
+            .rev()
+            .map(|version| ModLoaderResourceInfo {
+              loader_type: ModLoaderType::Cleanroom,
+              // all releases so far are suffixed like `0.3.0-alpha`
+              stable: !version.contains('-'),
+              version,
+              description: String::new(),
+              branch: None,
+            })
+            .collect(),
+        );
+      }
+      Err(_) => continue,
+    }
+  }
+  Err(SJMCLError(String::new()))
+}
@@ -1,5 +1,7 @@
 use crate::error::{SJMCLError, SJMCLResult};
 use crate::instance::models::misc::ModLoaderType;
+use crate::resource::constants::LOADER_META_CACHE_TTL;
+use crate::resource::helpers::http_cache::send_with_cache;
 use crate::resource::helpers::misc::get_download_api;
 use crate::resource::models::{ModLoaderResourceInfo, ResourceError, ResourceType, SourceType};
 use serde::{Deserialize, Serialize};
@@ -31,30 +33,27 @@ pub async fn get_fabric_meta_by_game_version(
 ) -> SJMCLResult<Vec<ModLoaderResourceInfo>> {
   let client = app.state::<reqwest::Client>();
   for source_type in priority_list.iter() {
-    let url = get_download_api(*source_type, ResourceType::FabricMeta)?
-      .join("v2/versions/loader/")?
-      .join(game_version)?;
-    match client.get(url).send().await {
-      Ok(response) => {
-        if response.status().is_success() {
-          if let Ok(manifest) = response.json::<Vec<FabricMetaItem>>().await {
-            return Ok(
-              manifest
-                .into_iter()
-                .map(|info| ModLoaderResourceInfo {
-                  loader_type: ModLoaderType::Fabric,
-                  version: info.loader.version,
-                  description: String::new(),
-                  stable: info.loader.stable,
-                  branch: None,
-                })
-                .collect(),
-            );
-          } else {
-            return Err(ResourceError::ParseError.into());
-          }
+    let Ok(api) = get_download_api(*source_type, ResourceType::FabricMeta) else {
+      continue;
+    };
+    let url = api.join("v2/versions/loader/")?.join(game_version)?;
+    match send_with_cache(app, client.get(url), LOADER_META_CACHE_TTL).await {
+      Ok(body) => {
+        if let Ok(manifest) = serde_json::from_str::<Vec<FabricMetaItem>>(&body) {
+          return Ok(
+            manifest
+              .into_iter()
+              .map(|info| ModLoaderResourceInfo {
+                loader_type: ModLoaderType::Fabric,
+                version: info.loader.version,
+                description: String::new(),
+                stable: info.loader.stable,
+                branch: None,
+              })
+              .collect(),
+          );
         } else {
-          continue;
+          return Err(ResourceError::ParseError.into());
         }
       }
       Err(_) => continue,
@@ -0,0 +1,155 @@
+use crate::error::{SJMCLError, SJMCLResult};
+use crate::instance::models::misc::ModLoaderType;
+use crate::resource::constants::LOADER_META_CACHE_TTL;
+use crate::resource::helpers::http_cache::send_with_cache;
+use crate::resource::helpers::misc::get_download_api;
+use crate::resource::models::{ModLoaderResourceInfo, ResourceError, ResourceType, SourceType};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_http::reqwest;
+
+const LITELOADER_ARTEFACT_KEY: &str = "com.mumfrey:liteloader";
+const DEFAULT_LITELOADER_REPO: &str = "https://dl.liteloader.com/versions/";
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+struct LiteLoaderVersionsJson {
+  pub versions: HashMap<String, LiteLoaderGameVersion>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+struct LiteLoaderGameVersion {
+  pub repo: Option<LiteLoaderRepo>,
+  pub artefacts: HashMap<String, HashMap<String, Value>>,
+  pub snapshots: HashMap<String, HashMap<String, Value>>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+struct LiteLoaderRepo {
+  pub url: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct LiteLoaderLibrary {
+  pub name: String,
+  pub url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LiteLoaderArtefact {
+  pub tweak_class: String,
+  pub libraries: Vec<LiteLoaderLibrary>,
+  pub stream: String,
+  pub file: String,
+  pub version: String,
+  pub timestamp: Value,
+  // the maven repository hosting the jar, filled from the game version entry
+  #[serde(skip)]
+  pub repo_url: String,
+}
+
+impl LiteLoaderArtefact {
+  pub fn is_snapshot(&self) -> bool {
+    self.stream.eq_ignore_ascii_case("SNAPSHOT")
+  }
+}
+
+async fn fetch_liteloader_versions(
+  app: &AppHandle,
+  priority_list: &[SourceType],
+) -> SJMCLResult<LiteLoaderVersionsJson> {
+  let client = app.state::<reqwest::Client>();
+  for source_type in priority_list.iter() {
+    let Ok(url) = get_download_api(*source_type, ResourceType::Liteloader) else {
+      continue;
+    };
+    match send_with_cache(app, client.get(url), LOADER_META_CACHE_TTL).await {
+      Ok(body) => {
+        return serde_json::from_str::<LiteLoaderVersionsJson>(&body)
+          .map_err(|_| ResourceError::ParseError.into());
+      }
+      Err(_) => continue,
+    }
+  }
+  Err(SJMCLError(String::new()))
+}
+
+// releases come from `artefacts`, snapshots (e.g. 1.12.2) from `snapshots`; `latest` duplicates one of them
+fn get_artefacts(game_version: &LiteLoaderGameVersion) -> Vec<LiteLoaderArtefact> {
+  let repo_url = game_version
+    .repo
+    .as_ref()
+    .map(|repo| repo.url.clone())
+    .filter(|url| !url.is_empty())
+    .unwrap_or(DEFAULT_LITELOADER_REPO.to_string());
+  [&game_version.artefacts, &game_version.snapshots]
+    .into_iter()
+    .filter_map(|branch| branch.get(LITELOADER_ARTEFACT_KEY))
+    .flat_map(|items| items.iter())
+    .filter(|(key, _)| key.as_str() != "latest")
+    .filter_map(|(_, value)| serde_json::from_value::<LiteLoaderArtefact>(value.clone()).ok())
+    .map(|artefact| LiteLoaderArtefact {
+      repo_url: repo_url.clone(),
+      ..artefact
+    })
+    .collect()
+}
+
+fn get_timestamp(artefact: &LiteLoaderArtefact) -> i64 {
+  match &artefact.timestamp {
+    Value::String(s) => s.parse().unwrap_or_default(),
+    Value::Number(n) => n.as_i64().unwrap_or_default(),
+    _ => 0,
+  }
+}
+
+pub async fn get_liteloader_meta_by_game_version(
+  app: &AppHandle,
+  priority_list: &[SourceType],
+  game_version: &str,
+) -> SJMCLResult<Vec<ModLoaderResourceInfo>> {
+  let manifest = fetch_liteloader_versions(app, priority_list).await?;
+  let Some(entry) = manifest.versions.get(game_version) else {
+    return Ok(Vec::new());
+  };
+
+  let mut artefacts = get_artefacts(entry);
+  artefacts.sort_by_key(|artefact| std::cmp::Reverse(get_timestamp(artefact)));
+  Ok(
+    artefacts
+      .into_iter()
+      .map(|artefact| ModLoaderResourceInfo {
+        loader_type: ModLoaderType::LiteLoader,
+        description: chrono::DateTime::from_timestamp(get_timestamp(&artefact), 0)
+          .map(|time| time.to_rfc3339())
+          .unwrap_or_default(),
+        stable: !artefact.is_snapshot(),
+        version: artefact.version,
+        branch: None,
+      })
+      .collect(),
+  )
+}
+
+pub async fn get_liteloader_artefact(
+  app: &AppHandle,
+  priority_list: &[SourceType],
+  game_version: &str,
+  loader_version: &str,
+) -> SJMCLResult<LiteLoaderArtefact> {
+  let manifest = fetch_liteloader_versions(app, priority_list).await?;
+  let entry = manifest
+    .versions
+    .get(game_version)
+    .ok_or(ResourceError::ClientVersionNotFound)?;
+  get_artefacts(entry)
+    .into_iter()
+    .find(|artefact| artefact.version == loader_version)
+    .ok_or(ResourceError::ParseError.into())
+}
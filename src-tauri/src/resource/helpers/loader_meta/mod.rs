@@ -1,4 +1,7 @@
+pub mod cleanroom;
 pub mod fabric;
 pub mod forge;
+pub mod liteloader;
 pub mod neoforge;
 pub mod optifine;
+pub mod quilt;
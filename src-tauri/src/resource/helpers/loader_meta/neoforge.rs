@@ -1,5 +1,7 @@
 use crate::error::SJMCLResult;
 use crate::instance::models::misc::ModLoaderType;
+use crate::resource::constants::LOADER_META_CACHE_TTL;
+use crate::resource::helpers::http_cache::send_with_cache;
 use crate::resource::helpers::misc::get_download_api;
 use crate::resource::models::{ModLoaderResourceInfo, ResourceError, ResourceType, SourceType};
 use lazy_static::lazy_static;
@@ -26,6 +28,7 @@ struct NeoforgeVersions {
 // https://github.com/HMCL-dev/HMCL/blob/efd088e014bf1c113f7b3fdf73fb983087ae3f5e/HMCLCore/src/main/java/org/jackhuang/hmcl/download/neoforge/NeoForgeOfficialVersionList.java
 async fn get_neoforge_meta_by_game_version_official(
   app: &AppHandle,
+  source_type: SourceType,
   game_version: &str,
 ) -> SJMCLResult<Vec<ModLoaderResourceInfo>> {
   lazy_static! {
@@ -49,20 +52,10 @@ async fn get_neoforge_meta_by_game_version_official(
   let client = app.state::<reqwest::Client>();
 
   if game_version == "1.20.1" {
-    let url = get_download_api(SourceType::Official, ResourceType::NeoforgeMetaForge)?;
-    let response = client
-      .get(url)
-      .send()
-      .await
-      .map_err(|_| ResourceError::NetworkError)?;
-    if !response.status().is_success() {
-      return Err(ResourceError::NetworkError.into());
-    }
-
-    let versions: serde_json::Value = response
-      .json()
-      .await
-      .map_err(|_| ResourceError::ParseError)?;
+    let url = get_download_api(source_type, ResourceType::NeoforgeMetaForge)?;
+    let body = send_with_cache(app, client.get(url), LOADER_META_CACHE_TTL).await?;
+    let versions: serde_json::Value =
+      serde_json::from_str(&body).map_err(|_| ResourceError::ParseError)?;
     let Some(version_list) = versions.get("versions").and_then(|v| v.as_array()) else {
       return Err(ResourceError::ParseError.into());
     };
@@ -95,20 +88,10 @@ async fn get_neoforge_meta_by_game_version_official(
     return Ok(results.into_iter().map(|r| r.1).collect());
   }
 
-  let url = get_download_api(SourceType::Official, ResourceType::NeoforgeMetaNeoforge)?;
-  let response = client
-    .get(url)
-    .send()
-    .await
-    .map_err(|_| ResourceError::NetworkError)?;
-  if !response.status().is_success() {
-    return Err(ResourceError::NetworkError.into());
-  }
-
-  let versions: serde_json::Value = response
-    .json()
-    .await
-    .map_err(|_| ResourceError::ParseError)?;
+  let url = get_download_api(source_type, ResourceType::NeoforgeMetaNeoforge)?;
+  let body = send_with_cache(app, client.get(url), LOADER_META_CACHE_TTL).await?;
+  let versions: serde_json::Value =
+    serde_json::from_str(&body).map_err(|_| ResourceError::ParseError)?;
   let Some(version_list) = versions.get("versions").and_then(|v| v.as_array()) else {
     return Err(ResourceError::ParseError.into());
   };
@@ -200,50 +183,42 @@ async fn get_neoforge_meta_by_game_version_bmcl(
   )?
   .join("list/")?
   .join(game_version)?;
-  match client.get(url).send().await {
-    Ok(response) => {
-      if response.status().is_success() {
-        if let Ok(mut manifest) = response.json::<Vec<NeoforgeMetaItem>>().await {
-          manifest.sort_by(|a, b| {
-            let parse_version = |v: &str| {
-              let stripped = if game_version == "1.20.1" {
-                v.strip_prefix("1.20.1-").unwrap_or(v)
-              } else {
-                v
-              };
-              stripped
-                .split('.')
-                .flat_map(|part| part.split('-'))
-                .flat_map(|part| part.split('+'))
-                .map(|s| s.parse::<i32>().unwrap_or(0))
-                .collect::<Vec<_>>()
-            };
-            parse_version(&b.version).cmp(&parse_version(&a.version))
-          });
-          Ok(
-            manifest
-              .into_iter()
-              .map(|info| {
-                let version = info.version;
-                let stable = !version.contains("beta") && !version.contains("alpha");
-                ModLoaderResourceInfo {
-                  loader_type: ModLoaderType::NeoForge,
-                  version,
-                  description: String::new(),
-                  stable,
-                  branch: None,
-                }
-              })
-              .collect(),
-          )
+  let body = send_with_cache(app, client.get(url), LOADER_META_CACHE_TTL).await?;
+  if let Ok(mut manifest) = serde_json::from_str::<Vec<NeoforgeMetaItem>>(&body) {
+    manifest.sort_by(|a, b| {
+      let parse_version = |v: &str| {
+        let stripped = if game_version == "1.20.1" {
+          v.strip_prefix("1.20.1-").unwrap_or(v)
         } else {
-          Err(ResourceError::ParseError.into())
-        }
-      } else {
-        Err(ResourceError::NetworkError.into())
-      }
-    }
-    Err(_) => Err(ResourceError::NetworkError.into()),
+          v
+        };
+        stripped
+          .split('.')
+          .flat_map(|part| part.split('-'))
+          .flat_map(|part| part.split('+'))
+          .map(|s| s.parse::<i32>().unwrap_or(0))
+          .collect::<Vec<_>>()
+      };
+      parse_version(&b.version).cmp(&parse_version(&a.version))
+    });
+    Ok(
+      manifest
+        .into_iter()
+        .map(|info| {
+          let version = info.version;
+          let stable = !version.contains("beta") && !version.contains("alpha");
+          ModLoaderResourceInfo {
+            loader_type: ModLoaderType::NeoForge,
+            version,
+            description: String::new(),
+            stable,
+            branch: None,
+          }
+        })
+        .collect(),
+    )
+  } else {
+    Err(ResourceError::ParseError.into())
   }
 }
 
@@ -254,8 +229,10 @@ pub async fn get_neoforge_meta_by_game_version(
 ) -> SJMCLResult<Vec<ModLoaderResourceInfo>> {
   for source_type in priority_list.iter() {
     match *source_type {
-      SourceType::Official => {
-        if let Ok(meta) = get_neoforge_meta_by_game_version_official(app, game_version).await {
+      SourceType::Official | SourceType::Custom(_) => {
+        if let Ok(meta) =
+          get_neoforge_meta_by_game_version_official(app, *source_type, game_version).await
+        {
           return Ok(meta);
         }
       }
@@ -1,4 +1,6 @@
 use crate::error::SJMCLResult;
+use crate::resource::constants::LOADER_META_CACHE_TTL;
+use crate::resource::helpers::http_cache::send_with_cache;
 use crate::resource::helpers::misc::get_download_api;
 use crate::resource::models::{OptiFineResourceInfo, ResourceError, ResourceType, SourceType};
 use tauri::Manager;
@@ -11,19 +13,9 @@ async fn get_optifine_meta_by_game_version_bmcl(
   let client = app.state::<reqwest::Client>();
   let url =
     get_download_api(SourceType::BMCLAPIMirror, ResourceType::OptiFine)?.join(game_version)?;
-  match client.get(url).send().await {
-    Ok(response) => {
-      if response.status().is_success() {
-        response
-          .json::<Vec<OptiFineResourceInfo>>()
-          .await
-          .map_err(|_| ResourceError::ParseError.into())
-      } else {
-        Err(ResourceError::NetworkError.into())
-      }
-    }
-    Err(_) => Err(ResourceError::NetworkError.into()),
-  }
+  let body = send_with_cache(app, client.get(url), LOADER_META_CACHE_TTL).await?;
+  serde_json::from_str::<Vec<OptiFineResourceInfo>>(&body)
+    .map_err(|_| ResourceError::ParseError.into())
 }
 
 pub async fn get_optifine_meta_by_game_version(
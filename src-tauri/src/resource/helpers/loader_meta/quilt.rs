@@ -0,0 +1,64 @@
+use crate::error::{SJMCLError, SJMCLResult};
+use crate::instance::models::misc::ModLoaderType;
+use crate::resource::constants::LOADER_META_CACHE_TTL;
+use crate::resource::helpers::http_cache::send_with_cache;
+use crate::resource::helpers::misc::get_download_api;
+use crate::resource::models::{ModLoaderResourceInfo, ResourceError, ResourceType, SourceType};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_http::reqwest;
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct QuiltMetaItem {
+  pub loader: QuiltLoaderInfo,
+  pub hashed: Value,
+  pub intermediary: Value,
+  pub launcher_meta: Value,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct QuiltLoaderInfo {
+  pub separator: String,
+  pub build: i64,
+  pub maven: String,
+  pub version: String,
+}
+
+pub async fn get_quilt_meta_by_game_version(
+  app: &AppHandle,
+  priority_list: &[SourceType],
+  game_version: &str,
+) -> SJMCLResult<Vec<ModLoaderResourceInfo>> {
+  let client = app.state::<reqwest::Client>();
+  for source_type in priority_list.iter() {
+    let Ok(api) = get_download_api(*source_type, ResourceType::QuiltMeta) else {
+      continue;
+    };
+    let url = api.join("v3/versions/loader/")?.join(game_version)?;
+    match send_with_cache(app, client.get(url), LOADER_META_CACHE_TTL).await {
+      Ok(body) => {
+        if let Ok(manifest) = serde_json::from_str::<Vec<QuiltMetaItem>>(&body) {
+          return Ok(
+            manifest
+              .into_iter()
+              .map(|info| ModLoaderResourceInfo {
+                loader_type: ModLoaderType::Quilt,
+                // quilt meta has no stable flag, pre-releases are like `0.20.0-beta.9`
+                stable: !info.loader.version.contains('-'),
+                version: info.loader.version,
+                description: String::new(),
+                branch: None,
+              })
+              .collect(),
+          );
+        } else {
+          return Err(ResourceError::ParseError.into());
+        }
+      }
+      Err(_) => continue,
+    }
+  }
+  Err(SJMCLError(String::new()))
+}
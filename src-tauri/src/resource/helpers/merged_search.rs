@@ -0,0 +1,314 @@
+use crate::error::SJMCLResult;
+use crate::resource::constants::SEARCH_CACHE_TTL;
+use crate::resource::helpers::modrinth::fetch_versions_by_hashes_modrinth;
+use crate::resource::helpers::provider::{get_resource_provider, ResourceProvider};
+use crate::resource::models::{
+  OtherResourceInfo, OtherResourceLink, OtherResourceSearchQuery, OtherResourceSearchRes,
+  OtherResourceSource, ResourceError,
+};
+use futures::future::join_all;
+use lazy_static::lazy_static;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+
+const ALL_FILTER: &str = "All";
+
+// tags offered by the merged search, named after the Modrinth category, with the CurseForge
+// category of the same meaning for each resource type.
+const MERGED_TAGS: &[(&str, &str, &str)] = &[
+  ("mod", "adventure", "Adventure and RPG"),
+  ("mod", "decoration", "Cosmetic"),
+  ("mod", "equipment", "Armor, Tools, and Weapons"),
+  ("mod", "food", "Food"),
+  ("mod", "library", "API and Library"),
+  ("mod", "magic", "Magic"),
+  ("mod", "mobs", "Mobs"),
+  ("mod", "optimization", "Performance"),
+  ("mod", "storage", "Storage"),
+  ("mod", "technology", "Technology"),
+  ("mod", "transportation", "Player Transport"),
+  ("mod", "utility", "Utility & QoL"),
+  ("mod", "worldgen", "World Gen"),
+  ("resourcepack", "16x", "16x"),
+  ("resourcepack", "32x", "32x"),
+  ("resourcepack", "64x", "64x"),
+  ("resourcepack", "128x", "128x"),
+  ("resourcepack", "256x", "256x"),
+  ("resourcepack", "512x+", "512x and Higher"),
+  ("resourcepack", "fonts", "Font Packs"),
+  ("resourcepack", "modded", "Mod Support"),
+  ("resourcepack", "realistic", "Photo Realistic"),
+  ("shader", "fantasy", "Fantasy"),
+  ("shader", "realistic", "Realistic"),
+  ("shader", "vanilla-like", "Vanilla"),
+  ("datapack", "adventure", "Adventure"),
+  ("datapack", "library", "Library"),
+  ("datapack", "magic", "Magic"),
+  ("datapack", "technology", "Tech"),
+  ("datapack", "utility", "Utility"),
+  ("modpack", "adventure", "Adventure and RPG"),
+  ("modpack", "challenging", "Hardcore"),
+  ("modpack", "combat", "Combat / PvP"),
+  ("modpack", "kitchen-sink", "Extra Large"),
+  ("modpack", "lightweight", "Small / Light"),
+  ("modpack", "magic", "Magic"),
+  ("modpack", "multiplayer", "Multiplayer"),
+  ("modpack", "quests", "Quests"),
+  ("modpack", "technology", "Tech"),
+];
+
+// the tags of the merged search on CurseForge and Modrinth, None if the tag is unknown.
+fn cvt_merged_tag(resource_type: &str, tag: &str) -> Option<(&'static str, &'static str)> {
+  if tag.is_empty() || tag == ALL_FILTER {
+    return Some((ALL_FILTER, ALL_FILTER));
+  }
+  MERGED_TAGS
+    .iter()
+    .find(|(_type, modrinth, _)| *_type == resource_type && *modrinth == tag)
+    .map(|(_, modrinth, curseforge)| (*curseforge, *modrinth))
+}
+
+// normalized sort orders of the merged search, mapped to the `sort_by` of CurseForge and Modrinth.
+// there is no order by name, since Modrinth cannot sort its results that way.
+fn cvt_merged_sort_by(sort_by: &str) -> (&'static str, &'static str) {
+  match sort_by {
+    "downloads" => ("Total downloads", "downloads"),
+    "updated" => ("Latest update", "updated"),
+    "newest" => ("Creation date", "newest"),
+    _ => ("Popularity", "relevance"),
+  }
+}
+
+// orders without a comparable field in the results keep the rank given by each platform.
+fn cmp_by_sort_key(a: &OtherResourceInfo, b: &OtherResourceInfo, sort_by: &str) -> Ordering {
+  match sort_by {
+    "downloads" => b.downloads.cmp(&a.downloads),
+    "updated" => {
+      let parse = |date: &str| chrono::DateTime::parse_from_rfc3339(date).ok();
+      parse(&b.last_updated).cmp(&parse(&a.last_updated))
+    }
+    _ => Ordering::Equal,
+  }
+}
+
+lazy_static! {
+  // pages of each source fetched by the previous merged searches, keyed by the source and the
+  // query without its page, so that loading more only fetches the next page of each source.
+  static ref LEADING_PAGES: Mutex<HashMap<String, (Instant, Vec<OtherResourceSearchRes>)>> =
+    Mutex::new(HashMap::new());
+}
+
+// the first `page + 1` pages of one source, so that the merged page can be cut at the same
+// position regardless of how many duplicates were removed before it.
+async fn fetch_leading_pages(
+  app: &AppHandle,
+  provider: &dyn ResourceProvider,
+  query: &OtherResourceSearchQuery,
+) -> SJMCLResult<(Vec<OtherResourceInfo>, u64)> {
+  let key = format!(
+    "{:?}:{:?}",
+    provider.source(),
+    OtherResourceSearchQuery {
+      page: 0,
+      ..query.clone()
+    }
+  );
+  let ttl = Duration::from_secs(SEARCH_CACHE_TTL);
+  let mut pages = {
+    let mut cached = LEADING_PAGES.lock()?;
+    cached.retain(|_, (fetched_at, _)| fetched_at.elapsed() < ttl);
+    cached
+      .get(&key)
+      .map(|(_, pages)| pages.clone())
+      .unwrap_or_default()
+  };
+
+  let is_last = |page: &OtherResourceSearchRes| (page.list.len() as u32) < query.page_size;
+  if pages.len() <= query.page as usize && !pages.last().is_some_and(is_last) {
+    let queries: Vec<OtherResourceSearchQuery> = (pages.len() as u32..=query.page)
+      .map(|page| OtherResourceSearchQuery {
+        page,
+        ..query.clone()
+      })
+      .collect();
+    let fetched = join_all(queries.iter().map(|q| provider.search(app, q))).await;
+    for page in fetched {
+      pages.push(page?);
+    }
+    LEADING_PAGES
+      .lock()?
+      .insert(key, (Instant::now(), pages.clone()));
+  }
+
+  let mut list = Vec::new();
+  let mut total = 0;
+  for page in pages.into_iter().take(query.page as usize + 1) {
+    total = page.total;
+    let reached_end = is_last(&page);
+    list.extend(page.list);
+    if reached_end {
+      break;
+    }
+  }
+  Ok((list, total))
+}
+
+fn is_same_project(
+  a: &OtherResourceInfo,
+  b: &OtherResourceInfo,
+  hash_links: &HashSet<(String, String)>,
+) -> bool {
+  if a.source == b.source || a._type != b._type {
+    return false;
+  }
+  if !a.slug.is_empty() && a.slug.eq_ignore_ascii_case(&b.slug) {
+    return true;
+  }
+  if a.mcmod_id != 0 && a.mcmod_id == b.mcmod_id {
+    return true;
+  }
+  let (cf, mr) = match a.source {
+    OtherResourceSource::CurseForge => (a, b),
+    _ => (b, a),
+  };
+  hash_links.contains(&(cf.id.clone(), mr.id.clone()))
+}
+
+fn link_of(info: &OtherResourceInfo) -> OtherResourceLink {
+  OtherResourceLink {
+    id: info.id.clone(),
+    slug: info.slug.clone(),
+    source: info.source.clone(),
+    website_url: info.website_url.clone(),
+  }
+}
+
+/// Searches CurseForge and Modrinth concurrently and merges the results into one list.
+///
+/// The same project found on both sources is kept once (matched by slug, mcmod id, or the
+/// sha1 of its latest files), with the other source in `linked_sources`.
+/// The page is cut from the merged list, so paging is consistent across both sources.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri AppHandle.
+/// * `query` - The search query, `sort_by` is one of `relevance`, `downloads`, `updated` and
+///   `newest`, and `selected_tag` is one of the Modrinth categories in `MERGED_TAGS`.
+///
+/// # Returns
+///
+/// The merged page, whose total is the sum of both sources minus the duplicates found so far.
+pub async fn fetch_resource_list_by_name_merged(
+  app: &AppHandle,
+  query: &OtherResourceSearchQuery,
+) -> SJMCLResult<OtherResourceSearchRes> {
+  let (curseforge_sort_by, modrinth_sort_by) = cvt_merged_sort_by(&query.sort_by);
+  let (curseforge_tag, modrinth_tag) =
+    cvt_merged_tag(&query.resource_type, &query.selected_tag).ok_or(ResourceError::ParseError)?;
+  let curseforge = get_resource_provider(&OtherResourceSource::CurseForge)?;
+  let modrinth = get_resource_provider(&OtherResourceSource::Modrinth)?;
+  let (curseforge_res, modrinth_res) = tokio::join!(
+    fetch_leading_pages(
+      app,
+      curseforge.as_ref(),
+      &OtherResourceSearchQuery {
+        selected_tag: curseforge_tag.to_string(),
+        sort_by: curseforge_sort_by.to_string(),
+        ..query.clone()
+      },
+    ),
+    fetch_leading_pages(
+      app,
+      modrinth.as_ref(),
+      &OtherResourceSearchQuery {
+        selected_tag: modrinth_tag.to_string(),
+        sort_by: modrinth_sort_by.to_string(),
+        ..query.clone()
+      },
+    ),
+  );
+
+  // a source failing should not hide the results of the other one
+  let ((curseforge_list, curseforge_total), (modrinth_list, modrinth_total)) =
+    match (curseforge_res, modrinth_res) {
+      (Err(e), Err(_)) => return Err(e),
+      (curseforge_res, modrinth_res) => (
+        curseforge_res.unwrap_or_else(|e| {
+          log::warn!("Merged search failed on CurseForge: {:?}", e);
+          (vec![], 0)
+        }),
+        modrinth_res.unwrap_or_else(|e| {
+          log::warn!("Merged search failed on Modrinth: {:?}", e);
+          (vec![], 0)
+        }),
+      ),
+    };
+
+  // link CurseForge projects to Modrinth ones sharing a file
+  let hashes: Vec<String> = curseforge_list
+    .iter()
+    .flat_map(|info| info.latest_file_hashes.iter().cloned())
+    .collect();
+  let versions = fetch_versions_by_hashes_modrinth(app, modrinth.api_base(), &hashes)
+    .await
+    .unwrap_or_default();
+  let hash_links: HashSet<(String, String)> = curseforge_list
+    .iter()
+    .flat_map(|info| {
+      info
+        .latest_file_hashes
+        .iter()
+        .filter_map(|hash| versions.get(hash))
+        .map(|version| (info.id.clone(), version.project_id.clone()))
+    })
+    .collect();
+
+  // interleave by the rank on each platform, then apply the normalized order if comparable
+  let mut combined = Vec::with_capacity(curseforge_list.len() + modrinth_list.len());
+  let mut curseforge_iter = curseforge_list.into_iter();
+  let mut modrinth_iter = modrinth_list.into_iter();
+  loop {
+    let (cf, mr) = (curseforge_iter.next(), modrinth_iter.next());
+    if cf.is_none() && mr.is_none() {
+      break;
+    }
+    combined.extend(cf);
+    combined.extend(mr);
+  }
+  combined.sort_by(|a, b| cmp_by_sort_key(a, b, &query.sort_by));
+
+  let mut merged: Vec<OtherResourceInfo> = Vec::new();
+  let mut duplicates = 0;
+  for info in combined {
+    match merged.iter_mut().find(|existing| {
+      is_same_project(existing, &info, &hash_links)
+        && !existing
+          .linked_sources
+          .iter()
+          .any(|link| link.source == info.source)
+    }) {
+      Some(existing) => {
+        existing.linked_sources.push(link_of(&info));
+        duplicates += 1;
+      }
+      None => merged.push(info),
+    }
+  }
+
+  let start = (query.page * query.page_size) as usize;
+  let list = merged
+    .into_iter()
+    .skip(start)
+    .take(query.page_size as usize)
+    .collect();
+
+  Ok(OtherResourceSearchRes {
+    list,
+    total: (curseforge_total + modrinth_total).saturating_sub(duplicates),
+    page: query.page,
+    page_size: query.page_size,
+  })
+}
@@ -1,20 +1,79 @@
 use crate::error::SJMCLResult;
-use crate::launcher_config::models::LauncherConfig;
-use crate::resource::helpers::curseforge::misc::translate_description_curseforge;
+use crate::launcher_config::models::{CustomDownloadSource, LauncherConfig};
 use crate::resource::helpers::mod_db::ModDataBase;
-use crate::resource::helpers::modrinth::misc::translate_description_modrinth;
+use crate::resource::helpers::provider::get_resource_provider;
+use crate::resource::helpers::source_ranking::rank_sources;
 use crate::resource::models::{
-  OtherResourceInfo, OtherResourceSource, OtherResourceVersionPack, ResourceError, ResourceType,
-  SourceType,
+  OtherResourceInfo, OtherResourceVersionPack, ResourceError, ResourceType, SourceType,
 };
+use lazy_static::lazy_static;
 use std::cmp::Ordering;
-use std::sync::Mutex;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, RwLock};
 use strum::IntoEnumIterator;
 use tauri::{AppHandle, Manager};
 use url::Url;
 
+lazy_static! {
+  // custom sources keyed by their ids. the edited or removed ones are kept, so that a priority list
+  // built before still refers to the urls it was built with.
+  static ref CUSTOM_SOURCES: RwLock<HashMap<u64, CustomDownloadSource>> = RwLock::new(HashMap::new());
+}
+
+// derived from the content, an edited source gets a new id
+fn get_custom_source_id(source: &CustomDownloadSource) -> u64 {
+  let mut base_urls: Vec<(String, &String)> = source
+    .base_urls
+    .iter()
+    .map(|(resource_type, url)| (format!("{:?}", resource_type), url))
+    .collect();
+  base_urls.sort();
+  let mut hasher = DefaultHasher::new();
+  source.name.hash(&mut hasher);
+  base_urls.hash(&mut hasher);
+  hasher.finish()
+}
+
+// register the custom sources of the launcher config, called when it is loaded or updated.
+// returns the enabled ones in the configured order.
+pub fn register_custom_sources(launcher_config: &LauncherConfig) -> Vec<SourceType> {
+  let mut enabled = Vec::new();
+  let Ok(mut registered) = CUSTOM_SOURCES.write() else {
+    return enabled;
+  };
+  for source in &launcher_config.download.source.custom_sources {
+    let id = get_custom_source_id(source);
+    registered.entry(id).or_insert_with(|| source.clone());
+    if source.enabled && !enabled.contains(&SourceType::Custom(id)) {
+      enabled.push(SourceType::Custom(id));
+    }
+  }
+  enabled
+}
+
+// custom sources are preferred, followed by the builtin ones in the configured order.
+// with the auto strategy, all of them are reordered by the measured ranking once available.
 pub fn get_source_priority_list(launcher_config: &LauncherConfig) -> Vec<SourceType> {
-  match launcher_config.download.source.strategy.as_str() {
+  build_source_priority_list(launcher_config, None)
+}
+
+// same as `get_source_priority_list`, but ranked by the measurements of a single resource type.
+pub fn get_source_priority_list_for(
+  launcher_config: &LauncherConfig,
+  resource_type: ResourceType,
+) -> Vec<SourceType> {
+  build_source_priority_list(launcher_config, Some(resource_type))
+}
+
+fn build_source_priority_list(
+  launcher_config: &LauncherConfig,
+  resource_type: Option<ResourceType>,
+) -> Vec<SourceType> {
+  let mut priority_list = register_custom_sources(launcher_config);
+
+  priority_list.extend(match launcher_config.download.source.strategy.as_str() {
     "official" => vec![SourceType::Official, SourceType::BMCLAPIMirror],
     "mirror" => vec![SourceType::BMCLAPIMirror, SourceType::Official],
     "auto" => match launcher_config.basic_info.is_china_mainland_ip {
@@ -22,7 +81,44 @@ pub fn get_source_priority_list(launcher_config: &LauncherConfig) -> Vec<SourceT
       false => vec![SourceType::Official, SourceType::BMCLAPIMirror],
     },
     _ => vec![SourceType::BMCLAPIMirror, SourceType::Official],
+  });
+  if launcher_config.download.source.strategy == "auto" {
+    rank_sources(&mut priority_list, resource_type);
   }
+  priority_list
+}
+
+// the first source in the priority list serving the resource type, custom sources may not.
+pub fn get_first_source_for(
+  priority_list: &[SourceType],
+  resource_type: ResourceType,
+) -> SourceType {
+  priority_list
+    .iter()
+    .copied()
+    .find(|source_type| get_download_api(*source_type, resource_type).is_ok())
+    .unwrap_or(SourceType::Official)
+}
+
+pub fn get_download_api_for(
+  priority_list: &[SourceType],
+  resource_type: ResourceType,
+) -> SJMCLResult<Url> {
+  get_download_api(
+    get_first_source_for(priority_list, resource_type),
+    resource_type,
+  )
+}
+
+fn get_all_source_types() -> Vec<SourceType> {
+  let custom_ids: Vec<u64> = CUSTOM_SOURCES
+    .read()
+    .map(|sources| sources.keys().copied().collect())
+    .unwrap_or_default();
+  [SourceType::Official, SourceType::BMCLAPIMirror]
+    .into_iter()
+    .chain(custom_ids.into_iter().map(SourceType::Custom))
+    .collect()
 }
 
 // https://bmclapidoc.bangbang93.com/
@@ -41,6 +137,7 @@ pub fn get_download_api(source: SourceType, resource_type: ResourceType) -> SJMC
       ResourceType::ForgeInstall => Ok(Url::parse("https://maven.minecraftforge.net/net/minecraftforge/forge/")?),
       ResourceType::ForgeMeta => Err(ResourceError::NoDownloadApi.into()), // https://github.com/HMCL-dev/HMCL/pull/3259/files
       ResourceType::Liteloader => Ok(Url::parse("https://dl.liteloader.com/versions/versions.json")?),
+      ResourceType::LiteloaderMaven => Ok(Url::parse("https://dl.liteloader.com/versions/")?),
       ResourceType::OptiFine => Err(ResourceError::NoDownloadApi.into()), // 
       ResourceType::AuthlibInjector => Ok(Url::parse("https://authlib-injector.yushi.moe/")?),
       ResourceType::FabricMeta => Ok(Url::parse("https://meta.fabricmc.net/")?),
@@ -51,6 +148,7 @@ pub fn get_download_api(source: SourceType, resource_type: ResourceType) -> SJMC
       ResourceType::NeoforgeMaven | ResourceType::NeoforgeInstall => Ok(Url::parse("https://maven.neoforged.net/releases/")?),
       ResourceType::QuiltMaven => Ok(Url::parse("https://maven.quiltmc.org/repository/release/")?),
       ResourceType::QuiltMeta => Ok(Url::parse("https://meta.quiltmc.org/")?),
+      ResourceType::CleanroomMaven => Ok(Url::parse("https://repo.cleanroommc.com/releases/")?),
     },
     SourceType::BMCLAPIMirror => match resource_type {
       ResourceType::VersionManifest => Ok(Url::parse("https://bmclapi2.bangbang93.com/mc/game/version_manifest.json")?),
@@ -64,6 +162,7 @@ pub fn get_download_api(source: SourceType, resource_type: ResourceType) -> SJMC
       ResourceType::ForgeInstall => Ok(Url::parse("https://bmclapi2.bangbang93.com/forge/download/")?),
       ResourceType::ForgeMeta => Ok(Url::parse("https://bmclapi2.bangbang93.com/forge/")?),
       ResourceType::Liteloader => Ok(Url::parse("https://bmclapi.bangbang93.com/maven/com/mumfrey/liteloader/versions.json")?),
+      ResourceType::LiteloaderMaven => Ok(Url::parse("https://bmclapi2.bangbang93.com/maven/")?),
       ResourceType::AuthlibInjector => Ok(Url::parse("https://bmclapi2.bangbang93.com/mirrors/authlib-injector/")?),
       ResourceType::FabricMeta => Ok(Url::parse("https://bmclapi2.bangbang93.com/fabric-meta/")?),
       ResourceType::FabricMaven => Ok(Url::parse("https://bmclapi2.bangbang93.com/maven/")?),
@@ -72,7 +171,20 @@ pub fn get_download_api(source: SourceType, resource_type: ResourceType) -> SJMC
       ResourceType::OptiFine => Ok(Url::parse("https://bmclapi2.bangbang93.com/optifine/")?),
       ResourceType::QuiltMaven => Ok(Url::parse("https://bmclapi2.bangbang93.com/maven/")?),
       ResourceType::QuiltMeta => Ok(Url::parse("https://bmclapi2.bangbang93.com/quilt-meta/")?),
+      ResourceType::CleanroomMaven => Ok(Url::parse("https://repo.cleanroommc.com/releases/")?), // no mirror yet
     },
+    SourceType::Custom(id) => {
+      let base_url = CUSTOM_SOURCES.read().ok().and_then(|sources| {
+        sources
+          .get(&id)
+          .and_then(|source| source.base_urls.get(&resource_type).cloned())
+      });
+      // a custom source may only serve some resource types, e.g. the libraries
+      match base_url {
+        Some(url) if !url.trim().is_empty() => Ok(Url::parse(url.trim())?),
+        _ => Err(ResourceError::NoDownloadApi.into()),
+      }
+    }
   }
 }
 
@@ -96,6 +208,49 @@ pub fn convert_url_source_type(
   }
 }
 
+// the resource type and the source whose api serves the url. if several match, the longest api
+// wins, then the first resource type, e.g. the maven of a mirror shared by several loaders.
+fn match_url_api(url: &Url) -> Option<(ResourceType, SourceType)> {
+  let source_types = get_all_source_types();
+  let mut matched: Option<(ResourceType, SourceType, usize)> = None;
+  for resource_type in ResourceType::iter() {
+    for source_type in &source_types {
+      let Ok(api) = get_download_api(*source_type, resource_type) else {
+        continue;
+      };
+      let len = api.as_str().len();
+      if url.as_str().starts_with(api.as_str()) && matched.is_none_or(|(_, _, cur)| len > cur) {
+        matched = Some((resource_type, *source_type, len));
+      }
+    }
+  }
+  matched.map(|(resource_type, source_type, _)| (resource_type, source_type))
+}
+
+pub fn get_url_resource_type(url: &Url) -> Option<ResourceType> {
+  match_url_api(url).map(|(resource_type, _)| resource_type)
+}
+
+// the same file on the other sources in priority order, for failing over inside a download task.
+// only the api of the resource type serving the url is converted, sources without it are skipped.
+pub fn get_alternative_urls(url: &Url, priority_list: &[SourceType]) -> Vec<Url> {
+  let Some((resource_type, src_type)) = match_url_api(url) else {
+    return Vec::new();
+  };
+  let mut alternatives: Vec<Url> = Vec::new();
+  for dst_type in priority_list
+    .iter()
+    .filter(|dst_type| **dst_type != src_type)
+  {
+    if let Ok(alternative) = convert_url_source_type(url, &resource_type, &src_type, dst_type) {
+      if &alternative != url && !alternatives.contains(&alternative) {
+        alternatives.push(alternative);
+      }
+    }
+  }
+  alternatives
+}
+
 pub fn convert_url_to_target_source(
   url: &Url,
   resource_types: &[ResourceType],
@@ -109,12 +264,12 @@ pub fn convert_url_to_target_source(
   };
 
   for resource_type in resource_candidates {
-    let dst_api = match get_download_api(*dst_type, resource_type) {
-      Ok(api) => api,
-      Err(_) => return Ok(url.clone()), // If destination API is not available, return the original URL
+    // the destination may not serve this resource type, e.g. a custom source, try the next one
+    let Ok(dst_api) = get_download_api(*dst_type, resource_type) else {
+      continue;
     };
 
-    for src_type in SourceType::iter() {
+    for src_type in get_all_source_types() {
       if &src_type == dst_type {
         continue;
       }
@@ -211,12 +366,13 @@ pub async fn apply_other_resource_enhancements(
   }
 
   // Get translated description
-  let translated_desc = match resource_info.source {
-    OtherResourceSource::Modrinth => translate_description_modrinth(app, &resource_info.id).await?,
-    OtherResourceSource::CurseForge => {
-      translate_description_curseforge(app, &resource_info.id).await?
+  let translated_desc = match get_resource_provider(&resource_info.source) {
+    Ok(provider) => {
+      provider
+        .translate_description(app, &resource_info.id)
+        .await?
     }
-    _ => None,
+    Err(_) => None,
   };
 
   if let Some(desc) = translated_desc {
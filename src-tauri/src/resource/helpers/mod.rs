@@ -1,6 +1,12 @@
 pub mod curseforge;
+pub mod dependency;
+pub mod http_cache;
 pub mod loader_meta;
+pub mod merged_search;
 pub mod misc;
 pub mod mod_db;
+pub mod mod_update;
 pub mod modrinth;
+pub mod provider;
+pub mod source_ranking;
 pub mod version_manifest;
@@ -1,9 +1,35 @@
 use crate::error::SJMCLResult;
+use crate::launcher_config::models::LauncherConfig;
+use crate::resource::constants::{
+  BUNDLED_MOD_DB_META_PATH, BUNDLED_MOD_DB_PATH, MOD_DB_FILE_NAME, MOD_DB_META_FILE_NAME,
+};
 use crate::resource::models::{OtherResourceSource, ResourceError};
-use crate::utils::fs::get_app_resource_filepath;
+use crate::tasks::commands::schedule_progressive_task_group;
+use crate::tasks::download::DownloadParam;
+use crate::tasks::monitor::TaskMonitor;
+use crate::tasks::PTaskParam;
+use crate::utils::fs::{calculate_sha1, get_app_resource_filepath};
+use crate::APP_DATA_DIR;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
 use std::sync::Mutex;
 use tauri::{AppHandle, Manager};
+use tauri_plugin_http::reqwest;
+
+// (version meta endpoint, database file url)
+const SOURCES: [(&str, &str); 2] = [
+  (
+    "https://mc.sjtu.cn/api-sjmcl/mod-data/latest",
+    "https://mc.sjtu.cn/sjmcl/mod-data/mod_data.csv",
+  ),
+  (
+    "https://raw.githubusercontent.com/UNIkeEN/SJMCL/main/src-tauri/assets/db/mod_data_meta.json",
+    "https://raw.githubusercontent.com/UNIkeEN/SJMCL/main/src-tauri/assets/db/mod_data.csv",
+  ),
+];
 
 fn clean_keyword(word: &str) -> Option<String> {
   const STOP_WORDS: &[&str] = &["a", "of", "the", "for", "mod", "with", "and", "ftb"];
@@ -164,9 +190,18 @@ impl MCModRecord {
   }
 }
 
+// version of a mod database file, published next to it on remote
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+struct ModDataBaseMeta {
+  version: u64,
+  sha1: String,
+}
+
 #[derive(Debug)]
 pub struct ModDataBase {
   initialized: bool,
+  version: u64,
   mods: Vec<MCModRecord>,
   modrinth_to_mod: HashMap<String, u32>,
   curseforge_to_mod: HashMap<String, u32>,
@@ -176,6 +211,7 @@ impl ModDataBase {
   pub fn new() -> Self {
     Self {
       initialized: false,
+      version: 0,
       mods: Vec::new(),
       modrinth_to_mod: HashMap::new(),
       curseforge_to_mod: HashMap::new(),
@@ -298,45 +334,45 @@ impl ModDataBase {
   }
 }
 
-pub async fn initialize_mod_db(app: &AppHandle) -> SJMCLResult<()> {
-  let csv_path = get_app_resource_filepath(app, "assets/db/mod_data.csv")
-    .ok()
-    .unwrap_or_default();
-  let content = tokio::fs::read_to_string(&csv_path)
-    .await
-    .unwrap_or_default();
-
-  let state = app.state::<Mutex<ModDataBase>>();
-  let mut cache = state.lock().map_err(|_| ResourceError::ParseError)?;
-
-  if content.is_empty() {
-    cache.initialized = true;
-    return Ok(());
-  }
+// lookups by mcmod id rely on the records being sorted by id without gaps
+fn parse_mod_db(content: &str, version: u64) -> SJMCLResult<ModDataBase> {
+  let mut db = ModDataBase::new();
+  db.version = version;
 
   let mut reader = csv::Reader::from_reader(content.as_bytes());
   let headers = reader
     .headers()
     .map_err(|_| ResourceError::ParseError)?
     .clone();
+  let get_index = |name: &str| {
+    headers
+      .iter()
+      .position(|h| h == name)
+      .ok_or(ResourceError::ParseError)
+  };
 
-  let mcmod_id_index = headers.iter().position(|h| h == "mcmod_id").unwrap();
-  let curseforge_slug_index = headers.iter().position(|h| h == "curseforge_slug").unwrap();
-  let modrinth_slug_index = headers.iter().position(|h| h == "modrinth_slug").unwrap();
-  let name_index = headers.iter().position(|h| h == "name").unwrap();
-  let subname_index = headers.iter().position(|h| h == "subname").unwrap();
-  let abbr_index = headers.iter().position(|h| h == "abbr").unwrap();
+  let mcmod_id_index = get_index("mcmod_id")?;
+  let curseforge_slug_index = get_index("curseforge_slug")?;
+  let modrinth_slug_index = get_index("modrinth_slug")?;
+  let name_index = get_index("name")?;
+  let subname_index = get_index("subname")?;
+  let abbr_index = get_index("abbr")?;
 
   for record in reader.records() {
     let record = record.map_err(|_| ResourceError::ParseError)?;
 
     let mcmod_id = record
       .get(mcmod_id_index)
-      .unwrap()
-      .parse::<u32>()
-      .ok()
-      .unwrap();
-    let name = record.get(name_index).unwrap().trim().to_string();
+      .and_then(|id| id.parse::<u32>().ok())
+      .ok_or(ResourceError::ParseError)?;
+    if mcmod_id as usize != db.mods.len() + 1 {
+      return Err(ResourceError::ParseError.into());
+    }
+    let name = record
+      .get(name_index)
+      .unwrap_or_default()
+      .trim()
+      .to_string();
 
     let curseforge_slug = record.get(curseforge_slug_index);
     let modrinth_slug = record.get(modrinth_slug_index);
@@ -352,24 +388,164 @@ pub async fn initialize_mod_db(app: &AppHandle) -> SJMCLResult<()> {
       abbr: abbr.map(str::to_owned),
     };
 
-    cache.mods.push(mod_record);
+    db.mods.push(mod_record);
 
     if let Some(curseforge_slug) = curseforge_slug {
-      cache
-        .curseforge_to_mod
+      db.curseforge_to_mod
         .insert(curseforge_slug.to_string(), mcmod_id);
     }
     if let Some(modrinth_slug) = modrinth_slug {
-      cache
-        .modrinth_to_mod
+      db.modrinth_to_mod
         .insert(modrinth_slug.to_string(), mcmod_id);
     }
   }
 
-  cache.initialized = true;
+  db.initialized = true;
+  Ok(db)
+}
+
+fn read_mod_db_meta(path: &Path) -> Option<ModDataBaseMeta> {
+  let content = fs::read_to_string(path).ok()?;
+  serde_json::from_str(&content).ok()
+}
+
+fn get_downloaded_mod_db_paths() -> Option<(PathBuf, PathBuf)> {
+  APP_DATA_DIR
+    .get()
+    .map(|dir| (dir.join(MOD_DB_FILE_NAME), dir.join(MOD_DB_META_FILE_NAME)))
+}
+
+// the downloaded database, if it is newer than the bundled one and not corrupted
+fn load_downloaded_mod_db(bundled_version: u64) -> Option<ModDataBase> {
+  let (csv_path, meta_path) = get_downloaded_mod_db_paths()?;
+  let meta = read_mod_db_meta(&meta_path)?;
+  if meta.version <= bundled_version || calculate_sha1(&csv_path).ok()? != meta.sha1 {
+    return None;
+  }
+  let content = fs::read_to_string(&csv_path).ok()?;
+  parse_mod_db(&content, meta.version).ok()
+}
+
+fn load_mod_db(app: &AppHandle) -> ModDataBase {
+  let version = get_app_resource_filepath(app, BUNDLED_MOD_DB_META_PATH)
+    .ok()
+    .and_then(|path| read_mod_db_meta(&path))
+    .map(|meta| meta.version)
+    .unwrap_or_default();
+  if let Some(db) = load_downloaded_mod_db(version) {
+    return db;
+  }
+
+  let content = get_app_resource_filepath(app, BUNDLED_MOD_DB_PATH)
+    .ok()
+    .and_then(|path| fs::read_to_string(path).ok())
+    .unwrap_or_default();
+  parse_mod_db(&content, version).unwrap_or_else(|_| {
+    // keep the database usable (and empty) rather than blocking the lookups
+    let mut db = ModDataBase::new();
+    db.initialized = true;
+    db
+  })
+}
+
+pub async fn initialize_mod_db(app: &AppHandle) -> SJMCLResult<()> {
+  let app_handle = app.clone();
+  let db = tokio::task::spawn_blocking(move || load_mod_db(&app_handle)).await?;
+
+  let state = app.state::<Mutex<ModDataBase>>();
+  *state.lock()? = db;
   Ok(())
 }
 
+async fn fetch_latest_mod_db_meta(app: &AppHandle) -> SJMCLResult<(ModDataBaseMeta, String)> {
+  let is_china_mainland_ip = {
+    let config_binding = app.state::<Mutex<LauncherConfig>>();
+    let config_state = config_binding.lock()?;
+    config_state.basic_info.is_china_mainland_ip
+  };
+  let client = app.state::<reqwest::Client>();
+
+  let mut sources = SOURCES;
+  // If not in China (mainland), firstly try GitHub.
+  if !is_china_mainland_ip {
+    sources.reverse();
+  }
+
+  for (endpoint, url) in sources {
+    if let Ok(resp) = client.get(endpoint).send().await {
+      if let Ok(meta) = resp.json::<ModDataBaseMeta>().await {
+        if meta.version > 0 && !meta.sha1.is_empty() {
+          return Ok((meta, url.to_string()));
+        }
+      }
+    }
+  }
+
+  Err(ResourceError::NetworkError.into())
+}
+
+/// Checks the remote mod database and replaces the one in use if a newer version is published.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri AppHandle.
+///
+/// # Returns
+///
+/// `true` if the database was updated. The file is downloaded by a task group so the progress
+/// shows up in the task list, and is only swapped in after its sha1 and content are verified.
+pub async fn update_mod_db(app: &AppHandle) -> SJMCLResult<bool> {
+  let current_version = app.state::<Mutex<ModDataBase>>().lock()?.version;
+  let (meta, url) = fetch_latest_mod_db_meta(app).await?;
+  if meta.version <= current_version {
+    return Ok(false);
+  }
+
+  let (csv_path, meta_path) =
+    get_downloaded_mod_db_paths().ok_or(ResourceError::FileOperationError)?;
+  let download_path = csv_path.with_extension("csv.download");
+  let task_group = schedule_progressive_task_group(
+    app.clone(),
+    "mod-database-update".to_string(),
+    vec![PTaskParam::Download(DownloadParam {
+      src: url::Url::parse(&url).map_err(|_| ResourceError::ParseError)?,
+      dest: download_path.clone(),
+      filename: Some(MOD_DB_FILE_NAME.to_string()),
+      sha1: Some(meta.sha1.clone()),
+      alt_srcs: vec![],
+      served_src: None,
+    })],
+    true,
+  )
+  .await?
+  .task_group;
+  let monitor = app.state::<Pin<Box<TaskMonitor>>>();
+  if !monitor.wait_for_group(&task_group).await {
+    return Err(ResourceError::NetworkError.into());
+  }
+
+  // a failed download task does not fail its group, so the file is checked again here
+  let db: SJMCLResult<ModDataBase> = match calculate_sha1(&download_path) {
+    Ok(sha1) if sha1 == meta.sha1 => fs::read_to_string(&download_path)
+      .map_err(|_| ResourceError::FileOperationError.into())
+      .and_then(|content| parse_mod_db(&content, meta.version)),
+    _ => Err(ResourceError::FileOperationError.into()),
+  };
+  let db = db.inspect_err(|_| {
+    let _ = fs::remove_file(&download_path);
+  })?;
+
+  // the meta is written after the file, a crash in between leaves a sha1 mismatch,
+  // and the bundled copy is used on the next start.
+  fs::rename(&download_path, &csv_path).map_err(|_| ResourceError::FileOperationError)?;
+  fs::write(&meta_path, serde_json::to_string(&meta)?)
+    .map_err(|_| ResourceError::FileOperationError)?;
+
+  let state = app.state::<Mutex<ModDataBase>>();
+  *state.lock()? = db;
+  Ok(true)
+}
+
 pub async fn handle_search_query(app: &AppHandle, query: &str) -> SJMCLResult<String> {
   // Only process Chinese queries
   if !query.chars().any(|c| matches!(c, '\u{4e00}'..='\u{9fbb}')) {
@@ -0,0 +1,314 @@
+use crate::error::SJMCLResult;
+use crate::instance::constants::{MOD_UPDATE_BACKUP_DIR_NAME, MOD_UPDATE_HISTORY_LIMIT};
+use crate::instance::helpers::mods::common::get_cached_sha1;
+use crate::instance::models::misc::{LocalModInfo, ModLoaderType};
+use crate::resource::helpers::curseforge::misc::{
+  calculate_curseforge_fingerprint, cvt_id_to_release_type,
+};
+use crate::resource::helpers::curseforge::{
+  fetch_file_changelog_curseforge, fetch_latest_files_by_fingerprints_curseforge,
+};
+use crate::resource::helpers::modrinth::fetch_latest_versions_by_hashes_modrinth;
+use crate::resource::helpers::provider::get_resource_provider;
+use crate::resource::models::{
+  ModUpdateHistoryEntry, ModUpdateInfo, ModUpdateQuery, OtherResourceFileInfo, OtherResourceSource,
+  ResourceError,
+};
+use crate::utils::fs::calculate_sha1;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+const KNOWN_LOADERS: &[&str] = &["forge", "fabric", "neoforge", "quilt", "liteloader"];
+
+// loader names used by modrinth, and by curseforge in lowercase
+fn get_loader_names(loader_type: &ModLoaderType) -> Vec<String> {
+  let names: &[&str] = match loader_type {
+    ModLoaderType::Fabric => &["fabric"],
+    ModLoaderType::Forge | ModLoaderType::LegacyForge | ModLoaderType::Cleanroom => &["forge"],
+    ModLoaderType::NeoForge => &["neoforge"],
+    // quilt is able to load most fabric mods
+    ModLoaderType::Quilt => &["quilt", "fabric"],
+    ModLoaderType::LiteLoader => &["liteloader"],
+    ModLoaderType::Unknown => &[],
+  };
+  names.iter().map(|name| name.to_string()).collect()
+}
+
+fn is_stable_release(release_type: &str) -> bool {
+  matches!(release_type, "release" | "beta")
+}
+
+// curseforge puts loaders in the game versions, files without any loader tag are accepted.
+fn match_curseforge_loader(game_versions: &[String], loaders: &[String]) -> Option<Option<String>> {
+  let file_loaders: Vec<&String> = game_versions
+    .iter()
+    .filter(|v| KNOWN_LOADERS.contains(&v.to_lowercase().as_str()))
+    .collect();
+  if file_loaders.is_empty() {
+    return Some(None);
+  }
+  file_loaders
+    .into_iter()
+    .find(|v| loaders.contains(&v.to_lowercase()))
+    .map(|v| Some(v.clone()))
+}
+
+pub async fn check_mod_updates_by_hashes(
+  app: &AppHandle,
+  mod_infos: &[LocalModInfo],
+  game_version: &str,
+  loader_type: &ModLoaderType,
+) -> SJMCLResult<Vec<ModUpdateInfo>> {
+  let loaders = get_loader_names(loader_type);
+  if loaders.is_empty() {
+    return Ok(Vec::new());
+  }
+
+  // hashing every jar takes a while on large mod folders, so it is kept off the async runtime
+  let app_clone = app.clone();
+  let file_paths: Vec<(usize, PathBuf)> = mod_infos
+    .iter()
+    .enumerate()
+    .filter(|(_, info)| info.file_path.is_file())
+    .map(|(index, info)| (index, info.file_path.clone()))
+    .collect();
+  let file_hashes = tauri::async_runtime::spawn_blocking(move || {
+    file_paths
+      .into_iter()
+      .filter_map(|(index, path)| {
+        let sha1 = get_cached_sha1(&app_clone, &path).or_else(|| calculate_sha1(&path).ok())?;
+        let file_content = fs::read(&path).ok()?;
+        Some((index, sha1, calculate_curseforge_fingerprint(&file_content)))
+      })
+      .collect::<Vec<_>>()
+  })
+  .await?;
+  // (mod info, sha1, curseforge fingerprint)
+  let mod_hashes: Vec<(&LocalModInfo, String, u64)> = file_hashes
+    .into_iter()
+    .map(|(index, sha1, fingerprint)| (&mod_infos[index], sha1, fingerprint))
+    .collect();
+  let hashes: Vec<String> = mod_hashes.iter().map(|(_, sha1, _)| sha1.clone()).collect();
+  let fingerprints: Vec<u64> = mod_hashes.iter().map(|(_, _, fp)| *fp).collect();
+
+  let game_versions = vec![game_version.to_string()];
+  let modrinth = get_resource_provider(&OtherResourceSource::Modrinth)?;
+  let curseforge = get_resource_provider(&OtherResourceSource::CurseForge)?;
+  let (mr_res, cf_res) = tokio::join!(
+    fetch_latest_versions_by_hashes_modrinth(
+      app,
+      modrinth.api_base(),
+      &hashes,
+      &loaders,
+      &game_versions,
+    ),
+    fetch_latest_files_by_fingerprints_curseforge(app, curseforge.api_base(), &fingerprints),
+  );
+  if mr_res.is_err() && cf_res.is_err() {
+    return Err(ResourceError::NetworkError.into());
+  }
+  let mr_versions = mr_res.unwrap_or_else(|e| {
+    log::error!("Failed to check mod updates from Modrinth: {}", e.0);
+    Default::default()
+  });
+  let cf_files = cf_res.unwrap_or_else(|e| {
+    log::error!("Failed to check mod updates from CurseForge: {}", e.0);
+    Default::default()
+  });
+
+  // (update info, curseforge (mod id, file id) to fetch changelog)
+  let mut updates = Vec::new();
+  for (info, sha1, fingerprint) in mod_hashes {
+    let mr_update = mr_versions
+      .get(&sha1)
+      .filter(|version| is_stable_release(&version.version_type))
+      .filter(|version| !version.files.iter().any(|file| file.hashes.sha1 == sha1))
+      .and_then(|version| {
+        let file = version
+          .files
+          .iter()
+          .find(|file| file.primary)
+          .or(version.files.first())?;
+        let loader = version
+          .loaders
+          .iter()
+          .find(|l| loaders.contains(l))
+          .cloned();
+        let file_info: OtherResourceFileInfo = (version, file, loader).into();
+        Some((file_info, version.changelog.clone().unwrap_or_default()))
+      });
+
+    let cf_update = cf_files
+      .get(&fingerprint)
+      .and_then(|(matched_file, latest_files)| {
+        latest_files
+          .iter()
+          .filter(|file| file.id != matched_file.id && file.file_date > matched_file.file_date)
+          .filter(|file| is_stable_release(&cvt_id_to_release_type(file.release_type)))
+          .filter(|file| file.game_versions.iter().any(|v| v == game_version))
+          .filter_map(|file| {
+            match_curseforge_loader(&file.game_versions, &loaders).map(|loader| (file, loader))
+          })
+          .max_by(|(a, _), (b, _)| a.file_date.cmp(&b.file_date))
+      })
+      .map(|(file, loader)| {
+        let file_info: OtherResourceFileInfo = (file, loader).into();
+        (file_info, (file.mod_id, file.id))
+      });
+
+    let (source, file, changelog, cf_ids) = match (mr_update, cf_update) {
+      (Some((mr_file, _)), Some((cf_file, cf_ids))) if cf_file.file_date > mr_file.file_date => (
+        OtherResourceSource::CurseForge,
+        cf_file,
+        String::new(),
+        Some(cf_ids),
+      ),
+      (None, Some((cf_file, cf_ids))) => (
+        OtherResourceSource::CurseForge,
+        cf_file,
+        String::new(),
+        Some(cf_ids),
+      ),
+      (Some((mr_file, changelog)), _) => (OtherResourceSource::Modrinth, mr_file, changelog, None),
+      (None, None) => continue,
+    };
+
+    updates.push((
+      ModUpdateInfo {
+        name: info.name.clone(),
+        cur_version: info.version.clone(),
+        old_file_path: info.file_path.to_string_lossy().to_string(),
+        source,
+        file,
+        changelog,
+      },
+      cf_ids,
+    ));
+  }
+
+  let curseforge_api = curseforge.api_base();
+  let tasks = updates.into_iter().map(|(mut update, cf_ids)| async move {
+    if let Some((mod_id, file_id)) = cf_ids {
+      update.changelog = fetch_file_changelog_curseforge(app, curseforge_api, mod_id, file_id)
+        .await
+        .unwrap_or_default();
+    }
+    update
+  });
+  Ok(futures::future::join_all(tasks).await)
+}
+
+pub fn get_mod_update_backup_dir(version_path: &Path, entry_id: &str) -> PathBuf {
+  version_path.join(MOD_UPDATE_BACKUP_DIR_NAME).join(entry_id)
+}
+
+// copy the files to be replaced into the backup dir, the originals stay in place until the
+// downloads of the update have finished, see `finish_mod_update`.
+pub fn backup_mod_files(backup_dir: &Path, old_file_paths: &[PathBuf]) -> SJMCLResult<()> {
+  fs::create_dir_all(backup_dir).map_err(|_| ResourceError::FileOperationError)?;
+  for path in old_file_paths {
+    let backup_path = backup_dir.join(path.file_name().unwrap_or_default());
+    if let Err(e) = fs::copy(path, &backup_path) {
+      log::error!("Failed to back up old mod file {}: {}", path.display(), e);
+      let _ = fs::remove_dir_all(backup_dir);
+      return Err(ResourceError::FileOperationError.into());
+    }
+  }
+  Ok(())
+}
+
+// called once the download group of an update has ended. the old files of the mods downloaded
+// and verified are removed. the other mods keep (or get back) their old files and are dropped
+// from the entry, so the history only records the replacements that really happened.
+pub fn finish_mod_update(
+  mods_dir: &Path,
+  backup_dir: &Path,
+  entry: &mut ModUpdateHistoryEntry,
+  queries: &[ModUpdateQuery],
+) {
+  for query in queries {
+    let old_path = PathBuf::from(&query.old_file_path);
+    let new_path = mods_dir.join(&query.file_name);
+    let old_file_name = old_path
+      .file_name()
+      .unwrap_or_default()
+      .to_string_lossy()
+      .to_string();
+    let backup_path = backup_dir.join(&old_file_name);
+
+    let downloaded = calculate_sha1(&new_path)
+      .map(|sha1| sha1.eq_ignore_ascii_case(&query.sha1))
+      .unwrap_or(false);
+    if downloaded {
+      if old_path != new_path && old_path.is_file() {
+        if let Err(e) = fs::remove_file(&old_path) {
+          log::error!(
+            "Failed to remove old mod file {}: {}",
+            old_path.display(),
+            e
+          );
+        }
+      }
+      continue;
+    }
+
+    if old_path == new_path {
+      // the old file was being overwritten by the failed download
+      if backup_path.is_file() {
+        let _ = fs::copy(&backup_path, &old_path);
+      }
+    } else if new_path.is_file() {
+      let _ = fs::remove_file(&new_path);
+    }
+    let _ = fs::remove_file(&backup_path);
+    entry
+      .items
+      .retain(|item| item.old_file_name != old_file_name || item.new_file_name != query.file_name);
+  }
+
+  if entry.items.is_empty() {
+    let _ = fs::remove_dir_all(backup_dir);
+  }
+}
+
+// remove the files downloaded by the entry and move the replaced files back.
+pub fn restore_mod_files(
+  mods_dir: &Path,
+  backup_dir: &Path,
+  entry: &ModUpdateHistoryEntry,
+) -> SJMCLResult<()> {
+  if entry
+    .items
+    .iter()
+    .any(|item| !backup_dir.join(&item.old_file_name).is_file())
+  {
+    return Err(ResourceError::FileOperationError.into());
+  }
+  for item in &entry.items {
+    // the new file may have been disabled by the user after updating
+    for new_path in [
+      mods_dir.join(&item.new_file_name),
+      mods_dir.join(format!("{}.disabled", item.new_file_name)),
+    ] {
+      if new_path.is_file() {
+        fs::remove_file(&new_path).map_err(|_| ResourceError::FileOperationError)?;
+      }
+    }
+    fs::rename(
+      backup_dir.join(&item.old_file_name),
+      mods_dir.join(&item.old_file_name),
+    )
+    .map_err(|_| ResourceError::FileOperationError)?;
+  }
+  let _ = fs::remove_dir_all(backup_dir);
+  Ok(())
+}
+
+// keep the latest entries only, with the backups of dropped entries removed.
+pub fn prune_mod_update_history(version_path: &Path, history: &mut Vec<ModUpdateHistoryEntry>) {
+  while history.len() > MOD_UPDATE_HISTORY_LIMIT {
+    let entry = history.remove(0);
+    let _ = fs::remove_dir_all(get_mod_update_backup_dir(version_path, &entry.id));
+  }
+}
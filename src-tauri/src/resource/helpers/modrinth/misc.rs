@@ -1,4 +1,6 @@
 use crate::error::{SJMCLError, SJMCLResult};
+use crate::resource::constants::MODRINTH_API_BASE;
+use crate::resource::helpers::http_cache::send_with_cache;
 use crate::resource::helpers::misc::version_pack_sort;
 use crate::resource::models::{
   OtherResourceApiEndpoint, OtherResourceDependency, OtherResourceFileInfo, OtherResourceInfo,
@@ -10,40 +12,33 @@ use tauri::{AppHandle, Manager};
 use tauri_plugin_http::reqwest;
 
 pub async fn make_modrinth_request<T, P>(
-  client: &reqwest::Client,
+  app: &AppHandle,
   url: &str,
   request_type: OtherResourceRequestType<'_, P>,
+  cache_ttl: u64,
 ) -> SJMCLResult<T>
 where
   T: serde::de::DeserializeOwned,
   P: serde::Serialize,
 {
+  let client = app.state::<reqwest::Client>();
   let request_builder = match request_type {
     OtherResourceRequestType::GetWithParams(params) => client.get(url).query(params),
     OtherResourceRequestType::Get => client.get(url),
     OtherResourceRequestType::Post(payload) => client.post(url).json(payload),
   };
 
-  let response = request_builder
-    .send()
-    .await
-    .map_err(|_| ResourceError::NetworkError)?;
-
-  if !response.status().is_success() {
-    return Err(ResourceError::NetworkError.into());
-  }
+  let body = send_with_cache(app, request_builder, cache_ttl).await?;
 
-  response
-    .json::<T>()
-    .await
-    .map_err(|_| ResourceError::ParseError.into())
+  serde_json::from_str::<T>(&body).map_err(|_| ResourceError::ParseError.into())
 }
 
 pub fn get_modrinth_api(
+  api_base: &str,
   endpoint: OtherResourceApiEndpoint,
   param: Option<&str>,
 ) -> SJMCLResult<String> {
-  let base_url = "https://api.modrinth.com/v2";
+  let base_url = api_base.trim_end_matches('/');
 
   let url_str = match endpoint {
     OtherResourceApiEndpoint::Search => format!("{}/search", base_url),
@@ -55,6 +50,7 @@ pub fn get_modrinth_api(
       let hash = param.ok_or(ResourceError::ParseError)?;
       format!("{}/version_file/{}", base_url, hash)
     }
+    OtherResourceApiEndpoint::BulkFromLocal => format!("{}/version_files", base_url),
     OtherResourceApiEndpoint::ById => {
       let project_id = param.ok_or(ResourceError::ParseError)?;
       format!("{}/project/{}", base_url, project_id)
@@ -66,6 +62,11 @@ pub fn get_modrinth_api(
         project_id
       )
     }
+    OtherResourceApiEndpoint::UpdateFromLocal => format!("{}/version_files/update", base_url),
+    OtherResourceApiEndpoint::Changelog => {
+      let version_id = param.ok_or(ResourceError::ParseError)?;
+      format!("{}/version/{}", base_url, version_id)
+    }
   };
 
   Ok(url_str)
@@ -100,6 +101,8 @@ structstruck::strike! {
   pub struct ModrinthFileInfo {
     pub url: String,
     pub filename: String,
+    #[serde(default)]
+    pub primary: bool,
     pub hashes: pub struct {
       pub sha1: String,
     },
@@ -121,6 +124,8 @@ structstruck::strike! {
     pub downloads: u64,
     pub version_type: String,
     pub files: Vec<ModrinthFileInfo>,
+    #[serde(default)]
+    pub changelog: Option<String>,
   }
 }
 
@@ -137,6 +142,7 @@ fn normalize_modrinth_loader(loader: &str) -> Option<String> {
       "forge" => Some("Forge".to_string()),
       "fabric" => Some("Fabric".to_string()),
       "neoforge" => Some("NeoForge".to_string()),
+      "quilt" => Some("Quilt".to_string()),
       "vanilla" => Some("Vanilla".to_string()),
       "iris" => Some("Iris".to_string()),
       "canvas" => Some("Canvas".to_string()),
@@ -162,6 +168,7 @@ pub fn map_modrinth_file_to_version_pack(
       "forge",
       "fabric",
       "neoforge",
+      "quilt",
       "vanilla",
       "iris",
       "canvas",
@@ -223,6 +230,8 @@ impl From<ModrinthProject> for OtherResourceInfo {
       source: OtherResourceSource::Modrinth,
       translated_name: None,
       translated_description: None,
+      linked_sources: vec![],
+      latest_file_hashes: vec![],
     }
   }
 }
@@ -271,7 +280,11 @@ pub async fn translate_description_modrinth(
   resource_id: &str,
 ) -> SJMCLResult<Option<String>> {
   let result = async {
-    let url = get_modrinth_api(OtherResourceApiEndpoint::TranslateDesc, Some(resource_id))?;
+    let url = get_modrinth_api(
+      MODRINTH_API_BASE,
+      OtherResourceApiEndpoint::TranslateDesc,
+      Some(resource_id),
+    )?;
     let client = app.state::<reqwest::Client>();
 
     let translation_res = client
@@ -1,34 +1,40 @@
 pub mod misc;
 
 use crate::error::SJMCLResult;
+use crate::resource::constants::{
+  MODRINTH_API_BASE, RESOURCE_CACHE_TTL, SEARCH_CACHE_TTL, UPDATE_CACHE_TTL,
+};
 use crate::resource::helpers::misc::apply_other_resource_enhancements;
 use crate::resource::helpers::mod_db::handle_search_query;
+use crate::resource::helpers::provider::ResourceProvider;
 use crate::resource::models::{
   OtherResourceApiEndpoint, OtherResourceFileInfo, OtherResourceInfo, OtherResourceRequestType,
-  OtherResourceSearchQuery, OtherResourceSearchRes, OtherResourceVersionPack,
+  OtherResourceSearchQuery, OtherResourceSearchRes, OtherResourceSource, OtherResourceVersionPack,
   OtherResourceVersionPackQuery, ResourceError,
 };
 use crate::tasks::download::DownloadParam;
+use async_trait::async_trait;
 use hex;
 use misc::{
-  get_modrinth_api, make_modrinth_request, map_modrinth_file_to_version_pack, ModrinthProject,
-  ModrinthSearchRes, ModrinthVersionPack,
+  get_modrinth_api, make_modrinth_request, map_modrinth_file_to_version_pack,
+  translate_description_modrinth, ModrinthProject, ModrinthSearchRes, ModrinthVersionPack,
 };
+use serde_json::json;
 use sha1::{Digest, Sha1};
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
-use tauri::{AppHandle, Manager};
-use tauri_plugin_http::reqwest;
+use tauri::AppHandle;
 use url::Url;
 
 const ALL_FILTER: &str = "All";
 
 pub async fn fetch_resource_list_by_name_modrinth(
   app: &AppHandle,
+  api_base: &str,
   query: &OtherResourceSearchQuery,
 ) -> SJMCLResult<OtherResourceSearchRes> {
-  let url = get_modrinth_api(OtherResourceApiEndpoint::Search, None)?;
+  let url = get_modrinth_api(api_base, OtherResourceApiEndpoint::Search, None)?;
 
   let OtherResourceSearchQuery {
     resource_type,
@@ -62,11 +68,11 @@ pub async fn fetch_resource_list_by_name_modrinth(
   params.insert("limit".to_string(), page_size.to_string());
   params.insert("index".to_string(), sort_by.to_string());
 
-  let client = app.state::<reqwest::Client>();
   let results = make_modrinth_request::<ModrinthSearchRes, ()>(
-    &client,
+    app,
     &url,
     OtherResourceRequestType::GetWithParams(&params),
+    SEARCH_CACHE_TTL,
   )
   .await?;
 
@@ -80,6 +86,7 @@ pub async fn fetch_resource_list_by_name_modrinth(
 
 pub async fn fetch_resource_version_packs_modrinth(
   app: &AppHandle,
+  api_base: &str,
   query: &OtherResourceVersionPackQuery,
 ) -> SJMCLResult<Vec<OtherResourceVersionPack>> {
   let OtherResourceVersionPackQuery {
@@ -88,7 +95,11 @@ pub async fn fetch_resource_version_packs_modrinth(
     game_versions,
   } = query;
 
-  let url = get_modrinth_api(OtherResourceApiEndpoint::VersionPack, Some(resource_id))?;
+  let url = get_modrinth_api(
+    api_base,
+    OtherResourceApiEndpoint::VersionPack,
+    Some(resource_id),
+  )?;
 
   let mut params = HashMap::new();
   if mod_loader != ALL_FILTER {
@@ -112,12 +123,11 @@ pub async fn fetch_resource_version_packs_modrinth(
     }
   }
 
-  let client = app.state::<reqwest::Client>();
-
   let results = make_modrinth_request::<Vec<ModrinthVersionPack>, ()>(
-    &client,
+    app,
     &url,
     OtherResourceRequestType::GetWithParams(&params),
+    RESOURCE_CACHE_TTL,
   )
   .await?;
 
@@ -126,6 +136,7 @@ pub async fn fetch_resource_version_packs_modrinth(
 
 pub async fn fetch_remote_resource_by_local_modrinth(
   app: &AppHandle,
+  api_base: &str,
   file_path: &str,
 ) -> SJMCLResult<OtherResourceFileInfo> {
   let file_content = fs::read(file_path).map_err(|_| ResourceError::ParseError)?;
@@ -138,13 +149,16 @@ pub async fn fetch_remote_resource_by_local_modrinth(
   let mut params = HashMap::new();
   params.insert("algorithm".to_string(), "sha1".to_string());
 
-  let url = get_modrinth_api(OtherResourceApiEndpoint::FromLocal, Some(&hash_string))?;
-  let client = app.state::<reqwest::Client>();
-
+  let url = get_modrinth_api(
+    api_base,
+    OtherResourceApiEndpoint::FromLocal,
+    Some(&hash_string),
+  )?;
   let version_pack = make_modrinth_request::<ModrinthVersionPack, ()>(
-    &client,
+    app,
     &url,
     OtherResourceRequestType::GetWithParams(&params),
+    RESOURCE_CACHE_TTL,
   )
   .await?;
 
@@ -168,16 +182,70 @@ pub async fn fetch_remote_resource_by_local_modrinth(
   )
 }
 
+// resolve local files by sha1 in one request, returns the latest version matching
+// the loaders and game versions of each file, keyed by sha1.
+pub async fn fetch_latest_versions_by_hashes_modrinth(
+  app: &AppHandle,
+  api_base: &str,
+  hashes: &[String],
+  loaders: &[String],
+  game_versions: &[String],
+) -> SJMCLResult<HashMap<String, ModrinthVersionPack>> {
+  if hashes.is_empty() {
+    return Ok(HashMap::new());
+  }
+  let url = get_modrinth_api(api_base, OtherResourceApiEndpoint::UpdateFromLocal, None)?;
+  let payload = json!({
+    "hashes": hashes,
+    "algorithm": "sha1",
+    "loaders": loaders,
+    "game_versions": game_versions,
+  });
+  make_modrinth_request::<HashMap<String, ModrinthVersionPack>, _>(
+    app,
+    &url,
+    OtherResourceRequestType::Post(&payload),
+    UPDATE_CACHE_TTL,
+  )
+  .await
+}
+
+// look up the versions of files by sha1 in one request, keyed by sha1.
+pub async fn fetch_versions_by_hashes_modrinth(
+  app: &AppHandle,
+  api_base: &str,
+  hashes: &[String],
+) -> SJMCLResult<HashMap<String, ModrinthVersionPack>> {
+  if hashes.is_empty() {
+    return Ok(HashMap::new());
+  }
+  let url = get_modrinth_api(api_base, OtherResourceApiEndpoint::BulkFromLocal, None)?;
+  let payload = json!({
+    "hashes": hashes,
+    "algorithm": "sha1",
+  });
+  make_modrinth_request::<HashMap<String, ModrinthVersionPack>, _>(
+    app,
+    &url,
+    OtherResourceRequestType::Post(&payload),
+    RESOURCE_CACHE_TTL,
+  )
+  .await
+}
+
 pub async fn fetch_remote_resource_by_id_modrinth(
   app: &AppHandle,
+  api_base: &str,
   resource_id: &str,
 ) -> SJMCLResult<OtherResourceInfo> {
-  let url = get_modrinth_api(OtherResourceApiEndpoint::ById, Some(resource_id))?;
-  let client = app.state::<reqwest::Client>();
-
-  let results =
-    make_modrinth_request::<ModrinthProject, ()>(&client, &url, OtherResourceRequestType::Get)
-      .await?;
+  let url = get_modrinth_api(api_base, OtherResourceApiEndpoint::ById, Some(resource_id))?;
+  let results = make_modrinth_request::<ModrinthProject, ()>(
+    app,
+    &url,
+    OtherResourceRequestType::Get,
+    RESOURCE_CACHE_TTL,
+  )
+  .await?;
 
   let mut resource_info: OtherResourceInfo = results.into();
   let _ = apply_other_resource_enhancements(app, &mut resource_info).await;
@@ -185,20 +253,114 @@ pub async fn fetch_remote_resource_by_id_modrinth(
   Ok(resource_info)
 }
 
+// the api base defaults to the official one, see `register_resource_providers`
+pub struct ModrinthProvider {
+  pub api_base: String,
+}
+
+impl Default for ModrinthProvider {
+  fn default() -> Self {
+    ModrinthProvider {
+      api_base: MODRINTH_API_BASE.to_string(),
+    }
+  }
+}
+
+#[async_trait]
+impl ResourceProvider for ModrinthProvider {
+  fn source(&self) -> OtherResourceSource {
+    OtherResourceSource::Modrinth
+  }
+
+  fn api_base(&self) -> &str {
+    &self.api_base
+  }
+
+  async fn search(
+    &self,
+    app: &AppHandle,
+    query: &OtherResourceSearchQuery,
+  ) -> SJMCLResult<OtherResourceSearchRes> {
+    fetch_resource_list_by_name_modrinth(app, &self.api_base, query).await
+  }
+
+  async fn fetch_version_packs(
+    &self,
+    app: &AppHandle,
+    query: &OtherResourceVersionPackQuery,
+  ) -> SJMCLResult<Vec<OtherResourceVersionPack>> {
+    fetch_resource_version_packs_modrinth(app, &self.api_base, query).await
+  }
+
+  async fn fetch_by_local_file(
+    &self,
+    app: &AppHandle,
+    file_path: &str,
+  ) -> SJMCLResult<OtherResourceFileInfo> {
+    fetch_remote_resource_by_local_modrinth(app, &self.api_base, file_path).await
+  }
+
+  async fn fetch_by_id(
+    &self,
+    app: &AppHandle,
+    resource_id: &str,
+  ) -> SJMCLResult<OtherResourceInfo> {
+    fetch_remote_resource_by_id_modrinth(app, &self.api_base, resource_id).await
+  }
+
+  async fn translate_description(
+    &self,
+    app: &AppHandle,
+    resource_id: &str,
+  ) -> SJMCLResult<Option<String>> {
+    // the translations are indexed by the project ids on the official modrinth
+    if self.api_base != MODRINTH_API_BASE {
+      return Ok(None);
+    }
+    translate_description_modrinth(app, resource_id).await
+  }
+}
+
 pub async fn get_latest_fabric_api_mod_download(
   app: &AppHandle,
   game_version: &str,
   mods_dir: PathBuf,
 ) -> SJMCLResult<Option<DownloadParam>> {
   const FABRIC_API_MOD_ID: &str = "P7dR8mSH"; // Fabric API Mod Id in Modrinth
+  get_latest_mod_download(app, FABRIC_API_MOD_ID, "Fabric", game_version, mods_dir).await
+}
 
+pub async fn get_latest_quilted_fabric_api_mod_download(
+  app: &AppHandle,
+  game_version: &str,
+  mods_dir: PathBuf,
+) -> SJMCLResult<Option<DownloadParam>> {
+  const QUILTED_FABRIC_API_MOD_ID: &str = "qvIfYCYJ"; // Quilted Fabric API (QSL) Mod Id in Modrinth
+  get_latest_mod_download(
+    app,
+    QUILTED_FABRIC_API_MOD_ID,
+    "Quilt",
+    game_version,
+    mods_dir,
+  )
+  .await
+}
+
+async fn get_latest_mod_download(
+  app: &AppHandle,
+  resource_id: &str,
+  mod_loader: &str,
+  game_version: &str,
+  mods_dir: PathBuf,
+) -> SJMCLResult<Option<DownloadParam>> {
   let query = OtherResourceVersionPackQuery {
-    resource_id: FABRIC_API_MOD_ID.to_string(),
-    mod_loader: "Fabric".to_string(),
+    resource_id: resource_id.to_string(),
+    mod_loader: mod_loader.to_string(),
     game_versions: vec![game_version.to_string()],
   };
 
-  let version_packs = fetch_resource_version_packs_modrinth(app, &query).await?;
+  // the fabric api mods are looked up on the official modrinth, even if another api is configured
+  let version_packs = fetch_resource_version_packs_modrinth(app, MODRINTH_API_BASE, &query).await?;
 
   let version_pack = version_packs.first().ok_or(ResourceError::ParseError)?;
 
@@ -227,5 +389,7 @@ pub async fn get_latest_fabric_api_mod_download(
     dest: dest_path,
     filename: Some(filename),
     sha1: Some(latest_file.sha1.clone()),
+    alt_srcs: vec![],
+    served_src: None,
   }))
 }
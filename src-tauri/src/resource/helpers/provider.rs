@@ -0,0 +1,94 @@
+use crate::error::SJMCLResult;
+use crate::launcher_config::models::LauncherConfig;
+use crate::resource::helpers::curseforge::CurseForgeProvider;
+use crate::resource::helpers::modrinth::ModrinthProvider;
+use crate::resource::models::{
+  OtherResourceFileInfo, OtherResourceInfo, OtherResourceSearchQuery, OtherResourceSearchRes,
+  OtherResourceSource, OtherResourceVersionPack, OtherResourceVersionPackQuery, ResourceError,
+};
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use tauri::AppHandle;
+
+// a platform hosting mods, resource packs, shaders, worlds and modpacks.
+// to add a platform, implement this trait and register it in `register_resource_providers`.
+#[async_trait]
+pub trait ResourceProvider: Send + Sync {
+  fn source(&self) -> OtherResourceSource;
+  // the root of the platform api, e.g. a self-hosted labrinth serving the modrinth api
+  fn api_base(&self) -> &str;
+  async fn search(
+    &self,
+    app: &AppHandle,
+    query: &OtherResourceSearchQuery,
+  ) -> SJMCLResult<OtherResourceSearchRes>;
+  async fn fetch_version_packs(
+    &self,
+    app: &AppHandle,
+    query: &OtherResourceVersionPackQuery,
+  ) -> SJMCLResult<Vec<OtherResourceVersionPack>>;
+  // find the remote file matching a local one, usually by its hash
+  async fn fetch_by_local_file(
+    &self,
+    app: &AppHandle,
+    file_path: &str,
+  ) -> SJMCLResult<OtherResourceFileInfo>;
+  async fn fetch_by_id(&self, app: &AppHandle, resource_id: &str)
+    -> SJMCLResult<OtherResourceInfo>;
+  // translated description of the resource, None if the platform does not provide one
+  async fn translate_description(
+    &self,
+    _app: &AppHandle,
+    _resource_id: &str,
+  ) -> SJMCLResult<Option<String>> {
+    Ok(None)
+  }
+}
+
+lazy_static! {
+  static ref RESOURCE_PROVIDERS: RwLock<HashMap<OtherResourceSource, Arc<dyn ResourceProvider>>> =
+    RwLock::new(HashMap::new());
+}
+
+fn build_resource_providers(launcher_config: &LauncherConfig) -> Vec<Arc<dyn ResourceProvider>> {
+  let apis = &launcher_config.download.resource_api;
+  let mut curseforge = CurseForgeProvider::default();
+  if !apis.curseforge.trim().is_empty() {
+    curseforge.api_base = apis.curseforge.trim().to_string();
+  }
+  let mut modrinth = ModrinthProvider::default();
+  if !apis.modrinth.trim().is_empty() {
+    modrinth.api_base = apis.modrinth.trim().to_string();
+  }
+  vec![Arc::new(curseforge), Arc::new(modrinth)]
+}
+
+// (re)build the providers from the launcher config, called when it is loaded or updated.
+pub fn register_resource_providers(launcher_config: &LauncherConfig) {
+  let Ok(mut registered) = RESOURCE_PROVIDERS.write() else {
+    return;
+  };
+  registered.clear();
+  for provider in build_resource_providers(launcher_config) {
+    registered.insert(provider.source(), provider);
+  }
+}
+
+pub fn get_resource_provider(
+  source: &OtherResourceSource,
+) -> SJMCLResult<Arc<dyn ResourceProvider>> {
+  if let Some(provider) = RESOURCE_PROVIDERS
+    .read()
+    .ok()
+    .and_then(|registered| registered.get(source).cloned())
+  {
+    return Ok(provider);
+  }
+  // not registered yet, fall back to the official apis
+  build_resource_providers(&LauncherConfig::default())
+    .into_iter()
+    .find(|provider| &provider.source() == source)
+    .ok_or(ResourceError::NoDownloadApi.into())
+}
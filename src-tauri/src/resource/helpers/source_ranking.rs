@@ -0,0 +1,367 @@
+use crate::launcher_config::models::LauncherConfig;
+use crate::resource::constants::{
+  SOURCE_PROBE_INTERVAL, SOURCE_PROBE_SAMPLE_BYTES, SOURCE_PROBE_SAMPLE_SECS, SOURCE_PROBE_TIMEOUT,
+  SOURCE_RANKING_FILE_NAME,
+};
+use crate::resource::helpers::misc::{get_download_api, get_source_priority_list};
+use crate::resource::models::{ResourceType, SourceType};
+use crate::storage::Storage;
+use crate::APP_DATA_DIR;
+use futures::future::join_all;
+use futures::StreamExt;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_http::reqwest::{Client, Response};
+use url::Url;
+
+// resource types whose endpoints are probed, the overall ranking is averaged over them.
+const PROBED_RESOURCE_TYPES: [ResourceType; 5] = [
+  ResourceType::VersionManifestV2,
+  ResourceType::Libraries,
+  ResourceType::Assets,
+  ResourceType::ForgeMaven,
+  ResourceType::FabricMeta,
+];
+
+// cost of a failure reported by a download task, on the same scale as the latency
+const FAILURE_PENALTY_MS: f64 = 10_000.0;
+const UNREACHABLE_COST_MS: f64 = 1_000_000.0;
+// the throughput is converted to the time needed to fetch a file of this size
+const REFERENCE_FILE_SIZE_KB: f64 = 1024.0;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SourceMeasurement {
+  pub latency_ms: Option<u64>, // None if the endpoint is unreachable
+  pub throughput_kbps: Option<f64>,
+  pub failures: u32, // failures reported by download tasks since the last probe
+  pub measured_at: i64,
+}
+
+impl SourceMeasurement {
+  pub fn cost(&self) -> f64 {
+    let base = match self.latency_ms {
+      Some(latency) => {
+        latency as f64
+          + self
+            .throughput_kbps
+            .filter(|throughput| *throughput > 0.0)
+            .map(|throughput| REFERENCE_FILE_SIZE_KB / throughput * 1000.0)
+            .unwrap_or(0.0)
+      }
+      None => UNREACHABLE_COST_MS,
+    };
+    base + self.failures as f64 * FAILURE_PENALTY_MS
+  }
+}
+
+// measurements keyed by the api base url, so that custom sources and per-resource-type
+// endpoints of the same source are ranked independently.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SourceRanking {
+  #[serde(flatten)]
+  pub measurements: HashMap<String, SourceMeasurement>,
+}
+
+impl Storage for SourceRanking {
+  fn file_path() -> PathBuf {
+    APP_DATA_DIR.get().unwrap().join(SOURCE_RANKING_FILE_NAME)
+  }
+}
+
+lazy_static! {
+  static ref SOURCE_RANKING: RwLock<SourceRanking> = RwLock::new(SourceRanking::default());
+}
+
+fn get_source_cost(
+  ranking: &SourceRanking,
+  source: SourceType,
+  resource_type: ResourceType,
+) -> Option<f64> {
+  let url = get_download_api(source, resource_type).ok()?;
+  ranking
+    .measurements
+    .get(url.as_str())
+    .map(|measurement| measurement.cost())
+}
+
+// sort the sources by their measured cost for the resource type, or averaged over the probed
+// types if none is given. unmeasured sources are tried first so that new mirrors get a chance.
+pub fn rank_sources(sources: &mut [SourceType], resource_type: Option<ResourceType>) {
+  let ranking = match SOURCE_RANKING.read() {
+    Ok(ranking) => ranking,
+    Err(_) => return,
+  };
+  if ranking.measurements.is_empty() {
+    return;
+  }
+
+  let costs: HashMap<SourceType, f64> = sources
+    .iter()
+    .map(|source| {
+      let costs: Vec<f64> = match resource_type {
+        Some(resource_type) => get_source_cost(&ranking, *source, resource_type)
+          .into_iter()
+          .collect(),
+        None => PROBED_RESOURCE_TYPES
+          .iter()
+          .filter_map(|resource_type| get_source_cost(&ranking, *source, *resource_type))
+          .collect(),
+      };
+      let cost = if costs.is_empty() {
+        0.0
+      } else {
+        costs.iter().sum::<f64>() / costs.len() as f64
+      };
+      (*source, cost)
+    })
+    .collect();
+
+  sources.sort_by(|a, b| costs[a].total_cmp(&costs[b]));
+}
+
+// lower the rank of the source serving this url, until it is probed again.
+pub fn report_source_failure(url: &Url) {
+  let mut ranking = match SOURCE_RANKING.write() {
+    Ok(ranking) => ranking,
+    Err(_) => return,
+  };
+  let mut reported = false;
+  for (base_url, measurement) in ranking.measurements.iter_mut() {
+    if url.as_str().starts_with(base_url.as_str()) {
+      measurement.failures += 1;
+      reported = true;
+    }
+  }
+  if reported {
+    log::warn!(
+      "Download from {} failed, lowering the rank of its source",
+      url
+    );
+    let _ = ranking.save();
+  }
+}
+
+async fn sample_throughput(response: Response) -> Option<f64> {
+  let start = Instant::now();
+  let deadline = Duration::from_secs(SOURCE_PROBE_SAMPLE_SECS);
+  let mut stream = response.bytes_stream();
+  let mut received = 0usize;
+
+  while received < SOURCE_PROBE_SAMPLE_BYTES {
+    let remaining = deadline.checked_sub(start.elapsed())?;
+    match tokio::time::timeout(remaining, stream.next()).await {
+      Ok(Some(Ok(chunk))) => received += chunk.len(),
+      _ => break,
+    }
+  }
+
+  let elapsed = start.elapsed().as_secs_f64();
+  if received == 0 || elapsed <= 0.0 {
+    return None;
+  }
+  Some(received as f64 / 1024.0 / elapsed)
+}
+
+/// Measures the latency and a short throughput sample of an endpoint.
+///
+/// # Arguments
+///
+/// * `client` - The client used to send the probe request.
+/// * `url` - The endpoint to probe, usually the api base url of a source.
+///
+/// # Returns
+///
+/// The measurement, with `latency_ms` unset if the endpoint timed out or returned a server error.
+/// Client errors (e.g. 404 on a maven root) still count as reachable, but carry no throughput.
+pub async fn probe_endpoint(client: &Client, url: &Url) -> SourceMeasurement {
+  let measured_at = chrono::Utc::now().timestamp();
+  let start = Instant::now();
+  let timeout = Duration::from_secs(SOURCE_PROBE_TIMEOUT);
+
+  let response = match tokio::time::timeout(timeout, client.get(url.clone()).send()).await {
+    Ok(Ok(response)) if !response.status().is_server_error() => response,
+    _ => {
+      return SourceMeasurement {
+        measured_at,
+        ..Default::default()
+      }
+    }
+  };
+  let latency_ms = start.elapsed().as_millis() as u64;
+  let throughput_kbps = if response.status().is_success() {
+    sample_throughput(response).await
+  } else {
+    None
+  };
+
+  SourceMeasurement {
+    latency_ms: Some(latency_ms),
+    throughput_kbps,
+    failures: 0,
+    measured_at,
+  }
+}
+
+// probe the endpoints concurrently, keyed by url.
+pub async fn probe_endpoints(client: &Client, urls: &[Url]) -> HashMap<String, SourceMeasurement> {
+  let measurements = join_all(urls.iter().map(|url| probe_endpoint(client, url))).await;
+  urls
+    .iter()
+    .map(|url| url.to_string())
+    .zip(measurements)
+    .collect()
+}
+
+pub async fn refresh_source_ranking(app: &AppHandle) {
+  let sources = {
+    let config_binding = app.state::<Mutex<LauncherConfig>>();
+    let config_state = match config_binding.lock() {
+      Ok(config_state) => config_state,
+      Err(_) => return,
+    };
+    get_source_priority_list(&config_state)
+  };
+
+  let mut seen = HashSet::new();
+  let urls: Vec<Url> = sources
+    .iter()
+    .flat_map(|source| {
+      PROBED_RESOURCE_TYPES
+        .iter()
+        .filter_map(move |resource_type| get_download_api(*source, *resource_type).ok())
+    })
+    .filter(|url| seen.insert(url.to_string()))
+    .collect();
+
+  let client = app.state::<Client>().inner().clone();
+  let measurements = probe_endpoints(&client, &urls).await;
+
+  if let Ok(mut ranking) = SOURCE_RANKING.write() {
+    ranking.measurements.extend(measurements);
+    let _ = ranking.save();
+  }
+}
+
+// load the stored ranking, then probe the sources at startup and periodically after that.
+pub async fn monitor_source_ranking(app: AppHandle) {
+  if let Ok(stored) = SourceRanking::load() {
+    if let Ok(mut ranking) = SOURCE_RANKING.write() {
+      *ranking = stored;
+    }
+  }
+
+  loop {
+    let is_auto = app
+      .state::<Mutex<LauncherConfig>>()
+      .lock()
+      .map(|config| config.download.source.strategy == "auto")
+      .unwrap_or(false);
+    // the ranking is only used by the auto strategy, do not send probes otherwise
+    if is_auto {
+      refresh_source_ranking(&app).await;
+    }
+    tokio::time::sleep(Duration::from_secs(SOURCE_PROBE_INTERVAL)).await;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::launcher_config::models::CustomDownloadSource;
+  use crate::resource::helpers::misc::register_custom_sources;
+  use tokio::io::{AsyncReadExt, AsyncWriteExt};
+  use tokio::net::TcpListener;
+
+  // a local endpoint answering every request with `status` after `delay`
+  async fn serve(delay: Duration, status: &'static str) -> Url {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+      while let Ok((mut stream, _)) = listener.accept().await {
+        tokio::spawn(async move {
+          let mut buf = [0u8; 1024];
+          let _ = stream.read(&mut buf).await;
+          tokio::time::sleep(delay).await;
+          let body = vec![b'x'; 64 * 1024];
+          let header = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            status,
+            body.len()
+          );
+          let _ = stream.write_all(header.as_bytes()).await;
+          let _ = stream.write_all(&body).await;
+        });
+      }
+    });
+    Url::parse(&format!("http://{}/", addr)).unwrap()
+  }
+
+  fn client() -> Client {
+    Client::builder().no_proxy().build().unwrap()
+  }
+
+  #[tokio::test]
+  async fn probe_endpoint_measures_injected_latency() {
+    let url = serve(Duration::from_millis(300), "200 OK").await;
+    let measurement = probe_endpoint(&client(), &url).await;
+    assert!(measurement.latency_ms.is_some_and(|latency| latency >= 300));
+    assert!(measurement.throughput_kbps.is_some());
+  }
+
+  #[tokio::test]
+  async fn probe_endpoint_treats_server_error_as_unreachable() {
+    let url = serve(Duration::ZERO, "503 Service Unavailable").await;
+    let measurement = probe_endpoint(&client(), &url).await;
+    assert!(measurement.latency_ms.is_none());
+    assert_eq!(measurement.cost(), UNREACHABLE_COST_MS);
+  }
+
+  #[tokio::test]
+  async fn probe_endpoint_keeps_client_error_reachable() {
+    let url = serve(Duration::ZERO, "404 Not Found").await;
+    let measurement = probe_endpoint(&client(), &url).await;
+    assert!(measurement.latency_ms.is_some());
+    assert!(measurement.throughput_kbps.is_none());
+  }
+
+  #[tokio::test]
+  async fn rank_sources_prefers_faster_source() {
+    let slow_url = serve(Duration::from_millis(800), "200 OK").await;
+    let fast_url = serve(Duration::from_millis(50), "200 OK").await;
+    let broken_url = serve(Duration::ZERO, "500 Internal Server Error").await;
+
+    let mut config = LauncherConfig::default();
+    config.download.source.custom_sources = [
+      ("slow", &slow_url),
+      ("fast", &fast_url),
+      ("broken", &broken_url),
+    ]
+    .into_iter()
+    .map(|(name, url)| CustomDownloadSource {
+      name: name.to_string(),
+      enabled: true,
+      base_urls: HashMap::from([(ResourceType::FabricMeta, url.to_string())]),
+    })
+    .collect();
+    let mut sources = register_custom_sources(&config);
+    let &[slow, fast, broken] = sources.as_slice() else {
+      panic!("the custom sources are not registered");
+    };
+
+    let measurements = probe_endpoints(&client(), &[slow_url, fast_url, broken_url]).await;
+    SOURCE_RANKING
+      .write()
+      .unwrap()
+      .measurements
+      .extend(measurements);
+
+    rank_sources(&mut sources, Some(ResourceType::FabricMeta));
+    assert_eq!(sources, vec![fast, slow, broken]);
+  }
+}
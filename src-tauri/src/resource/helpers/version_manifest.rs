@@ -1,34 +1,50 @@
 use crate::error::SJMCLResult;
+use crate::resource::constants::{
+  BUNDLED_VERSION_MANIFEST_PATH, VERSION_MANIFEST_CACHE_TTL, VERSION_MANIFEST_FILE_NAME,
+};
+use crate::resource::helpers::http_cache::send_with_cache;
 use crate::resource::helpers::misc::get_download_api;
 use crate::resource::models::{GameClientResourceInfo, ResourceError, ResourceType, SourceType};
+use crate::utils::fs::get_app_resource_filepath;
+use crate::APP_DATA_DIR;
+use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
 use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
 use tauri::{AppHandle, Manager};
 use tauri_plugin_http::reqwest;
 
-#[derive(Serialize, Deserialize, Default)]
-struct VersionManifest {
+#[derive(Serialize, Deserialize, Default, Clone)]
+pub struct VersionManifest {
   pub latest: LatestVersion,
   pub versions: Vec<GameResource>,
 }
 
+// the bundled snapshot only keeps `id`, `type` and `releaseTime` of each version
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
-#[serde(rename_all = "camelCase", deny_unknown_fields)]
-struct GameResource {
+#[serde(rename_all = "camelCase")]
+pub struct GameResource {
   pub id: String,
   #[serde(rename = "type")]
   pub game_type: String,
   pub release_time: String,
+  #[serde(default)]
   pub time: String,
+  #[serde(default)]
   pub url: String,
 }
 
-#[derive(Serialize, Deserialize, Default)]
-struct LatestVersion {
+#[derive(Serialize, Deserialize, Default, Clone)]
+pub struct LatestVersion {
   pub release: String,
   pub snapshot: String,
 }
 
+lazy_static! {
+  static ref LOCAL_VERSION_MANIFEST: RwLock<Option<Arc<VersionManifest>>> = RwLock::new(None);
+}
+
 pub async fn get_game_version_manifest(
   app: &AppHandle,
   priority_list: &[SourceType],
@@ -36,19 +52,21 @@ pub async fn get_game_version_manifest(
   let client = app.state::<reqwest::Client>();
 
   for source_type in priority_list.iter() {
-    let url = get_download_api(*source_type, ResourceType::VersionManifest)?;
-    let response = match client.get(url).send().await {
-      Ok(resp) if resp.status().is_success() => resp,
-      _ => continue,
+    let Ok(url) = get_download_api(*source_type, ResourceType::VersionManifestV2) else {
+      continue;
+    };
+    let body = match send_with_cache(app, client.get(url), VERSION_MANIFEST_CACHE_TTL).await {
+      Ok(body) => body,
+      Err(_) => continue,
     };
 
-    let manifest = match response.json::<VersionManifest>().await {
+    let manifest = match serde_json::from_str::<VersionManifest>(&body) {
       Ok(m) => m,
       Err(_) => return Err(ResourceError::ParseError.into()),
     };
 
-    save_version_list_to_cache(app, &manifest.versions);
-    // update list saved in cache dir, may be used in version compare.
+    // persisted for version ordering and java requirement lookups without network
+    save_version_manifest(&body, manifest.clone());
 
     let game_info_list = manifest
       .versions
@@ -74,20 +92,58 @@ pub async fn get_game_version_manifest(
   Err(ResourceError::NetworkError.into())
 }
 
-fn save_version_list_to_cache(app: &AppHandle, versions: &[GameResource]) {
-  let cache_dir = match app.path().app_cache_dir().ok() {
-    Some(dir) => dir,
-    None => return,
-  };
+fn get_persisted_manifest_path() -> Option<PathBuf> {
+  APP_DATA_DIR
+    .get()
+    .map(|dir| dir.join(VERSION_MANIFEST_FILE_NAME))
+}
+
+fn read_version_manifest(path: &Path) -> Option<VersionManifest> {
+  let content = fs::read_to_string(path).ok()?;
+  serde_json::from_str(&content).ok()
+}
 
-  if !cache_dir.exists() && fs::create_dir_all(&cache_dir).is_err() {
-    return;
+fn save_version_manifest(body: &str, manifest: VersionManifest) {
+  if let Some(path) = get_persisted_manifest_path() {
+    let _ = fs::write(path, body);
   }
+  if let Ok(mut cached) = LOCAL_VERSION_MANIFEST.write() {
+    *cached = Some(Arc::new(manifest));
+  }
+}
 
-  let file_path = cache_dir.join("game_versions.txt");
-  let mut ids: Vec<String> = versions.iter().map(|v| v.id.clone()).collect();
-  ids.reverse(); // reverse order
+/// Loads the version manifest without network access.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri AppHandle, used to resolve the bundled snapshot in app resources.
+///
+/// # Returns
+///
+/// The last manifest fetched from remote, or the snapshot bundled with the launcher if it lists
+/// more versions (e.g. right after the launcher is updated). None if neither is readable.
+pub fn load_local_version_manifest(app: &AppHandle) -> Option<Arc<VersionManifest>> {
+  if let Some(manifest) = LOCAL_VERSION_MANIFEST
+    .read()
+    .ok()
+    .and_then(|cached| cached.clone())
+  {
+    return Some(manifest);
+  }
 
-  let content = ids.join("\n");
-  let _ = fs::write(file_path, content);
+  let persisted = get_persisted_manifest_path().and_then(|path| read_version_manifest(&path));
+  let bundled = get_app_resource_filepath(app, BUNDLED_VERSION_MANIFEST_PATH)
+    .ok()
+    .and_then(|path| read_version_manifest(&path));
+  let manifest = Arc::new(match (persisted, bundled) {
+    (Some(persisted), Some(bundled)) if bundled.versions.len() > persisted.versions.len() => {
+      bundled
+    }
+    (persisted, bundled) => persisted.or(bundled)?,
+  });
+
+  if let Ok(mut cached) = LOCAL_VERSION_MANIFEST.write() {
+    *cached = Some(manifest.clone());
+  }
+  Some(manifest)
 }
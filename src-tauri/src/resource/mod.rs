@@ -1,3 +1,4 @@
 pub mod commands;
+pub mod constants;
 pub mod helpers;
 pub mod models;
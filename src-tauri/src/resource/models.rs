@@ -4,7 +4,7 @@ use std::collections::HashMap;
 use std::str::FromStr;
 use strum_macros::{Display, EnumIter};
 
-#[derive(Eq, Hash, PartialEq, Clone, Copy, Debug, EnumIter)]
+#[derive(Eq, Hash, PartialEq, Clone, Copy, Debug, EnumIter, Deserialize, Serialize)]
 pub enum ResourceType {
   VersionManifest,
   VersionManifestV2,
@@ -18,6 +18,7 @@ pub enum ResourceType {
   ForgeMavenNew,
   ForgeInstall,
   Liteloader,
+  LiteloaderMaven,
   OptiFine,
   AuthlibInjector,
   FabricMeta,
@@ -28,21 +29,25 @@ pub enum ResourceType {
   NeoforgeMaven,
   QuiltMaven,
   QuiltMeta,
+  CleanroomMaven,
 }
 
-#[derive(Eq, Hash, PartialEq, Clone, Copy, Debug, EnumIter)]
+#[derive(Eq, Hash, PartialEq, Clone, Copy, Debug)]
 pub enum SourceType {
   Official,
   BMCLAPIMirror,
+  Custom(u64), // id of the custom source, see `register_custom_sources`
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize, Serialize, Default)]
 pub enum OtherResourceSource {
   #[default]
   Unknown,
   CurseForge,
   Modrinth,
   MultiMc,
+  Packwiz,
+  Hmcl,
 }
 
 impl FromStr for OtherResourceSource {
@@ -53,6 +58,8 @@ impl FromStr for OtherResourceSource {
       "curseforge" => Ok(OtherResourceSource::CurseForge),
       "modrinth" => Ok(OtherResourceSource::Modrinth),
       "multimc" => Ok(OtherResourceSource::MultiMc),
+      "packwiz" => Ok(OtherResourceSource::Packwiz),
+      "hmcl" => Ok(OtherResourceSource::Hmcl),
       _ => Err(format!("Unknown resource download type: {}", input)),
     }
   }
@@ -71,8 +78,11 @@ pub enum OtherResourceApiEndpoint {
   Search,
   VersionPack,
   FromLocal,
+  BulkFromLocal,
   ById,
   TranslateDesc,
+  UpdateFromLocal,
+  Changelog,
 }
 
 // mod, save, resourcepack, datapack and shader
@@ -93,6 +103,21 @@ pub struct OtherResourceInfo {
   pub downloads: u64,
   pub source: OtherResourceSource,
   pub website_url: String,
+  // the same project on other sources, only set by the merged search
+  #[serde(default)]
+  pub linked_sources: Vec<OtherResourceLink>,
+  // sha1 of the latest files if the search result provides them, used to match projects
+  #[serde(default, skip_serializing)]
+  pub latest_file_hashes: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct OtherResourceLink {
+  pub id: String,
+  pub slug: String,
+  pub source: OtherResourceSource,
+  pub website_url: String,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
@@ -162,6 +187,46 @@ pub struct ModUpdateQuery {
   pub old_file_path: String,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ModUpdateInfo {
+  pub name: String,
+  pub cur_version: String,
+  pub old_file_path: String,
+  pub source: OtherResourceSource,
+  pub file: OtherResourceFileInfo,
+  pub changelog: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ModUpdateHistoryEntry {
+  // also the name of the backup dir of replaced files
+  pub id: String,
+  pub time: i64,
+  pub items: Vec<ModUpdateHistoryItem>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ModUpdateHistoryItem {
+  pub old_file_name: String,
+  pub new_file_name: String,
+}
+
+// one required dependency in the download plan of a resource
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceDependencyPlanItem {
+  pub resource: OtherResourceInfo,
+  // None if no file matches the loader and game version of the instance
+  pub file: Option<OtherResourceFileInfo>,
+  // resource id of the mod that requires it
+  pub required_by: String,
+  // already present in the instance, will not be downloaded
+  pub installed: bool,
+}
+
 // game client itself
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -198,6 +263,7 @@ pub enum ResourceError {
   NetworkError,
   FileOperationError,
   ClientVersionNotFound,
+  ModUpdateHistoryNotFound,
 }
 
 impl std::error::Error for ResourceError {}
@@ -1,10 +1,17 @@
 use crate::error::SJMCLResult;
+use crate::launcher_config::models::LauncherConfig;
+use crate::resource::helpers::misc::{
+  get_alternative_urls, get_source_priority_list, get_source_priority_list_for,
+  get_url_resource_type,
+};
 use crate::tasks::download::DownloadTask;
 use crate::tasks::events::GEventStatus;
 use crate::tasks::monitor::TaskMonitor;
 use crate::tasks::{PTaskGroupDesc, PTaskParam, SJMCLFutureDesc, THandle};
 use crate::utils::fs::extract_filename;
+use std::collections::HashMap;
 use std::pin::Pin;
+use std::sync::Mutex;
 use std::time::Duration;
 use tauri::{AppHandle, Manager};
 
@@ -27,6 +34,14 @@ pub async fn schedule_progressive_task_group(
     task_group.clone()
   };
 
+  let launcher_config = {
+    let launcher_config_state = app.state::<Mutex<LauncherConfig>>();
+    let launcher_config = launcher_config_state.lock()?;
+    launcher_config.clone()
+  };
+  // the alternative sources are ranked by the measurements of the resource type of each file
+  let mut priority_lists = HashMap::new();
+
   for param in params {
     let task_id = monitor.get_new_id();
     match param {
@@ -37,6 +52,19 @@ pub async fn schedule_progressive_task_group(
             true,
           ));
         }
+        if param.alt_srcs.is_empty() {
+          let resource_type = get_url_resource_type(&param.src);
+          let priority_list =
+            priority_lists
+              .entry(resource_type)
+              .or_insert_with(|| match resource_type {
+                Some(resource_type) => {
+                  get_source_priority_list_for(&launcher_config, resource_type)
+                }
+                None => get_source_priority_list(&launcher_config),
+              });
+          param.alt_srcs = get_alternative_urls(&param.src, priority_list);
+        }
         let task = DownloadTask::new(
           app.clone(),
           task_id,
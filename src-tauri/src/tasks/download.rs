@@ -1,5 +1,6 @@
 use crate::error::{SJMCLError, SJMCLResult};
 use crate::launcher_config::commands::retrieve_launcher_config;
+use crate::resource::helpers::source_ranking::report_source_failure;
 use crate::tasks::streams::desc::{PDesc, PStatus};
 use crate::tasks::streams::reporter::Reporter;
 use crate::tasks::streams::ProgressStream;
@@ -12,7 +13,7 @@ use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::future::Future;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
 use tauri::{AppHandle, Manager, Url};
@@ -29,6 +30,12 @@ pub struct DownloadParam {
   pub dest: PathBuf,
   pub filename: Option<String>,
   pub sha1: Option<String>,
+  // tried in order after `src` fails with an http error or a hash mismatch
+  #[serde(default)]
+  pub alt_srcs: Vec<Url>,
+  // the url that finally served the file, recorded once the task completes
+  #[serde(default)]
+  pub served_src: Option<Url>,
 }
 
 pub struct DownloadTask {
@@ -122,15 +129,15 @@ impl DownloadTask {
   async fn send_request(
     app_handle: &AppHandle,
     current: i64,
-    param: &DownloadParam,
+    src: &Url,
   ) -> SJMCLResult<reqwest::Response> {
     let state = app_handle.state::<reqwest::Client>();
     let client = with_retry(state.inner().clone());
     let request = if current == 0 {
-      client.get(param.src.clone())
+      client.get(src.clone())
     } else {
       client
-        .get(param.src.clone())
+        .get(src.clone())
         .header(RANGE, format!("bytes={current}-"))
     };
 
@@ -149,12 +156,12 @@ impl DownloadTask {
   async fn create_resp_stream(
     app_handle: &AppHandle,
     current: i64,
-    param: &DownloadParam,
+    src: &Url,
   ) -> SJMCLResult<(
     impl Stream<Item = Result<bytes::Bytes, std::io::Error>> + Send,
     i64,
   )> {
-    let resp = Self::send_request(app_handle, current, param).await?;
+    let resp = Self::send_request(app_handle, current, src).await?;
     let total_progress = if current == 0 {
       resp.content_length().unwrap() as i64
     } else {
@@ -169,6 +176,37 @@ impl DownloadTask {
     ))
   }
 
+  async fn download_from(
+    app_handle: &AppHandle,
+    src: &Url,
+    current: i64,
+    dest_path: &Path,
+    task_handle: Arc<RwLock<PTaskHandle>>,
+    limiter: Option<Limiter>,
+  ) -> SJMCLResult<()> {
+    let (resp, total_progress) = Self::create_resp_stream(app_handle, current, src).await?;
+    let stream = ProgressStream::new(resp, task_handle.clone());
+    tokio::fs::create_dir_all(dest_path.parent().unwrap()).await?;
+    let mut file = if current == 0 {
+      tokio::fs::File::create(dest_path).await?
+    } else {
+      let mut f = tokio::fs::OpenOptions::new().open(dest_path).await?;
+      f.seek(std::io::SeekFrom::Start(current as u64)).await?;
+      f
+    };
+    {
+      let mut task_handle = task_handle.write().unwrap();
+      task_handle.set_total(total_progress);
+      task_handle.mark_started();
+    }
+    if let Some(lim) = limiter {
+      tokio::io::copy(&mut lim.limit(stream.into_async_read()).compat(), &mut file).await?;
+    } else {
+      tokio::io::copy(&mut stream.into_async_read().compat(), &mut file).await?;
+    }
+    Ok(())
+  }
+
   async fn future_impl(
     self,
     app_handle: AppHandle,
@@ -177,42 +215,56 @@ impl DownloadTask {
     impl Future<Output = SJMCLResult<()>> + Send,
     Arc<RwLock<PTaskHandle>>,
   )> {
-    let current = self.p_handle.desc.current;
+    let mut current = self.p_handle.desc.current;
     let handle = Arc::new(RwLock::new(self.p_handle));
     let task_handle = handle.clone();
     let param = self.param.clone();
     Ok((
       async move {
-        let (resp, total_progress) = Self::create_resp_stream(&app_handle, current, &param).await?;
-        let stream = ProgressStream::new(resp, task_handle.clone());
-        tokio::fs::create_dir_all(&self.dest_path.parent().unwrap()).await?;
-        let mut file = if current == 0 {
-          tokio::fs::File::create(&self.dest_path).await?
-        } else {
-          let mut f = tokio::fs::OpenOptions::new().open(&self.dest_path).await?;
-          f.seek(std::io::SeekFrom::Start(current as u64)).await?;
-          f
-        };
-        {
+        let srcs = std::iter::once(&param.src).chain(param.alt_srcs.iter());
+        let mut last_err = None;
+        for (index, src) in srcs.enumerate() {
+          if index > 0 {
+            // a partial download cannot be resumed from another source
+            current = 0;
+            task_handle.write().unwrap().desc.current = 0;
+            log::info!("Falling back to {} for {}", src, param.dest.display());
+          }
+
+          if let Err(e) = Self::download_from(
+            &app_handle,
+            src,
+            current,
+            &self.dest_path,
+            task_handle.clone(),
+            limiter.clone(),
+          )
+          .await
+          {
+            report_source_failure(src);
+            last_err = Some(e);
+            continue;
+          }
+
+          if task_handle.read().unwrap().status().is_cancelled() {
+            tokio::fs::remove_file(&self.dest_path).await?;
+            return Ok(());
+          }
+          if let Some(truth) = &param.sha1 {
+            if let Err(e) = validate_sha1(param.dest.clone(), truth.clone()) {
+              report_source_failure(src);
+              last_err = Some(e);
+              continue;
+            }
+          }
+
           let mut task_handle = task_handle.write().unwrap();
-          task_handle.set_total(total_progress);
-          task_handle.mark_started();
-        }
-        if let Some(lim) = limiter {
-          tokio::io::copy(&mut lim.limit(stream.into_async_read()).compat(), &mut file).await?;
-        } else {
-          tokio::io::copy(&mut stream.into_async_read().compat(), &mut file).await?;
-        }
-        drop(file);
-        if task_handle.read().unwrap().status().is_cancelled() {
-          tokio::fs::remove_file(&self.dest_path).await?;
-          Ok(())
-        } else {
-          match param.sha1 {
-            Some(truth) => validate_sha1(param.dest, truth),
-            None => Ok(()),
+          match &mut task_handle.desc.payload {
+            PTaskParam::Download(payload) => payload.served_src = Some(src.clone()),
           }
+          return Ok(());
         }
+        Err(last_err.unwrap_or_else(|| SJMCLError("No download source available".to_string())))
       },
       handle,
     ))
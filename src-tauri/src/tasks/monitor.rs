@@ -469,6 +469,30 @@ impl TaskMonitor {
     }
   }
 
+  // None if the group does not exist, e.g. it has failed or been cancelled
+  pub fn get_group_status(&self, task_group: &str) -> Option<GEventStatus> {
+    self
+      .group_map
+      .read()
+      .unwrap()
+      .get(task_group)
+      .map(|g| g.status.clone())
+  }
+
+  // wait until the group is no longer running or stopped, returns true if it has completed.
+  // a failed task does not fail its group, so the outputs still have to be checked by the caller.
+  pub async fn wait_for_group(&self, task_group: &str) -> bool {
+    loop {
+      match self.get_group_status(task_group) {
+        Some(GEventStatus::Completed) => return true,
+        Some(GEventStatus::Started) | Some(GEventStatus::Stopped) => {
+          tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+        }
+        _ => return false,
+      }
+    }
+  }
+
   pub fn state_list(&self) -> Vec<PTaskGroupDesc> {
     self
       .group_map
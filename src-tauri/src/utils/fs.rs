@@ -2,7 +2,7 @@ use crate::error::{SJMCLError, SJMCLResult};
 use crate::IS_PORTABLE;
 use regex::Regex;
 use sha1::{Digest, Sha1};
-use sha2::Sha256;
+use sha2::{Sha256, Sha512};
 use std::ffi::OsStr;
 use std::io::Read;
 use std::path::{Path, PathBuf};
@@ -403,6 +403,58 @@ pub fn validate_sha1(dest_path: PathBuf, truth: String) -> SJMCLResult<()> {
   }
 }
 
+/// Calculates the SHA1 hash of a file.
+///
+/// # Parameters
+/// - `path`: The file path to hash
+///
+/// # Returns
+/// - `SJMCLResult<String>`: The SHA1 hash as a hexadecimal string, or an error
+pub fn calculate_sha1(path: &Path) -> SJMCLResult<String> {
+  let mut file = std::fs::File::open(path).map_err(|e| {
+    SJMCLError(format!(
+      "Failed to open file {} for hashing: {}",
+      path.display(),
+      e
+    ))
+  })?;
+  let mut hasher = Sha1::new();
+  std::io::copy(&mut file, &mut hasher).map_err(|e| {
+    SJMCLError(format!(
+      "Error reading file {} for hashing: {}",
+      path.display(),
+      e
+    ))
+  })?;
+  Ok(hex::encode(hasher.finalize()))
+}
+
+/// Calculates the SHA512 hash of a file.
+///
+/// # Parameters
+/// - `path`: The file path to hash
+///
+/// # Returns
+/// - `SJMCLResult<String>`: The SHA512 hash as a hexadecimal string, or an error
+pub fn calculate_sha512(path: &Path) -> SJMCLResult<String> {
+  let mut file = std::fs::File::open(path).map_err(|e| {
+    SJMCLError(format!(
+      "Failed to open file {} for hashing: {}",
+      path.display(),
+      e
+    ))
+  })?;
+  let mut hasher = Sha512::new();
+  std::io::copy(&mut file, &mut hasher).map_err(|e| {
+    SJMCLError(format!(
+      "Error reading file {} for hashing: {}",
+      path.display(),
+      e
+    ))
+  })?;
+  Ok(hex::encode(hasher.finalize()))
+}
+
 /// Calculates the SHA256 hash of a file.
 ///
 /// # Parameters
@@ -35,6 +35,9 @@ export const modLoaderTypes: ModLoaderType[] = [
   ModLoaderType.Forge,
   ModLoaderType.Fabric,
   ModLoaderType.NeoForge,
+  ModLoaderType.Quilt,
+  ModLoaderType.LiteLoader,
+  ModLoaderType.Cleanroom,
 ];
 
 export const modLoaderTypesToIcon: Record<string, string> = {
@@ -42,6 +45,9 @@ export const modLoaderTypesToIcon: Record<string, string> = {
   Fabric: "Fabric.png",
   Forge: "Forge.png",
   NeoForge: "NeoForge.png",
+  Quilt: "Quilt.png",
+  LiteLoader: "LiteLoader.png",
+  Cleanroom: "Anvil.png", // a fork of Forge
 };
 
 interface LoaderSelectorProps {
@@ -50,6 +56,8 @@ interface LoaderSelectorProps {
   onSelectModLoader: (v: ModLoaderResourceInfo) => void;
   selectedOptiFine?: OptiFineResourceInfo | undefined;
   onSelectOptiFine?: (v: OptiFineResourceInfo | undefined) => void;
+  selectedLiteLoader?: ModLoaderResourceInfo | undefined;
+  onSelectLiteLoader?: (v: ModLoaderResourceInfo | undefined) => void;
 }
 
 export const LoaderSelector: React.FC<LoaderSelectorProps> = ({
@@ -58,6 +66,8 @@ export const LoaderSelector: React.FC<LoaderSelectorProps> = ({
   onSelectModLoader,
   selectedOptiFine,
   onSelectOptiFine,
+  selectedLiteLoader,
+  onSelectLiteLoader,
   ...props
 }) => {
   const { t } = useTranslation();
@@ -71,6 +81,11 @@ export const LoaderSelector: React.FC<LoaderSelectorProps> = ({
   );
   const [selectedId, setSelectedId] = useState("");
 
+  // LiteLoader can be installed on top of Forge, instead of being the mod loader
+  const isLiteLoaderStacked =
+    typeof onSelectLiteLoader === "function" &&
+    selectedModLoader.loaderType === ModLoaderType.Forge;
+
   useEffect(() => {
     if (selectedOptiFine) {
       setSelectedType("OptiFine");
@@ -119,7 +134,13 @@ export const LoaderSelector: React.FC<LoaderSelectorProps> = ({
         children: <></>,
         isFullClickZone: true,
         onClick: () => {
-          if (isModLoaderResourceInfo(version)) {
+          if (
+            isModLoaderResourceInfo(version) &&
+            isLiteLoaderStacked &&
+            version.loaderType === ModLoaderType.LiteLoader
+          ) {
+            onSelectLiteLoader?.(version);
+          } else if (isModLoaderResourceInfo(version)) {
             onSelectModLoader(version);
           } else {
             onSelectOptiFine?.(version);
@@ -128,7 +149,14 @@ export const LoaderSelector: React.FC<LoaderSelectorProps> = ({
         },
       };
     },
-    [primaryColor, t, onSelectModLoader, onSelectOptiFine]
+    [
+      primaryColor,
+      t,
+      isLiteLoaderStacked,
+      onSelectModLoader,
+      onSelectOptiFine,
+      onSelectLiteLoader,
+    ]
   );
 
   const handleFetchModLoaderVersionList = useCallback(
@@ -217,6 +245,9 @@ export const LoaderSelector: React.FC<LoaderSelectorProps> = ({
           // When OptiFine is not compatible with the selected mod loader, or selected without a version, clear it
           onSelectOptiFine?.(undefined);
         }
+        if (type !== ModLoaderType.Forge) {
+          onSelectLiteLoader?.(undefined);
+        }
       },
       onCancel: () => {
         if (selectedType === type) {
@@ -224,10 +255,36 @@ export const LoaderSelector: React.FC<LoaderSelectorProps> = ({
           setSelectedId("");
         }
         onSelectModLoader(defaultModLoaderResourceInfo);
+        onSelectLiteLoader?.(undefined);
       },
     })
   );
 
+  if (isLiteLoaderStacked) {
+    selectableCardItems = selectableCardItems.map((item) =>
+      item.title !== ModLoaderType.LiteLoader
+        ? item
+        : {
+            ...item,
+            description:
+              selectedLiteLoader?.version ||
+              t("LoaderSelector.noVersionSelected"),
+            isSelected: !!selectedLiteLoader,
+            onSelect: () => {
+              setSelectedType(ModLoaderType.LiteLoader);
+              setSelectedId(selectedLiteLoader?.version || "");
+            },
+            onCancel: () => {
+              if (selectedType === ModLoaderType.LiteLoader) {
+                setSelectedType(ModLoaderType.Forge);
+                setSelectedId(selectedModLoader.version);
+              }
+              onSelectLiteLoader?.(undefined);
+            },
+          }
+    );
+  }
+
   if (typeof onSelectOptiFine === "function") {
     selectableCardItems.push({
       title: "OptiFine",
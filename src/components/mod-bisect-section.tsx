@@ -0,0 +1,270 @@
+import { Button, HStack, Tag, Text, VStack } from "@chakra-ui/react";
+import { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import {
+  LuCircleCheck,
+  LuCircleX,
+  LuPlay,
+  LuShieldCheck,
+} from "react-icons/lu";
+import { OptionItem, OptionItemGroup } from "@/components/common/option-item";
+import { Section } from "@/components/common/section";
+import { useLauncherConfig } from "@/contexts/config";
+import { useSharedModals } from "@/contexts/shared-modal";
+import { useToast } from "@/contexts/toast";
+import { InstanceSummary, ModBisectSession } from "@/models/instance/misc";
+import { InvokeResponse } from "@/models/response";
+import { InstanceService } from "@/services/instance";
+import { LaunchService } from "@/services/launch";
+
+interface ModBisectSectionProps {
+  summary: InstanceSummary | undefined;
+  onModsChanged: () => void;
+}
+
+const ModBisectSection: React.FC<ModBisectSectionProps> = ({
+  summary,
+  onModsChanged,
+}) => {
+  const { t } = useTranslation();
+  const { config } = useLauncherConfig();
+  const primaryColor = config.appearance.theme.primaryColor;
+  const { openSharedModal, openGenericConfirmDialog } = useSharedModals();
+  const toast = useToast();
+
+  const [session, setSession] = useState<ModBisectSession | null>(null);
+  const [isSubmitting, setIsSubmitting] = useState<boolean>(false);
+
+  const handleRetrieveModBisectSession = useCallback(() => {
+    if (!summary?.id) return;
+    InstanceService.retrieveModBisectSession(summary.id).then((response) => {
+      if (response.status === "success") setSession(response.data);
+    });
+  }, [summary?.id]);
+
+  useEffect(() => {
+    handleRetrieveModBisectSession();
+  }, [handleRetrieveModBisectSession]);
+
+  const handleResponse = (
+    response: InvokeResponse<unknown>,
+    withSuccessToast: boolean
+  ) => {
+    if (response.status === "success") {
+      if (withSuccessToast) {
+        toast({ title: response.message, status: "success" });
+      }
+    } else {
+      toast({
+        title: response.message,
+        description: response.details,
+        status: "error",
+      });
+    }
+    handleRetrieveModBisectSession();
+    onModsChanged();
+  };
+
+  const handleStartModBisect = () => {
+    if (!summary?.id) return;
+    setIsSubmitting(true);
+    InstanceService.startModBisect(summary.id)
+      .then((response) => handleResponse(response, true))
+      .finally(() => setIsSubmitting(false));
+  };
+
+  const handleSubmitModBisectResult = (reachedReady: boolean) => {
+    if (!summary?.id) return;
+    setIsSubmitting(true);
+    InstanceService.submitModBisectResult(summary.id, reachedReady)
+      .then((response) => handleResponse(response, false))
+      .finally(() => setIsSubmitting(false));
+  };
+
+  const handleStopModBisect = () => {
+    if (!summary?.id) return;
+    setIsSubmitting(true);
+    InstanceService.stopModBisect(summary.id)
+      .then((response) => handleResponse(response, true))
+      .finally(() => setIsSubmitting(false));
+  };
+
+  const handleLaunch = (safeMode: boolean) => {
+    openSharedModal("launch", {
+      instanceId: summary?.id,
+      safeMode,
+      modBisect: !safeMode,
+    });
+  };
+
+  // ask the user to confirm the result, the exit status is only a hint
+  useEffect(() => {
+    const unlisten = LaunchService.onModBisectRunExited(
+      ({ instanceId, exitOk }) => {
+        if (instanceId !== summary?.id) return;
+        openGenericConfirmDialog({
+          title: t("ModBisectSection.runExitedDialog.title"),
+          body: t(
+            `ModBisectSection.runExitedDialog.${exitOk ? "worked" : "crashed"}`
+          ),
+          btnOK: t(`ModBisectSection.button.${exitOk ? "worked" : "crashed"}`),
+          btnCancel: t("ModBisectSection.runExitedDialog.later"),
+          onOKCallback: () => handleSubmitModBisectResult(exitOk),
+        });
+      }
+    );
+    return () => unlisten();
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [summary?.id]);
+
+  const inProgress = !!session && !session.finished;
+  const currentStep = inProgress
+    ? session.steps[session.steps.length - 1]
+    : null;
+
+  const renderSessionStatus = () => {
+    if (!session) {
+      return (
+        <Text fontSize="xs" className="secondary-text">
+          {t("ModBisectSection.label.idle")}
+        </Text>
+      );
+    }
+    if (session.finished) {
+      return session.culprit ? (
+        <VStack spacing={0} align="flex-start">
+          <Text fontSize="xs-sm">
+            {t("ModBisectSection.label.culprit", {
+              modId: session.culprit.modId,
+            })}
+          </Text>
+          <Text fontSize="xs" className="secondary-text">
+            {session.culprit.files.join(", ")}
+          </Text>
+        </VStack>
+      ) : (
+        <Text fontSize="xs" className="secondary-text">
+          {t("ModBisectSection.label.noCulprit")}
+        </Text>
+      );
+    }
+    return (
+      <VStack spacing={0} align="flex-start">
+        <Text fontSize="xs-sm">
+          {t("ModBisectSection.label.progress", {
+            step: session.steps.length,
+            suspects: session.suspects.length,
+          })}
+        </Text>
+        <Text fontSize="xs" className="secondary-text">
+          {t("ModBisectSection.label.disabledNow", {
+            mods:
+              currentStep?.disabledUnits
+                .map((index) => session.units[index]?.modId)
+                .join(", ") || "-",
+          })}
+        </Text>
+      </VStack>
+    );
+  };
+
+  return (
+    <Section
+      title={t("ModBisectSection.title")}
+      headExtra={
+        <Button
+          leftIcon={<LuShieldCheck />}
+          size="xs"
+          variant="ghost"
+          onClick={() => handleLaunch(true)}
+        >
+          {t("ModBisectSection.button.safeModeLaunch")}
+        </Button>
+      }
+    >
+      <OptionItemGroup
+        items={[
+          <OptionItem
+            key="bisect"
+            title={
+              <HStack>
+                <Text fontSize="xs-sm">
+                  {t("ModBisectSection.label.bisect")}
+                </Text>
+                {inProgress && (
+                  <Tag colorScheme={primaryColor} className="tag-xs">
+                    {t("ModBisectSection.label.inProgress")}
+                  </Tag>
+                )}
+              </HStack>
+            }
+            description={renderSessionStatus()}
+          >
+            {inProgress ? (
+              <HStack spacing={2}>
+                <Button
+                  size="xs"
+                  leftIcon={<LuPlay />}
+                  colorScheme={primaryColor}
+                  onClick={() => handleLaunch(false)}
+                >
+                  {t("ModBisectSection.button.launch")}
+                </Button>
+                <Button
+                  size="xs"
+                  variant="outline"
+                  leftIcon={<LuCircleCheck />}
+                  isDisabled={isSubmitting}
+                  onClick={() => handleSubmitModBisectResult(true)}
+                >
+                  {t("ModBisectSection.button.worked")}
+                </Button>
+                <Button
+                  size="xs"
+                  variant="outline"
+                  leftIcon={<LuCircleX />}
+                  isDisabled={isSubmitting}
+                  onClick={() => handleSubmitModBisectResult(false)}
+                >
+                  {t("ModBisectSection.button.crashed")}
+                </Button>
+                <Button
+                  size="xs"
+                  variant="ghost"
+                  colorScheme="red"
+                  isDisabled={isSubmitting}
+                  onClick={handleStopModBisect}
+                >
+                  {t("ModBisectSection.button.stop")}
+                </Button>
+              </HStack>
+            ) : (
+              <HStack spacing={2}>
+                {session?.finished && (
+                  <Button
+                    size="xs"
+                    variant="ghost"
+                    isDisabled={isSubmitting}
+                    onClick={handleStopModBisect}
+                  >
+                    {t("ModBisectSection.button.clear")}
+                  </Button>
+                )}
+                <Button
+                  size="xs"
+                  colorScheme={primaryColor}
+                  isLoading={isSubmitting}
+                  onClick={handleStartModBisect}
+                >
+                  {t("ModBisectSection.button.start")}
+                </Button>
+              </HStack>
+            )}
+          </OptionItem>,
+        ]}
+      />
+    </Section>
+  );
+};
+
+export default ModBisectSection;
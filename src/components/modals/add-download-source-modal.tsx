@@ -0,0 +1,148 @@
+import {
+  Button,
+  FormControl,
+  FormHelperText,
+  FormLabel,
+  HStack,
+  Input,
+  Modal,
+  ModalBody,
+  ModalCloseButton,
+  ModalContent,
+  ModalFooter,
+  ModalHeader,
+  ModalOverlay,
+  ModalProps,
+  Text,
+  VStack,
+} from "@chakra-ui/react";
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { useLauncherConfig } from "@/contexts/config";
+
+// resource types that can be mirrored, with the official base url as the placeholder
+const mirrorableResourceTypes: Record<string, string> = {
+  VersionManifest:
+    "https://launchermeta.mojang.com/mc/game/version_manifest.json",
+  LauncherMeta: "https://launchermeta.mojang.com/",
+  Launcher: "https://launcher.mojang.com/",
+  Assets: "https://resources.download.minecraft.net/",
+  Libraries: "https://libraries.minecraft.net/",
+  ForgeMaven: "https://files.minecraftforge.net/maven/",
+  ForgeMavenNew: "https://maven.minecraftforge.net",
+  ForgeInstall: "https://maven.minecraftforge.net/net/minecraftforge/forge/",
+  NeoforgeMaven: "https://maven.neoforged.net/releases/",
+  FabricMeta: "https://meta.fabricmc.net/",
+  FabricMaven: "https://maven.fabricmc.net/",
+  QuiltMeta: "https://meta.quiltmc.org/",
+  QuiltMaven: "https://maven.quiltmc.org/repository/release/",
+  AuthlibInjector: "https://authlib-injector.yushi.moe/",
+};
+
+const AddDownloadSourceModal: React.FC<Omit<ModalProps, "children">> = ({
+  ...props
+}) => {
+  const { t } = useTranslation();
+  const { config, update } = useLauncherConfig();
+  const primaryColor = config.appearance.theme.primaryColor;
+  const [name, setName] = useState<string>("");
+  const [baseUrls, setBaseUrls] = useState<Record<string, string>>({});
+
+  const trimmedBaseUrls = Object.fromEntries(
+    Object.entries(baseUrls)
+      .map(([type, url]) => [type, url.trim()])
+      .filter(([, url]) => url)
+  );
+  const isValid =
+    !!name.trim() &&
+    Object.keys(trimmedBaseUrls).length > 0 &&
+    Object.values(trimmedBaseUrls).every((url) => URL.canParse(url));
+
+  const handleCloseModal = () => {
+    setName("");
+    setBaseUrls({});
+    props.onClose();
+  };
+
+  const handleConfirm = () => {
+    if (!isValid) return;
+    const updated = [
+      ...config.download.source.customSources,
+      { name: name.trim(), enabled: true, baseUrls: trimmedBaseUrls },
+    ];
+    update("download.source.customSources", updated);
+    handleCloseModal();
+  };
+
+  return (
+    <Modal
+      scrollBehavior="inside"
+      size="xl"
+      {...props}
+      onClose={handleCloseModal}
+    >
+      <ModalOverlay />
+      <ModalContent>
+        <ModalHeader>{t("AddDownloadSourceModal.modal.header")}</ModalHeader>
+        <ModalCloseButton />
+        <ModalBody>
+          <VStack spacing={4} align="stretch">
+            <FormControl isRequired>
+              <FormLabel>{t("AddDownloadSourceModal.label.name")}</FormLabel>
+              <Input
+                value={name}
+                onChange={(e) => setName(e.target.value)}
+                placeholder={t("AddDownloadSourceModal.placeholder.name")}
+                focusBorderColor={`${primaryColor}.500`}
+                required
+              />
+            </FormControl>
+            <FormControl isRequired>
+              <FormLabel>{t("AddDownloadSourceModal.label.baseUrls")}</FormLabel>
+              <VStack spacing={2} align="stretch">
+                {Object.entries(mirrorableResourceTypes).map(
+                  ([type, officialUrl]) => (
+                    <HStack key={type}>
+                      <Text fontSize="xs-sm" minW={32} flexShrink={0}>
+                        {type}
+                      </Text>
+                      <Input
+                        size="sm"
+                        value={baseUrls[type] || ""}
+                        onChange={(e) =>
+                          setBaseUrls((prev) => ({
+                            ...prev,
+                            [type]: e.target.value,
+                          }))
+                        }
+                        placeholder={officialUrl}
+                        focusBorderColor={`${primaryColor}.500`}
+                      />
+                    </HStack>
+                  )
+                )}
+              </VStack>
+              <FormHelperText>
+                {t("AddDownloadSourceModal.helper.baseUrls")}
+              </FormHelperText>
+            </FormControl>
+          </VStack>
+        </ModalBody>
+        <ModalFooter>
+          <Button variant="ghost" onClick={handleCloseModal}>
+            {t("General.cancel")}
+          </Button>
+          <Button
+            colorScheme={primaryColor}
+            onClick={handleConfirm}
+            isDisabled={!isValid}
+          >
+            {t("General.confirm")}
+          </Button>
+        </ModalFooter>
+      </ModalContent>
+    </Modal>
+  );
+};
+
+export default AddDownloadSourceModal;
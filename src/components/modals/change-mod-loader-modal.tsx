@@ -21,9 +21,14 @@ import { useEffect, useMemo, useState } from "react";
 import { useTranslation } from "react-i18next";
 import { LuArrowRight } from "react-icons/lu";
 import { OptionItem } from "@/components/common/option-item";
-import { LoaderSelector } from "@/components/loader-selector";
+import {
+  LoaderSelector,
+  modLoaderTypesToIcon,
+} from "@/components/loader-selector";
 import { useLauncherConfig } from "@/contexts/config";
+import { useGlobalData } from "@/contexts/global-data";
 import { useInstanceSharedData } from "@/contexts/instance";
+import { useSharedModals } from "@/contexts/shared-modal";
 import { useToast } from "@/contexts/toast";
 import { ModLoaderType } from "@/enums/instance";
 import {
@@ -47,11 +52,14 @@ export const ChangeModLoaderModal: React.FC<ChangeModLoaderModalProps> = ({
   const primaryColor = config.appearance.theme.primaryColor;
   const toast = useToast();
   const router = useRouter();
+  const { getInstanceList } = useGlobalData();
+  const { openGenericConfirmDialog } = useSharedModals();
 
   const [selectedModLoader, setSelectedModLoader] =
     useState<ModLoaderResourceInfo>(defaultModLoaderResourceInfo);
   const [isLoading, setIsLoading] = useState(false);
   const [isInstallFabricApi, setIsInstallFabricApi] = useState(true);
+  const [isMoveModsAside, setIsMoveModsAside] = useState(true);
 
   useEffect(() => {
     if (defaultSelectedType && defaultSelectedType !== ModLoaderType.Unknown) {
@@ -104,6 +112,34 @@ export const ChangeModLoaderModal: React.FC<ChangeModLoaderModalProps> = ({
     }
   };
 
+  const handleUninstallModLoader = async () => {
+    if (!summary?.id) return;
+    setIsLoading(true);
+
+    try {
+      const res = await InstanceService.changeModLoader(
+        summary.id,
+        defaultModLoaderResourceInfo,
+        false,
+        isMoveModsAside
+      );
+
+      if (res.status === "error") {
+        toast({
+          title: res.message,
+          status: "error",
+          description: res.details,
+        });
+      } else {
+        toast({ title: res.message, status: "success" });
+        modalProps.onClose?.();
+        getInstanceList(true);
+      }
+    } finally {
+      setIsLoading(false);
+    }
+  };
+
   const isUnselected =
     !selectedModLoader.version ||
     selectedModLoader.loaderType === ModLoaderType.Unknown;
@@ -137,7 +173,7 @@ export const ChangeModLoaderModal: React.FC<ChangeModLoaderModalProps> = ({
                 <OptionItem
                   prefixElement={
                     <Image
-                      src={`/images/icons/${currentModLoader.loaderType}.png`}
+                      src={`/images/icons/${modLoaderTypesToIcon[currentModLoader.loaderType]}`}
                       alt={currentModLoader.loaderType}
                       boxSize="36px"
                       borderRadius="md"
@@ -174,7 +210,7 @@ export const ChangeModLoaderModal: React.FC<ChangeModLoaderModalProps> = ({
                   <OptionItem
                     prefixElement={
                       <Image
-                        src={`/images/icons/${selectedModLoader.loaderType}.png`}
+                        src={`/images/icons/${modLoaderTypesToIcon[selectedModLoader.loaderType]}`}
                         alt={selectedModLoader.loaderType}
                         boxSize="36px"
                         borderRadius="md"
@@ -210,7 +246,8 @@ export const ChangeModLoaderModal: React.FC<ChangeModLoaderModalProps> = ({
             )}
           </ModalBody>
           <ModalFooter>
-            {selectedModLoader.loaderType === ModLoaderType.Fabric && (
+            {(selectedModLoader.loaderType === ModLoaderType.Fabric ||
+              selectedModLoader.loaderType === ModLoaderType.Quilt) && (
               <Checkbox
                 colorScheme={primaryColor}
                 isChecked={
@@ -220,11 +257,45 @@ export const ChangeModLoaderModal: React.FC<ChangeModLoaderModalProps> = ({
                 onChange={(e) => setIsInstallFabricApi(e.target.checked)}
               >
                 <Text fontSize="sm">
-                  {t("ChangeModLoaderModal.footer.installFabricApi")}
+                  {t(
+                    `ChangeModLoaderModal.footer.${selectedModLoader.loaderType === ModLoaderType.Quilt ? "installQuiltedFabricApi" : "installFabricApi"}`
+                  )}
                 </Text>
               </Checkbox>
             )}
+            {currentModLoader.loaderType !== ModLoaderType.Unknown &&
+              selectedModLoader.loaderType === ModLoaderType.Unknown && (
+                <Checkbox
+                  colorScheme={primaryColor}
+                  isChecked={isMoveModsAside}
+                  onChange={(e) => setIsMoveModsAside(e.target.checked)}
+                >
+                  <Text fontSize="sm">
+                    {t("ChangeModLoaderModal.footer.moveModsAside")}
+                  </Text>
+                </Checkbox>
+              )}
             <HStack spacing={3} ml="auto">
+              {currentModLoader.loaderType !== ModLoaderType.Unknown && (
+                <Button
+                  variant="ghost"
+                  colorScheme="red"
+                  isDisabled={isLoading}
+                  onClick={() =>
+                    openGenericConfirmDialog({
+                      title: t("ChangeModLoaderModal.uninstallDialog.title"),
+                      body: t("ChangeModLoaderModal.uninstallDialog.content", {
+                        loader: currentModLoader.loaderType,
+                      }),
+                      btnOK: t("ChangeModLoaderModal.button.uninstall"),
+                      isAlert: true,
+                      onOKCallback: handleUninstallModLoader,
+                    })
+                  }
+                >
+                  {t("ChangeModLoaderModal.button.uninstall")}
+                </Button>
+              )}
               <Button variant="ghost" onClick={modalProps.onClose}>
                 {t("General.cancel")}
               </Button>
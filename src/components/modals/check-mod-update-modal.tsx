@@ -18,16 +18,20 @@ import {
 } from "@chakra-ui/react";
 import { useCallback, useEffect, useState } from "react";
 import { useTranslation } from "react-i18next";
+import { LuHistory, LuUndo2 } from "react-icons/lu";
+import { CommonIconButton } from "@/components/common/common-icon-button";
+import Empty from "@/components/common/empty";
+import { OptionItem, OptionItemGroup } from "@/components/common/option-item";
 import { useLauncherConfig } from "@/contexts/config";
-import { ModLoaderType } from "@/enums/instance";
-import { OtherResourceSource } from "@/enums/resource";
+import { useToast } from "@/contexts/toast";
 import { InstanceSummary, LocalModInfo } from "@/models/instance/misc";
 import {
+  ModUpdateHistoryEntry,
   ModUpdateQuery,
   ModUpdateRecord,
-  OtherResourceFileInfo,
 } from "@/models/resource";
 import { ResourceService } from "@/services/resource";
+import { UNIXToISOString, formatRelativeTime } from "@/utils/datetime";
 
 interface CheckModUpdateModalProps extends Omit<ModalProps, "children"> {
   summary: InstanceSummary | undefined;
@@ -45,12 +49,16 @@ const CheckModUpdateModal: React.FC<CheckModUpdateModalProps> = ({
   const addPrefix =
     config.general.general.language === "zh-Hans" &&
     config.general.functionality.translatedFilenamePrefix;
+  const toast = useToast();
 
   const [selectedMods, setSelectedMods] = useState<ModUpdateRecord[]>([]);
   const [isCheckingUpdate, setIsCheckingUpdate] = useState<boolean>(true);
   const [updateList, setUpdateList] = useState<ModUpdateRecord[]>([]);
   const [modsToUpdate, setModsToUpdate] = useState<LocalModInfo[]>([]);
-  const [checkingUpdateIndex, setCheckingUpdateIndex] = useState<number>(1);
+  const [showHistory, setShowHistory] = useState<boolean>(false);
+  const [updateHistory, setUpdateHistory] = useState<ModUpdateHistoryEntry[]>(
+    []
+  );
 
   const headerBg = useColorModeValue("gray.50", "gray.800");
   const borderColor = useColorModeValue("gray.200", "gray.600");
@@ -84,51 +92,10 @@ const CheckModUpdateModal: React.FC<CheckModUpdateModalProps> = ({
     setUpdateList([]);
     setModsToUpdate([]);
     setSelectedMods([]);
-    setCheckingUpdateIndex(1);
+    setShowHistory(false);
+    setUpdateHistory([]);
   }, []);
 
-  const handleFetchLatestMod = useCallback(
-    async (
-      resourceId: string,
-      modLoader: ModLoaderType | "All",
-      gameVersions: string[],
-      downloadSource: OtherResourceSource
-    ): Promise<OtherResourceFileInfo | undefined> => {
-      try {
-        const response = await ResourceService.fetchResourceVersionPacks(
-          resourceId,
-          modLoader,
-          gameVersions,
-          downloadSource
-        );
-
-        if (response.status === "success") {
-          const versionPack = response.data.find(
-            (pack) => pack.name === summary?.version
-          );
-
-          if (!versionPack) return undefined;
-
-          const candidateFiles = versionPack.items.filter(
-            (file) =>
-              file.releaseType === "beta" || file.releaseType === "release"
-          );
-
-          candidateFiles.sort(
-            (a, b) =>
-              new Date(b.fileDate).getTime() - new Date(a.fileDate).getTime()
-          );
-
-          return candidateFiles[0];
-        } else return undefined;
-      } catch (error) {
-        logger.error("Failed to fetch latest mod:", error);
-        return undefined;
-      }
-    },
-    [summary?.version]
-  );
-
   const handleCheckModUpdate = useCallback(async () => {
     const currentSummary = summary;
     const currentLocalMods = localMods;
@@ -140,123 +107,39 @@ const CheckModUpdateModal: React.FC<CheckModUpdateModalProps> = ({
         return;
       }
 
-      const updatePromises = currentLocalMods.map(async (mod) => {
-        try {
-          const [cfRemoteModRes, mrRemoteModRes] = await Promise.all([
-            ResourceService.fetchRemoteResourceByLocal(
-              OtherResourceSource.CurseForge,
-              mod.filePath
-            ),
-            ResourceService.fetchRemoteResourceByLocal(
-              OtherResourceSource.Modrinth,
-              mod.filePath
-            ),
-          ]);
-
-          let cfRemoteMod = undefined;
-          let mrRemoteMod = undefined;
-
-          if (cfRemoteModRes.status === "success") {
-            cfRemoteMod = cfRemoteModRes.data;
-          }
-          if (mrRemoteModRes.status === "success") {
-            mrRemoteMod = mrRemoteModRes.data;
-          }
-
-          const updatePromises = [];
-
-          if (cfRemoteMod?.resourceId) {
-            updatePromises.push(
-              handleFetchLatestMod(
-                cfRemoteMod.resourceId,
-                mod.loaderType,
-                [currentSummary?.majorVersion || "All"],
-                OtherResourceSource.CurseForge
-              )
-            );
-          } else {
-            updatePromises.push(Promise.resolve(undefined));
-          }
-
-          if (mrRemoteMod?.resourceId) {
-            updatePromises.push(
-              handleFetchLatestMod(
-                mrRemoteMod.resourceId,
-                mod.loaderType,
-                [currentSummary?.version || "All"],
-                OtherResourceSource.Modrinth
-              )
-            );
-          } else {
-            updatePromises.push(Promise.resolve(undefined));
-          }
-
-          const [cfRemoteFile, mrRemoteFile] =
-            await Promise.all(updatePromises);
-
-          let isCurseForgeNewer = cfRemoteMod !== undefined;
-          if (cfRemoteFile && mrRemoteFile) {
-            isCurseForgeNewer =
-              new Date(cfRemoteFile.fileDate).getTime() >
-              new Date(mrRemoteFile.fileDate).getTime();
-          }
-
-          const latestFile = isCurseForgeNewer ? cfRemoteFile : mrRemoteFile;
-          const remoteMod = isCurseForgeNewer ? cfRemoteMod : mrRemoteMod;
-
-          let needUpdate = false;
-          if (latestFile && remoteMod) {
-            needUpdate =
-              new Date(latestFile.fileDate).getTime() -
-                new Date(remoteMod.fileDate).getTime() >
-              0;
-          }
-
-          if (needUpdate && latestFile) {
-            return {
-              mod,
-              updateRecord: {
-                name: mod.name,
-                curVersion: mod.version,
-                newVersion: latestFile.name,
-                source: isCurseForgeNewer
-                  ? OtherResourceSource.CurseForge
-                  : OtherResourceSource.Modrinth,
-                downloadUrl: latestFile.downloadUrl,
-                sha1: latestFile.sha1,
-                fileName: latestFile.fileName,
-              },
-            };
-          }
-          return null;
-        } catch (error) {
-          logger.error(`Failed to check update for mod ${mod.name}:`, error);
-          return null;
-        }
-      });
-
-      const results: any[] = [];
-
-      await Promise.allSettled(
-        updatePromises.map(async (p) => {
-          const res = await p;
-          setCheckingUpdateIndex(results.length + 1);
-          results.push(res);
-        })
+      const response = await ResourceService.checkModUpdates(
+        currentSummary.id
       );
+      if (response.status !== "success") return;
 
-      const validUpdates = results.filter(
-        (result): result is NonNullable<typeof result> => result !== null
-      );
+      const mods: LocalModInfo[] = [];
+      const records: ModUpdateRecord[] = [];
+      for (const update of response.data) {
+        const mod = currentLocalMods.find(
+          (m) => m.filePath === update.oldFilePath
+        );
+        if (!mod) continue;
+        mods.push(mod);
+        records.push({
+          name: update.name,
+          curVersion: update.curVersion,
+          newVersion: update.file.name,
+          source: update.source,
+          downloadUrl: update.file.downloadUrl,
+          sha1: update.file.sha1,
+          fileName: update.file.fileName,
+          changelog: update.changelog,
+        });
+      }
 
-      setModsToUpdate(validUpdates.map((item) => item.mod));
-      setUpdateList(validUpdates.map((item) => item.updateRecord));
+      setModsToUpdate(mods);
+      setUpdateList(records);
     } catch (error) {
       logger.error("Failed to check mod updates:", error);
     } finally {
       setIsCheckingUpdate(false);
     }
-  }, [summary, localMods, handleFetchLatestMod, onCheckUpdateModalClear]);
+  }, [summary, localMods, onCheckUpdateModalClear]);
 
   const handleDownloadUpdatedMods = useCallback(
     async (urlShaPairs: { url: string; sha1: string; fileName: string }[]) => {
@@ -290,6 +173,44 @@ const CheckModUpdateModal: React.FC<CheckModUpdateModalProps> = ({
     [summary?.id, modsToUpdate, updateList, addPrefix]
   );
 
+  const handleRetrieveModUpdateHistory = useCallback(() => {
+    if (!summary?.id) return;
+    ResourceService.retrieveModUpdateHistory(summary.id).then((response) => {
+      if (response.status === "success") {
+        // show the latest update first
+        setUpdateHistory([...response.data].reverse());
+      } else {
+        setUpdateHistory([]);
+        toast({
+          title: response.message,
+          description: response.details,
+          status: "error",
+        });
+      }
+    });
+  }, [summary?.id, toast]);
+
+  const handleRollbackModUpdate = (entryId: string) => {
+    if (!summary?.id) return;
+    ResourceService.rollbackModUpdate(summary.id, entryId).then((response) => {
+      if (response.status === "success") {
+        toast({ title: response.message, status: "success" });
+      } else {
+        toast({
+          title: response.message,
+          description: response.details,
+          status: "error",
+        });
+      }
+      handleRetrieveModUpdateHistory();
+    });
+  };
+
+  const handleToggleHistory = () => {
+    if (!showHistory) handleRetrieveModUpdateHistory();
+    setShowHistory(!showHistory);
+  };
+
   useEffect(() => {
     if (modalProps.isOpen && summary?.id && localMods.length > 0) {
       handleCheckModUpdate();
@@ -312,7 +233,18 @@ const CheckModUpdateModal: React.FC<CheckModUpdateModalProps> = ({
       <ModalContent h="100%">
         <ModalHeader>
           <HStack w="100%" justify="flex-start" align="center">
-            <Text>{t("CheckModUpdateModal.header.title")}</Text>
+            <Text>
+              {t(
+                `CheckModUpdateModal.header.${showHistory ? "history" : "title"}`
+              )}
+            </Text>
+            <CommonIconButton
+              icon={LuHistory}
+              label={t(
+                `CheckModUpdateModal.button.${showHistory ? "back" : "history"}`
+              )}
+              onClick={handleToggleHistory}
+            />
           </HStack>
         </ModalHeader>
         <ModalCloseButton />
@@ -323,20 +255,50 @@ const CheckModUpdateModal: React.FC<CheckModUpdateModalProps> = ({
           flexDirection="column"
           overflow="hidden"
         >
-          {isCheckingUpdate ? (
+          {showHistory ? (
+            updateHistory.length > 0 ? (
+              <Box flex="1" overflowY="auto">
+                <OptionItemGroup
+                  items={updateHistory.map((entry) => (
+                    <OptionItem
+                      key={entry.id}
+                      title={formatRelativeTime(
+                        UNIXToISOString(entry.time),
+                        t
+                      )}
+                      description={
+                        <VStack spacing={0} align="flex-start">
+                          {entry.items.map((item) => (
+                            <Text
+                              key={item.oldFileName}
+                              fontSize="xs"
+                              className="secondary-text"
+                            >
+                              {`${item.oldFileName} → ${item.newFileName}`}
+                            </Text>
+                          ))}
+                        </VStack>
+                      }
+                    >
+                      <CommonIconButton
+                        icon={LuUndo2}
+                        label={t("CheckModUpdateModal.button.rollback")}
+                        onClick={() => handleRollbackModUpdate(entry.id)}
+                      />
+                    </OptionItem>
+                  ))}
+                />
+              </Box>
+            ) : (
+              <Empty withIcon={false} size="sm" />
+            )
+          ) : isCheckingUpdate ? (
             <VStack spacing={4} justify="center" align="center" h="75%">
               <Text fontSize="md" color={textColor}>
-                {t("CheckModUpdateModal.label.loading", {
-                  x: checkingUpdateIndex,
-                  y: localMods.length,
-                })}
+                {t("CheckModUpdateModal.label.loading")}
               </Text>
               <Progress
-                value={
-                  localMods.length > 0
-                    ? (checkingUpdateIndex / localMods.length) * 100
-                    : 0
-                }
+                isIndeterminate
                 size="md"
                 colorScheme={primaryColor}
                 w="80%"
@@ -452,7 +414,11 @@ const CheckModUpdateModal: React.FC<CheckModUpdateModalProps> = ({
                           fontSize="xs"
                           color={successTextColor}
                           noOfLines={1}
-                          title={mod.newVersion}
+                          title={
+                            mod.changelog
+                              ? mod.changelog.replace(/<[^>]+>/g, "")
+                              : mod.newVersion
+                          }
                           textAlign="center"
                         >
                           {mod.newVersion}
@@ -477,7 +443,7 @@ const CheckModUpdateModal: React.FC<CheckModUpdateModalProps> = ({
           )}
         </ModalBody>
 
-        {!isCheckingUpdate && updateList.length > 0 && (
+        {!showHistory && !isCheckingUpdate && updateList.length > 0 && (
           <ModalFooter flexShrink={0}>
             <HStack spacing={3}>
               <Button variant="ghost" onClick={modalProps.onClose}>
@@ -56,6 +56,9 @@ export const loaderTypesToIcon: Record<string, string> = {
   Fabric: "/images/icons/Fabric.png",
   Forge: "/images/icons/Anvil.png", // differ from that in mod-loader-selector
   NeoForge: "/images/icons/NeoForge.png",
+  Quilt: "/images/icons/Quilt.png",
+  LiteLoader: "/images/icons/LiteLoader.png",
+  Cleanroom: "/images/icons/Anvil.png",
   OptiFine: "/images/icons/OptiFine.png",
 };
 
@@ -80,6 +83,9 @@ export const CreateInstanceModal: React.FC<Omit<ModalProps, "children">> = ({
   const [selectedOptiFine, setSelectedOptiFine] = useState<
     OptiFineResourceInfo | undefined
   >(undefined);
+  const [selectedLiteLoader, setSelectedLiteLoader] = useState<
+    ModLoaderResourceInfo | undefined
+  >(undefined);
   const [instanceName, setInstanceName] = useState("");
   const [instanceDescription, setInstanceDescription] = useState("");
   const [instanceIconSrc, setInstanceIconSrc] = useState("");
@@ -89,6 +95,7 @@ export const CreateInstanceModal: React.FC<Omit<ModalProps, "children">> = ({
 
   useEffect(() => {
     setSelectedModLoader(defaultModLoaderResourceInfo);
+    setSelectedLiteLoader(undefined);
     setInstanceName("");
     setInstanceDescription("");
     setInstanceIconSrc(
@@ -110,7 +117,9 @@ export const CreateInstanceModal: React.FC<Omit<ModalProps, "children">> = ({
       selectedModLoader,
       selectedOptiFine,
       undefined, // modpackPath
-      isInstallFabricApi
+      isInstallFabricApi,
+      undefined, // modpackOptionalFiles
+      selectedLiteLoader
     )
       .then((res) => {
         if (res.status === "success") {
@@ -134,6 +143,7 @@ export const CreateInstanceModal: React.FC<Omit<ModalProps, "children">> = ({
     instanceIconSrc,
     selectedModLoader,
     selectedOptiFine,
+    selectedLiteLoader,
     isInstallFabricApi,
     modalProps,
     router,
@@ -178,11 +188,14 @@ export const CreateInstanceModal: React.FC<Omit<ModalProps, "children">> = ({
               onSelectModLoader={setSelectedModLoader}
               selectedOptiFine={selectedOptiFine}
               onSelectOptiFine={setSelectedOptiFine}
+              selectedLiteLoader={selectedLiteLoader}
+              onSelectLiteLoader={setSelectedLiteLoader}
             />
           </ModalBody>
           <ModalFooter>
-            {/* Fabric API download option - only show when Fabric is selected and has version */}
-            {selectedModLoader.loaderType === ModLoaderType.Fabric && (
+            {/* (Quilted) Fabric API download option - only show when Fabric or Quilt is selected and has version */}
+            {(selectedModLoader.loaderType === ModLoaderType.Fabric ||
+              selectedModLoader.loaderType === ModLoaderType.Quilt) && (
               <Checkbox
                 colorScheme={primaryColor}
                 isChecked={
@@ -192,7 +205,9 @@ export const CreateInstanceModal: React.FC<Omit<ModalProps, "children">> = ({
                 onChange={(e) => setIsInstallFabricApi(e.target.checked)}
               >
                 <Text fontSize="sm">
-                  {t("CreateInstanceModal.footer.installFabricApi")}
+                  {t(
+                    `CreateInstanceModal.footer.${selectedModLoader.loaderType === ModLoaderType.Quilt ? "installQuiltedFabricApi" : "installFabricApi"}`
+                  )}
                 </Text>
               </Checkbox>
             )}
@@ -210,6 +225,7 @@ export const CreateInstanceModal: React.FC<Omit<ModalProps, "children">> = ({
                   if (!selectedModLoader.version) {
                     // if the user selected the loader but did not choose a version from the list
                     setSelectedModLoader(defaultModLoaderResourceInfo);
+                    setSelectedLiteLoader(undefined);
                     setInstanceName(selectedGameVersion.id);
                     setInstanceIconSrc(
                       gameTypesToIcon[selectedGameVersion.gameType]
@@ -223,6 +239,10 @@ export const CreateInstanceModal: React.FC<Omit<ModalProps, "children">> = ({
                     );
                   }
 
+                  if (selectedLiteLoader && selectedModLoader.version) {
+                    setInstanceName((prev) => `${prev}-LiteLoader`);
+                  }
+
                   if (selectedOptiFine) {
                     if (!selectedOptiFine.filename) {
                       // if the user selected OptiFine but did not choose a version from the list
@@ -246,6 +266,7 @@ export const CreateInstanceModal: React.FC<Omit<ModalProps, "children">> = ({
     selectedGameVersion,
     selectedModLoader,
     selectedOptiFine,
+    selectedLiteLoader,
     primaryColor,
     isInstallFabricApi,
     t,
@@ -321,6 +342,9 @@ export const CreateInstanceModal: React.FC<Omit<ModalProps, "children">> = ({
               parseModLoaderVersion(selectedModLoader.version) ||
               t("LoaderSelector.noVersionSelected")
             }`;
+            if (selectedLiteLoader) {
+              desc += ` + LiteLoader`;
+            }
             if (selectedOptiFine) {
               desc += ` + OptiFine`;
             }
@@ -343,6 +367,7 @@ export const CreateInstanceModal: React.FC<Omit<ModalProps, "children">> = ({
       selectedModLoader.loaderType,
       selectedModLoader.version,
       selectedOptiFine,
+      selectedLiteLoader,
     ]
   );
 
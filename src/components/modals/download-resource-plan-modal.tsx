@@ -0,0 +1,221 @@
+import {
+  Avatar,
+  Button,
+  HStack,
+  Modal,
+  ModalBody,
+  ModalCloseButton,
+  ModalContent,
+  ModalFooter,
+  ModalHeader,
+  ModalOverlay,
+  ModalProps,
+  Tag,
+  Text,
+  VStack,
+} from "@chakra-ui/react";
+import { useCallback, useEffect, useMemo, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { BeatLoader } from "react-spinners";
+import Empty from "@/components/common/empty";
+import { OptionItem } from "@/components/common/option-item";
+import { useLauncherConfig } from "@/contexts/config";
+import { useToast } from "@/contexts/toast";
+import { OtherResourceSource } from "@/enums/resource";
+import {
+  OtherResourceFileInfo,
+  ResourceDependencyPlanItem,
+} from "@/models/resource";
+import { ResourceService } from "@/services/resource";
+
+interface DownloadResourcePlanModalProps extends Omit<ModalProps, "children"> {
+  instanceId: string;
+  downloadSource: OtherResourceSource;
+  file: OtherResourceFileInfo;
+  resourceName: string;
+  onDownload: (dependencies: OtherResourceFileInfo[]) => void;
+}
+
+const DownloadResourcePlanModal: React.FC<DownloadResourcePlanModalProps> = ({
+  instanceId,
+  downloadSource,
+  file,
+  resourceName,
+  onDownload,
+  ...modalProps
+}) => {
+  const { t } = useTranslation();
+  const { config } = useLauncherConfig();
+  const primaryColor = config.appearance.theme.primaryColor;
+  const toast = useToast();
+
+  const [plan, setPlan] = useState<ResourceDependencyPlanItem[]>([]);
+  const [isLoading, setIsLoading] = useState<boolean>(true);
+
+  const handleResolveDependencies = useCallback(() => {
+    setIsLoading(true);
+    ResourceService.resolveResourceDependencies(
+      instanceId,
+      downloadSource,
+      file
+    )
+      .then((response) => {
+        if (response.status === "success") {
+          setPlan(response.data);
+        } else {
+          setPlan([]);
+          toast({
+            title: response.message,
+            description: response.details,
+            status: "error",
+          });
+        }
+      })
+      .finally(() => setIsLoading(false));
+  }, [instanceId, downloadSource, file, toast]);
+
+  useEffect(() => {
+    handleResolveDependencies();
+  }, [handleResolveDependencies]);
+
+  const dependencyFiles = useMemo(
+    () =>
+      plan
+        .filter((item) => !item.installed && item.file)
+        .map((item) => item.file as OtherResourceFileInfo),
+    [plan]
+  );
+
+  const getResourceName = (resourceId: string) => {
+    if (resourceId === file.resourceId) return resourceName;
+    return (
+      plan.find((item) => item.resource.id === resourceId)?.resource.name ||
+      resourceId
+    );
+  };
+
+  const renderPlanItem = (item: ResourceDependencyPlanItem) => {
+    const { resource } = item;
+    return (
+      <OptionItem
+        key={resource.id}
+        title={
+          <Text fontSize="xs-sm" className="ellipsis-text">
+            {resource.translatedName
+              ? `${resource.translatedName} | ${resource.name}`
+              : resource.name}
+          </Text>
+        }
+        titleExtra={
+          item.installed ? (
+            <Tag size="sm" colorScheme="gray" variant="subtle">
+              {t("DownloadResourcePlanModal.status.installed")}
+            </Tag>
+          ) : !item.file ? (
+            <Tag size="sm" colorScheme="red" variant="subtle">
+              {t("DownloadResourcePlanModal.status.unavailable")}
+            </Tag>
+          ) : (
+            <Tag size="sm" colorScheme={primaryColor} variant="subtle">
+              {t("DownloadResourcePlanModal.status.toDownload")}
+            </Tag>
+          )
+        }
+        description={
+          <VStack
+            fontSize="xs"
+            className="secondary-text"
+            spacing={0}
+            align="flex-start"
+          >
+            <Text className="ellipsis-text">
+              {item.file?.fileName ||
+                t("DownloadResourcePlanModal.label.noFile")}
+            </Text>
+            <Text className="ellipsis-text">
+              {t("DownloadResourcePlanModal.label.requiredBy", {
+                name: getResourceName(item.requiredBy),
+              })}
+            </Text>
+          </VStack>
+        }
+        prefixElement={
+          <Avatar
+            src={resource.iconSrc}
+            name={resource.name}
+            boxSize="36px"
+            borderRadius="4px"
+          />
+        }
+        fontWeight={400}
+      />
+    );
+  };
+
+  return (
+    <Modal
+      scrollBehavior="inside"
+      size={{ base: "md", lg: "lg", xl: "xl" }}
+      {...modalProps}
+    >
+      <ModalOverlay />
+      <ModalContent>
+        <ModalHeader>{t("DownloadResourcePlanModal.header.title")}</ModalHeader>
+        <ModalCloseButton />
+        <ModalBody>
+          {isLoading ? (
+            <VStack mt={8}>
+              <BeatLoader size={16} color="gray" />
+            </VStack>
+          ) : (
+            <VStack spacing={2} align="stretch">
+              <Text className="secondary-text" mb={2}>
+                {t("DownloadResourcePlanModal.description", {
+                  name: resourceName,
+                  count: dependencyFiles.length,
+                })}
+              </Text>
+              {plan.length > 0 ? (
+                plan.map(renderPlanItem)
+              ) : (
+                <Empty withIcon={false} size="sm" />
+              )}
+            </VStack>
+          )}
+        </ModalBody>
+        <ModalFooter>
+          <HStack spacing={3}>
+            <Button variant="ghost" onClick={modalProps.onClose}>
+              {t("General.cancel")}
+            </Button>
+            <Button
+              variant="outline"
+              colorScheme={primaryColor}
+              isDisabled={isLoading}
+              onClick={() => {
+                modalProps.onClose();
+                onDownload([]);
+              }}
+            >
+              {t("DownloadResourcePlanModal.button.downloadOriginal")}
+            </Button>
+            <Button
+              colorScheme={primaryColor}
+              isDisabled={isLoading}
+              onClick={() => {
+                modalProps.onClose();
+                onDownload(dependencyFiles);
+              }}
+            >
+              {t("DownloadResourcePlanModal.button.downloadAll", {
+                count: dependencyFiles.length + 1,
+              })}
+            </Button>
+          </HStack>
+        </ModalFooter>
+      </ModalContent>
+    </Modal>
+  );
+};
+
+export default DownloadResourcePlanModal;
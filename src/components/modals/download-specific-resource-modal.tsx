@@ -42,7 +42,11 @@ import { useGlobalData } from "@/contexts/global-data";
 import { useSharedModals } from "@/contexts/shared-modal";
 import { useToast } from "@/contexts/toast";
 import { InstanceSubdirType, ModLoaderType } from "@/enums/instance";
-import { OtherResourceSource, OtherResourceType } from "@/enums/resource";
+import {
+  DependencyType,
+  OtherResourceSource,
+  OtherResourceType,
+} from "@/enums/resource";
 import { GetStateFlag } from "@/hooks/get-state";
 import { useThemedCSSStyle } from "@/hooks/themed-css";
 import {
@@ -109,6 +113,7 @@ const DownloadSpecificResourceModal: React.FC<
     ModLoaderType.Fabric,
     ModLoaderType.Forge,
     ModLoaderType.NeoForge,
+    ModLoaderType.Quilt,
   ];
 
   const iconBackgroundColor: Record<string, string> = {
@@ -159,6 +164,10 @@ const DownloadSpecificResourceModal: React.FC<
       : t("DownloadSpecificResourceModal.label.all");
   };
 
+  const instanceId = Array.isArray(router.query.id)
+    ? router.query.id[0]
+    : router.query.id;
+
   const getDefaultFilePath = useCallback(async (): Promise<string | null> => {
     const resourceTypeToDirType: Record<string, InstanceSubdirType> = {
       mod: InstanceSubdirType.Mods,
@@ -170,9 +179,6 @@ const DownloadSpecificResourceModal: React.FC<
     const dirType =
       resourceTypeToDirType[resource.type] ?? InstanceSubdirType.Root;
 
-    let id = router.query.id;
-    const instanceId = Array.isArray(id) ? id[0] : id;
-
     if (instanceId !== undefined) {
       return InstanceService.retrieveInstanceSubdirPath(
         instanceId,
@@ -193,11 +199,12 @@ const DownloadSpecificResourceModal: React.FC<
 
     const defaultDownloadPath = await downloadDir();
     return defaultDownloadPath;
-  }, [resource.type, router.query.id, toast]);
+  }, [resource.type, instanceId, toast]);
 
   const startDownload = async (
     item: OtherResourceFileInfo,
-    translatedName?: string
+    translatedName?: string,
+    dependencies: OtherResourceFileInfo[] = []
   ) => {
     const dir = await getDefaultFilePath();
     const fileName = sanitizeFileName(
@@ -209,6 +216,7 @@ const DownloadSpecificResourceModal: React.FC<
       defaultPath: dir + "/" + fileName,
     });
     if (!savepath) return;
+    // dependencies go to the mods dir of the instance, in the same task group
     handleScheduleProgressiveTaskGroup(resource.type, [
       {
         src: item.downloadUrl,
@@ -216,6 +224,12 @@ const DownloadSpecificResourceModal: React.FC<
         sha1: item.sha1,
         taskType: TaskTypeEnums.Download,
       },
+      ...dependencies.map((dep) => ({
+        src: dep.downloadUrl,
+        dest: `${dir}/${sanitizeFileName(dep.fileName)}`,
+        sha1: dep.sha1,
+        taskType: TaskTypeEnums.Download,
+      })),
     ]);
 
     if (resource.type === OtherResourceType.ModPack) {
@@ -445,7 +459,27 @@ const DownloadSpecificResourceModal: React.FC<
                 }
                 isFullClickZone
                 onClick={() => {
+                  const hasRequiredDependency = item.dependencies.some(
+                    (dep) => dep.relation === DependencyType.Required
+                  );
                   if (
+                    hasRequiredDependency &&
+                    instanceId !== undefined &&
+                    resource.type === OtherResourceType.Mod
+                  ) {
+                    openSharedModal("download-resource-plan", {
+                      instanceId,
+                      downloadSource: resource.source as OtherResourceSource,
+                      file: item,
+                      resourceName: resource.translatedName || resource.name,
+                      onDownload: (dependencies: OtherResourceFileInfo[]) =>
+                        startDownload(
+                          item,
+                          resource.translatedName,
+                          dependencies
+                        ),
+                    });
+                  } else if (
                     item.dependencies.length > 0 &&
                     resource.type !== OtherResourceType.ModPack
                   ) {
@@ -0,0 +1,188 @@
+import {
+  Button,
+  HStack,
+  Modal,
+  ModalBody,
+  ModalCloseButton,
+  ModalContent,
+  ModalFooter,
+  ModalHeader,
+  ModalOverlay,
+  ModalProps,
+  Tag,
+  Text,
+  VStack,
+} from "@chakra-ui/react";
+import { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { BeatLoader } from "react-spinners";
+import Empty from "@/components/common/empty";
+import { OptionItem, OptionItemGroup } from "@/components/common/option-item";
+import { useLauncherConfig } from "@/contexts/config";
+import { useToast } from "@/contexts/toast";
+import { DuplicateModGroup, LocalModInfo } from "@/models/instance/misc";
+import { InstanceService } from "@/services/instance";
+
+interface DuplicateModsModalProps extends Omit<ModalProps, "children"> {
+  instanceId: string | undefined;
+  onCleanup: () => void;
+}
+
+const DuplicateModsModal: React.FC<DuplicateModsModalProps> = ({
+  instanceId,
+  onCleanup,
+  ...modalProps
+}) => {
+  const { t } = useTranslation();
+  const { config } = useLauncherConfig();
+  const primaryColor = config.appearance.theme.primaryColor;
+  const toast = useToast();
+
+  const [groups, setGroups] = useState<DuplicateModGroup[]>([]);
+  const [isLoading, setIsLoading] = useState<boolean>(true);
+  const [isCleaning, setIsCleaning] = useState<boolean>(false);
+
+  const handleRetrieveDuplicateModList = useCallback(() => {
+    if (!instanceId) return;
+    setIsLoading(true);
+    InstanceService.retrieveDuplicateModList(instanceId)
+      .then((response) => {
+        if (response.status === "success") {
+          setGroups(response.data);
+        } else {
+          setGroups([]);
+          toast({
+            title: response.message,
+            description: response.details,
+            status: "error",
+          });
+        }
+      })
+      .finally(() => setIsLoading(false));
+  }, [instanceId, toast]);
+
+  useEffect(() => {
+    if (modalProps.isOpen) handleRetrieveDuplicateModList();
+  }, [modalProps.isOpen, handleRetrieveDuplicateModList]);
+
+  const handleCleanup = (deleteFiles: boolean) => {
+    if (!instanceId) return;
+    setIsCleaning(true);
+    InstanceService.cleanupDuplicateMods(instanceId, deleteFiles)
+      .then((response) => {
+        if (response.status === "success") {
+          toast({
+            title: response.message,
+            status: "success",
+          });
+          onCleanup();
+          modalProps.onClose();
+        } else {
+          toast({
+            title: response.message,
+            description: response.details,
+            status: "error",
+          });
+        }
+      })
+      .finally(() => setIsCleaning(false));
+  };
+
+  const renderModItem = (mod: LocalModInfo, isWinner: boolean) => (
+    <OptionItem
+      key={mod.filePath}
+      title={
+        <Text fontSize="xs-sm" className="ellipsis-text">
+          {mod.fileName}
+        </Text>
+      }
+      titleExtra={
+        <HStack flex="0 0 auto">
+          {mod.version && (
+            <Text fontSize="xs" className="secondary-text">
+              {mod.version}
+            </Text>
+          )}
+          <Tag
+            size="sm"
+            colorScheme={isWinner ? primaryColor : "gray"}
+            variant="subtle"
+          >
+            {t(`DuplicateModsModal.status.${isWinner ? "keep" : "older"}`)}
+          </Tag>
+          {!mod.enabled && (
+            <Tag size="sm" colorScheme="gray" variant="subtle">
+              {t("DuplicateModsModal.status.disabled")}
+            </Tag>
+          )}
+        </HStack>
+      }
+      fontWeight={400}
+    />
+  );
+
+  return (
+    <Modal
+      scrollBehavior="inside"
+      size={{ base: "md", lg: "lg", xl: "xl" }}
+      {...modalProps}
+    >
+      <ModalOverlay />
+      <ModalContent>
+        <ModalHeader>{t("DuplicateModsModal.header.title")}</ModalHeader>
+        <ModalCloseButton />
+        <ModalBody>
+          {isLoading ? (
+            <VStack mt={8}>
+              <BeatLoader size={16} color="gray" />
+            </VStack>
+          ) : groups.length > 0 ? (
+            <VStack spacing={4} align="stretch">
+              <Text className="secondary-text">
+                {t("DuplicateModsModal.description")}
+              </Text>
+              {groups.map((group) => (
+                <OptionItemGroup
+                  key={group.modId}
+                  title={group.modId}
+                  items={[
+                    renderModItem(group.winner, true),
+                    ...group.duplicates.map((mod) => renderModItem(mod, false)),
+                  ]}
+                />
+              ))}
+            </VStack>
+          ) : (
+            <Empty withIcon={false} size="sm" />
+          )}
+        </ModalBody>
+        <ModalFooter>
+          <HStack spacing={3}>
+            <Button variant="ghost" onClick={modalProps.onClose}>
+              {t("General.cancel")}
+            </Button>
+            <Button
+              variant="outline"
+              colorScheme="red"
+              isDisabled={isLoading || groups.length === 0}
+              isLoading={isCleaning}
+              onClick={() => handleCleanup(true)}
+            >
+              {t("DuplicateModsModal.button.delete")}
+            </Button>
+            <Button
+              colorScheme={primaryColor}
+              isDisabled={isLoading || groups.length === 0}
+              isLoading={isCleaning}
+              onClick={() => handleCleanup(false)}
+            >
+              {t("DuplicateModsModal.button.disable")}
+            </Button>
+          </HStack>
+        </ModalFooter>
+      </ModalContent>
+    </Modal>
+  );
+};
+
+export default DuplicateModsModal;
@@ -1,6 +1,7 @@
 import {
   Button,
   Center,
+  Checkbox,
   HStack,
   Image,
   Modal,
@@ -62,6 +63,9 @@ const ImportModpackModal: React.FC<ImportModpackModalProps> = ({
   const [gameDirectory, setGameDirectory] = useState(
     config.localGameDirectories[0]
   );
+  const [selectedOptionalFiles, setSelectedOptionalFiles] = useState<
+    string[]
+  >([]);
   const [isPageLoading, setIsPageLoading] = useState(true);
   const [isBtnLoading, setIsBtnLoading] = useState(false);
 
@@ -173,6 +177,33 @@ const ImportModpackModal: React.FC<ImportModpackModalProps> = ({
           },
         ],
       },
+      ...(modpack.optionalFiles.length > 0
+        ? [
+            {
+              title: t("ImportModpackModal.label.optionalFiles"),
+              items: modpack.optionalFiles.map(
+                (file): OptionItemProps => ({
+                  title: file.path.split("/").pop() || file.path,
+                  description: file.description || file.path,
+                  prefixElement: (
+                    <Checkbox
+                      colorScheme={primaryColor}
+                      isChecked={selectedOptionalFiles.includes(file.path)}
+                      onChange={(e) =>
+                        setSelectedOptionalFiles((prev) =>
+                          e.target.checked
+                            ? [...prev, file.path]
+                            : prev.filter((p) => p !== file.path)
+                        )
+                      }
+                    />
+                  ),
+                  children: <></>,
+                })
+              ),
+            },
+          ]
+        : []),
     ];
   }, [
     modpack,
@@ -184,6 +215,8 @@ const ImportModpackModal: React.FC<ImportModpackModalProps> = ({
     checkDirNameError,
     setDescription,
     setGameDirectory,
+    selectedOptionalFiles,
+    primaryColor,
   ]);
 
   const handleImportModpack = useCallback(async () => {
@@ -221,7 +254,9 @@ const ImportModpackModal: React.FC<ImportModpackModalProps> = ({
             } as ModLoaderResourceInfo)
           : defaultModLoaderResourceInfo,
         undefined,
-        path
+        path,
+        undefined,
+        selectedOptionalFiles
       );
       if (createResp.status === "success") {
         onClose();
@@ -248,6 +283,7 @@ const ImportModpackModal: React.FC<ImportModpackModalProps> = ({
     name,
     path,
     router,
+    selectedOptionalFiles,
     toast,
   ]);
 
@@ -259,6 +295,11 @@ const ImportModpackModal: React.FC<ImportModpackModalProps> = ({
           setModpack(response.data);
           setName(sanitizeFileName(response.data.name));
           setDescription(response.data.description || "");
+          setSelectedOptionalFiles(
+            response.data.optionalFiles
+              .filter((file) => file.enabledByDefault)
+              .map((file) => file.path)
+          );
           setIconSrc(
             response.data.modLoader
               ? loaderTypesToIcon[response.data.modLoader.loaderType]
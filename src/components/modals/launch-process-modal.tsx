@@ -41,12 +41,16 @@ interface LaunchProcessModal extends Omit<ModalProps, "children"> {
   instanceId: string; // may not be select instance id
   quickPlaySingleplayer?: string;
   quickPlayMultiplayer?: string;
+  safeMode?: boolean;
+  modBisect?: boolean;
 }
 
 const LaunchProcessModal: React.FC<LaunchProcessModal> = ({
   instanceId,
   quickPlaySingleplayer,
   quickPlayMultiplayer,
+  safeMode,
+  modBisect,
   ...props
 }) => {
   const { t } = useTranslation();
@@ -144,7 +148,12 @@ const LaunchProcessModal: React.FC<LaunchProcessModal> = ({
       {
         label: "launchGame",
         function: () =>
-          LaunchService.launchGame(quickPlaySingleplayer, quickPlayMultiplayer),
+          LaunchService.launchGame(
+            quickPlaySingleplayer,
+            quickPlayMultiplayer,
+            safeMode,
+            modBisect
+          ),
         isOK: (data: any) => true,
         onResCallback: (data: any) => {},
         onErrCallback: (error: ResponseError) => {},
@@ -157,6 +166,8 @@ const LaunchProcessModal: React.FC<LaunchProcessModal> = ({
       openSharedModal,
       quickPlaySingleplayer,
       quickPlayMultiplayer,
+      safeMode,
+      modBisect,
       router,
       selectedPlayer,
       toast,
@@ -0,0 +1,197 @@
+import {
+  Button,
+  HStack,
+  Input,
+  Modal,
+  ModalBody,
+  ModalCloseButton,
+  ModalContent,
+  ModalHeader,
+  ModalOverlay,
+  ModalProps,
+  Text,
+  VStack,
+} from "@chakra-ui/react";
+import { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { LuCheck } from "react-icons/lu";
+import { BeatLoader } from "react-spinners";
+import { CommonIconButton } from "@/components/common/common-icon-button";
+import Empty from "@/components/common/empty";
+import { OptionItem, OptionItemGroup } from "@/components/common/option-item";
+import { useLauncherConfig } from "@/contexts/config";
+import { useToast } from "@/contexts/toast";
+import { ModProfile } from "@/models/instance/misc";
+import { InstanceService } from "@/services/instance";
+
+interface ModProfilesModalProps extends Omit<ModalProps, "children"> {
+  instanceId: string | undefined;
+  onApply: () => void;
+}
+
+const ModProfilesModal: React.FC<ModProfilesModalProps> = ({
+  instanceId,
+  onApply,
+  ...modalProps
+}) => {
+  const { t } = useTranslation();
+  const { config } = useLauncherConfig();
+  const primaryColor = config.appearance.theme.primaryColor;
+  const toast = useToast();
+
+  const [profiles, setProfiles] = useState<ModProfile[]>([]);
+  const [isLoading, setIsLoading] = useState<boolean>(true);
+  const [profileName, setProfileName] = useState<string>("");
+
+  const handleRetrieveModProfileList = useCallback(() => {
+    if (!instanceId) return;
+    setIsLoading(true);
+    InstanceService.retrieveModProfileList(instanceId)
+      .then((response) => {
+        if (response.status === "success") {
+          setProfiles(response.data);
+        } else {
+          setProfiles([]);
+          toast({
+            title: response.message,
+            description: response.details,
+            status: "error",
+          });
+        }
+      })
+      .finally(() => setIsLoading(false));
+  }, [instanceId, toast]);
+
+  useEffect(() => {
+    if (modalProps.isOpen) {
+      setProfileName("");
+      handleRetrieveModProfileList();
+    }
+  }, [modalProps.isOpen, handleRetrieveModProfileList]);
+
+  const handleSaveModProfile = () => {
+    if (!instanceId || !profileName.trim()) return;
+    InstanceService.saveModProfile(instanceId, profileName.trim()).then(
+      (response) => {
+        if (response.status === "success") {
+          toast({ title: response.message, status: "success" });
+          setProfileName("");
+          handleRetrieveModProfileList();
+        } else {
+          toast({
+            title: response.message,
+            description: response.details,
+            status: "error",
+          });
+        }
+      }
+    );
+  };
+
+  const handleApplyModProfile = (name: string) => {
+    if (!instanceId) return;
+    InstanceService.applyModProfile(instanceId, name).then((response) => {
+      if (response.status === "success") {
+        toast({ title: response.message, status: "success" });
+        onApply();
+        modalProps.onClose();
+      } else {
+        toast({
+          title: response.message,
+          description: response.details,
+          status: "error",
+        });
+      }
+    });
+  };
+
+  const handleDeleteModProfile = (name: string) => {
+    if (!instanceId) return;
+    InstanceService.deleteModProfile(instanceId, name).then((response) => {
+      if (response.status === "success") {
+        toast({ title: response.message, status: "success" });
+        handleRetrieveModProfileList();
+      } else {
+        toast({
+          title: response.message,
+          description: response.details,
+          status: "error",
+        });
+      }
+    });
+  };
+
+  return (
+    <Modal
+      scrollBehavior="inside"
+      size={{ base: "md", lg: "lg", xl: "xl" }}
+      {...modalProps}
+    >
+      <ModalOverlay />
+      <ModalContent>
+        <ModalHeader>{t("ModProfilesModal.header.title")}</ModalHeader>
+        <ModalCloseButton />
+        <ModalBody pb={4}>
+          <VStack spacing={4} align="stretch">
+            <HStack>
+              <Input
+                size="sm"
+                value={profileName}
+                onChange={(e) => setProfileName(e.target.value)}
+                placeholder={t("ModProfilesModal.placeholder")}
+                focusBorderColor={`${primaryColor}.500`}
+              />
+              <Button
+                size="sm"
+                colorScheme={primaryColor}
+                isDisabled={!profileName.trim()}
+                onClick={handleSaveModProfile}
+                flexShrink={0}
+              >
+                {t("ModProfilesModal.button.save")}
+              </Button>
+            </HStack>
+            {isLoading ? (
+              <VStack mt={4}>
+                <BeatLoader size={16} color="gray" />
+              </VStack>
+            ) : profiles.length > 0 ? (
+              <OptionItemGroup
+                items={profiles.map((profile) => (
+                  <OptionItem
+                    key={profile.name}
+                    title={profile.name}
+                    description={
+                      <Text fontSize="xs" className="secondary-text">
+                        {t("ModProfilesModal.label.enabledMods", {
+                          count: profile.enabledMods.length,
+                        })}
+                      </Text>
+                    }
+                  >
+                    <HStack spacing={0}>
+                      <CommonIconButton
+                        icon={LuCheck}
+                        label={t("ModProfilesModal.button.apply")}
+                        onClick={() => handleApplyModProfile(profile.name)}
+                      />
+                      <CommonIconButton
+                        icon="delete"
+                        colorScheme="red"
+                        onClick={() => handleDeleteModProfile(profile.name)}
+                      />
+                    </HStack>
+                  </OptionItem>
+                ))}
+              />
+            ) : (
+              <Empty withIcon={false} size="sm" />
+            )}
+          </VStack>
+        </ModalBody>
+      </ModalContent>
+    </Modal>
+  );
+};
+
+export default ModProfilesModal;
@@ -0,0 +1,156 @@
+import {
+  Button,
+  Center,
+  Modal,
+  ModalBody,
+  ModalCloseButton,
+  ModalContent,
+  ModalFooter,
+  ModalHeader,
+  ModalOverlay,
+  ModalProps,
+  Text,
+  VStack,
+} from "@chakra-ui/react";
+import { useRouter } from "next/router";
+import { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { BeatLoader } from "react-spinners";
+import Empty from "@/components/common/empty";
+import { OptionItemGroup } from "@/components/common/option-item";
+import { useLauncherConfig } from "@/contexts/config";
+import { useToast } from "@/contexts/toast";
+import { ModpackVerificationReport } from "@/models/instance/misc";
+import { InstanceService } from "@/services/instance";
+
+interface VerifyModpackModalProps extends Omit<ModalProps, "children"> {
+  instanceId: string;
+  report?: ModpackVerificationReport; // skip verifying if already done
+}
+
+const VerifyModpackModal: React.FC<VerifyModpackModalProps> = ({
+  instanceId,
+  report: initialReport,
+  ...modalProps
+}) => {
+  const { t } = useTranslation();
+  const { config } = useLauncherConfig();
+  const router = useRouter();
+  const toast = useToast();
+  const primaryColor = config.appearance.theme.primaryColor;
+  const { onClose } = modalProps;
+
+  const [report, setReport] = useState<ModpackVerificationReport | undefined>(
+    initialReport
+  );
+  const [isRepairing, setIsRepairing] = useState(false);
+
+  useEffect(() => {
+    if (initialReport) return;
+    InstanceService.verifyModpackFiles(instanceId).then((response) => {
+      if (response.status === "success") {
+        setReport(response.data);
+      } else {
+        toast({
+          title: response.message,
+          description: response.details,
+          status: "error",
+        });
+        onClose();
+      }
+    });
+  }, [instanceId, initialReport, toast, onClose]);
+
+  const handleRepair = useCallback(() => {
+    setIsRepairing(true);
+    InstanceService.repairModpackFiles(instanceId)
+      .then((response) => {
+        if (response.status === "success") {
+          toast({
+            title: response.message,
+            status: "success",
+          });
+          onClose();
+          router.push("/downloads");
+        } else {
+          toast({
+            title: response.message,
+            description: response.details,
+            status: "error",
+          });
+        }
+      })
+      .finally(() => setIsRepairing(false));
+  }, [instanceId, toast, onClose, router]);
+
+  const canRepair =
+    !!report && (report.missing.length > 0 || report.corrupt.length > 0);
+  const groups = report
+    ? (["missing", "corrupt", "extra"] as const).filter(
+        (key) => report[key].length > 0
+      )
+    : [];
+
+  return (
+    <Modal
+      scrollBehavior="inside"
+      size={{ base: "md", lg: "lg", xl: "xl" }}
+      {...modalProps}
+    >
+      <ModalOverlay />
+      <ModalContent>
+        <ModalHeader>{t("VerifyModpackModal.header.title")}</ModalHeader>
+        <ModalCloseButton />
+        <ModalBody>
+          {!report ? (
+            <Center>
+              <BeatLoader size={16} color="gray" />
+            </Center>
+          ) : groups.length === 0 ? (
+            <Empty
+              withIcon={false}
+              size="sm"
+              description={t("VerifyModpackModal.label.valid")}
+            />
+          ) : (
+            <VStack w="100%" spacing={4}>
+              {groups.map((key) => (
+                <OptionItemGroup
+                  key={key}
+                  w="100%"
+                  title={t(`VerifyModpackModal.label.${key}`, {
+                    count: report[key].length,
+                  })}
+                  items={report[key].map((path) => (
+                    <Text
+                      key={path}
+                      fontSize="xs-sm"
+                      className="ellipsis-text"
+                    >
+                      {path}
+                    </Text>
+                  ))}
+                />
+              ))}
+            </VStack>
+          )}
+        </ModalBody>
+        <ModalFooter>
+          <Button variant="ghost" onClick={onClose}>
+            {t("General.cancel")}
+          </Button>
+          <Button
+            colorScheme={primaryColor}
+            onClick={handleRepair}
+            isLoading={isRepairing}
+            isDisabled={!canRepair}
+          >
+            {t("VerifyModpackModal.button.repair")}
+          </Button>
+        </ModalFooter>
+      </ModalContent>
+    </Modal>
+  );
+};
+
+export default VerifyModpackModal;
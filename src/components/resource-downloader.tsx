@@ -28,6 +28,7 @@ import { useGlobalData } from "@/contexts/global-data";
 import { useToast } from "@/contexts/toast";
 import { ModLoaderType } from "@/enums/instance";
 import {
+  MERGED_SOURCE,
   OtherResourceSource,
   OtherResourceType,
   datapackTagList,
@@ -46,6 +47,8 @@ import { ISOToDate } from "@/utils/datetime";
 import { translateTag } from "@/utils/resource";
 import { formatDisplayCount } from "@/utils/string";
 
+type DownloadSource = OtherResourceSource | typeof MERGED_SOURCE;
+
 interface ResourceDownloaderProps {
   resourceType: OtherResourceType;
   initialSearchQuery?: string;
@@ -79,13 +82,19 @@ const tagLists: Record<string, any> = {
   datapack: datapackTagList,
 };
 
-const downloadSourceLists: Record<string, OtherResourceSource[]> = {
-  mod: [OtherResourceSource.CurseForge, OtherResourceSource.Modrinth],
+const bothSources: DownloadSource[] = [
+  OtherResourceSource.CurseForge,
+  OtherResourceSource.Modrinth,
+  MERGED_SOURCE,
+];
+
+const downloadSourceLists: Record<string, DownloadSource[]> = {
+  mod: bothSources,
   world: [OtherResourceSource.CurseForge],
-  resourcepack: [OtherResourceSource.CurseForge, OtherResourceSource.Modrinth],
-  shader: [OtherResourceSource.CurseForge, OtherResourceSource.Modrinth],
-  modpack: [OtherResourceSource.CurseForge, OtherResourceSource.Modrinth],
-  datapack: [OtherResourceSource.CurseForge, OtherResourceSource.Modrinth],
+  resourcepack: bothSources,
+  shader: bothSources,
+  modpack: bothSources,
+  datapack: bothSources,
 };
 
 const ResourceDownloaderMenu: React.FC<ResourceDownloaderMenuProps> = ({
@@ -263,7 +272,11 @@ const ResourceDownloaderList: React.FC<ResourceDownloaderListProps> = ({
           })}
           {...(selectedItem.type === OtherResourceType.Mod &&
             curInstance?.modLoader.loaderType !== ModLoaderType.Unknown && {
-              curInstanceModLoader: curInstance?.modLoader.loaderType,
+              // Cleanroom loads the mods made for Forge
+              curInstanceModLoader:
+                curInstance?.modLoader.loaderType === ModLoaderType.Cleanroom
+                  ? ModLoaderType.Forge
+                  : curInstance?.modLoader.loaderType,
             })}
         />
       )}
@@ -299,7 +312,7 @@ const ResourceDownloader: React.FC<ResourceDownloaderProps> = ({
       ? "Popularity"
       : "relevance"
   );
-  const [downloadSource, setDownloadSource] = useState<OtherResourceSource>(
+  const [downloadSource, setDownloadSource] = useState<DownloadSource>(
     initialDownloadSource
   );
 
@@ -308,9 +321,14 @@ const ResourceDownloader: React.FC<ResourceDownloaderProps> = ({
 
   const tagList = (tagLists[resourceType] || modpackTagList)[downloadSource];
   const sortByList = sortByLists[downloadSource];
+  // the merged tags are named after the Modrinth ones
+  const tagSource =
+    downloadSource === MERGED_SOURCE
+      ? OtherResourceSource.Modrinth
+      : downloadSource;
 
   const onDownloadSourceChange = (e: string) => {
-    setDownloadSource(e as OtherResourceSource);
+    setDownloadSource(e as DownloadSource);
     setSelectedTag("All");
     setSortBy(e === "CurseForge" ? "Popularity" : "relevance");
   };
@@ -329,15 +347,26 @@ const ResourceDownloader: React.FC<ResourceDownloaderProps> = ({
     ) => {
       if (page === 0) setIsLoadingResourceList(true);
 
-      ResourceService.fetchResourceListByName(
-        resourceType,
-        searchQuery,
-        gameVersion,
-        selectedTag,
-        sortBy,
-        downloadSource,
-        page,
-        pageSize
+      (downloadSource === MERGED_SOURCE
+        ? ResourceService.fetchMergedResourceListByName(
+            resourceType,
+            searchQuery,
+            gameVersion,
+            selectedTag,
+            sortBy,
+            page,
+            pageSize
+          )
+        : ResourceService.fetchResourceListByName(
+            resourceType,
+            searchQuery,
+            gameVersion,
+            selectedTag,
+            sortBy,
+            downloadSource,
+            page,
+            pageSize
+          )
       )
         .then((response) => {
           if (response.status === "success") {
@@ -456,7 +485,7 @@ const ResourceDownloader: React.FC<ResourceDownloaderProps> = ({
         group === "All" || resourceType === OtherResourceType.Mod ? (
           <MenuItemOption key={`group-${group}`} value={group} fontSize="xs">
             {t(
-              `ResourceDownloader.${resourceType}TagList.${downloadSource}.${group}`
+              `ResourceDownloader.${resourceType}TagList.${tagSource}.${group}`
             ) || group}
           </MenuItemOption>
         ) : (
@@ -470,7 +499,7 @@ const ResourceDownloader: React.FC<ResourceDownloaderProps> = ({
             _disabled={{ bg: "transparent", cursor: "default" }}
           >
             {t(
-              `ResourceDownloader.${resourceType}TagList.${downloadSource}.${group}`
+              `ResourceDownloader.${resourceType}TagList.${tagSource}.${group}`
             ) || group}
           </MenuItemOption>
         ),
@@ -480,7 +509,7 @@ const ResourceDownloader: React.FC<ResourceDownloaderProps> = ({
               .map((item, index) => (
                 <MenuItemOption key={index} value={item} fontSize="xs" pl={6}>
                   {t(
-                    `ResourceDownloader.${resourceType}TagList.${downloadSource}.${item}`
+                    `ResourceDownloader.${resourceType}TagList.${tagSource}.${item}`
                   ) || item}
                 </MenuItemOption>
               ))
@@ -496,7 +525,7 @@ const ResourceDownloader: React.FC<ResourceDownloaderProps> = ({
         <ResourceDownloaderMenu
           label={t("ResourceDownloader.label.tag")}
           displayText={t(
-            `ResourceDownloader.${resourceType}TagList.${downloadSource}.${selectedTag}`
+            `ResourceDownloader.${resourceType}TagList.${tagSource}.${selectedTag}`
           )}
           onChange={setSelectedTag}
           value={selectedTag}
@@ -534,13 +563,19 @@ const ResourceDownloader: React.FC<ResourceDownloaderProps> = ({
 
         <ResourceDownloaderMenu
           label={t("ResourceDownloader.label.source")}
-          displayText={downloadSource}
+          displayText={
+            downloadSource === MERGED_SOURCE
+              ? t("ResourceDownloader.sourceList.Merged")
+              : downloadSource
+          }
           onChange={onDownloadSourceChange}
           value={downloadSource}
           defaultValue={OtherResourceSource.CurseForge}
           options={downloadSourceLists[resourceType].map((item, index) => (
             <MenuItemOption key={index} value={item} fontSize="xs">
-              {item}
+              {item === MERGED_SOURCE
+                ? t("ResourceDownloader.sourceList.Merged")
+                : item}
             </MenuItemOption>
           ))}
           width={28}
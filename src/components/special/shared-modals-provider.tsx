@@ -1,3 +1,4 @@
+import { useEffect } from "react";
 import DownloadSpecificResourceModal from "@/components//modals/download-specific-resource-modal";
 import AddAuthServerModal from "@/components/modals/add-auth-server-modal";
 import AlertResourceDependencyModal from "@/components/modals/alert-resource-dependency-modal";
@@ -5,14 +6,17 @@ import CopyOrMoveModal from "@/components/modals/copy-or-move-modal";
 import DeleteInstanceDialog from "@/components/modals/delete-instance-alert-dialog";
 import DownloadModpackModal from "@/components/modals/download-modpack-modal";
 import DownloadResourceModal from "@/components/modals/download-resource-modal";
+import DownloadResourcePlanModal from "@/components/modals/download-resource-plan-modal";
 import GenericConfirmDialog from "@/components/modals/generic-confirm-dialog";
 import ImportModpackModal from "@/components/modals/import-modpack-modal";
 import LaunchProcessModal from "@/components/modals/launch-process-modal";
 import NotifyNewVersionModal from "@/components/modals/notify-new-version-modal";
 import ReLoginPlayerModal from "@/components/modals/relogin-player-modal";
 import SpotlightSearchModal from "@/components/modals/spotlight-search-modal";
+import VerifyModpackModal from "@/components/modals/verify-modpack-modal";
 import { SharedModalContextProvider } from "@/contexts/shared-modal";
 import { useSharedModals } from "@/contexts/shared-modal";
+import { InstanceService } from "@/services/instance";
 
 const SharedModalsProvider: React.FC<{ children: React.ReactNode }> = ({
   children,
@@ -27,7 +31,20 @@ const SharedModalsProvider: React.FC<{ children: React.ReactNode }> = ({
 const SharedModals: React.FC<{ children: React.ReactNode }> = ({
   children,
 }) => {
-  const { modalStates, closeSharedModal } = useSharedModals();
+  const { modalStates, openSharedModal, closeSharedModal } = useSharedModals();
+
+  // show the report if the files of a modpack are broken after downloading
+  useEffect(() => {
+    const unlisten = InstanceService.onModpackFilesChecked(
+      ({ instanceId, report }) => {
+        if (report.missing.length > 0 || report.corrupt.length > 0) {
+          openSharedModal("verify-modpack", { instanceId, report });
+        }
+      }
+    );
+    return () => unlisten();
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, []);
 
   const modals: Record<string, React.FC<any>> = {
     "add-auth-server": AddAuthServerModal,
@@ -36,6 +53,7 @@ const SharedModals: React.FC<{ children: React.ReactNode }> = ({
     "delete-instance-alert": DeleteInstanceDialog,
     "download-modpack": DownloadModpackModal,
     "download-resource": DownloadResourceModal,
+    "download-resource-plan": DownloadResourcePlanModal,
     "download-specific-resource": DownloadSpecificResourceModal,
     "generic-confirm": GenericConfirmDialog,
     "import-modpack": ImportModpackModal,
@@ -43,6 +61,7 @@ const SharedModals: React.FC<{ children: React.ReactNode }> = ({
     "notify-new-version": NotifyNewVersionModal,
     relogin: ReLoginPlayerModal,
     "spotlight-search": SpotlightSearchModal,
+    "verify-modpack": VerifyModpackModal,
   };
 
   return (
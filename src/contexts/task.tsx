@@ -223,6 +223,32 @@ export const TaskContextProvider: React.FC<{ children: React.ReactNode }> = ({
     [toast]
   );
 
+  // verify the files of the modpack instances named `instanceName` after installing,
+  // and ask the user to repair them if there are missing or corrupt ones.
+  const handleVerifyModpackFiles = useCallback(
+    (instanceName: string) => {
+      InstanceService.retrieveInstanceList().then((response) => {
+        if (response.status !== "success") return;
+        response.data
+          .filter((i) => i.name === instanceName && i.modpackSource)
+          .forEach((instance) => {
+            InstanceService.verifyModpackFiles(instance.id).then((resp) => {
+              if (
+                resp.status === "success" &&
+                (resp.data.missing.length > 0 || resp.data.corrupt.length > 0)
+              ) {
+                openSharedModal("verify-modpack", {
+                  instanceId: instance.id,
+                  report: resp.data,
+                });
+              }
+            });
+          });
+      });
+    },
+    [openSharedModal]
+  );
+
   useEffect(() => {
     const unlisten = TaskService.onProgressiveTaskUpdate(
       (payload: PTaskEventPayload) => {
@@ -434,9 +460,12 @@ export const TaskContextProvider: React.FC<{ children: React.ReactNode }> = ({
               case "game-client":
               case "change-mod-loader":
                 getInstanceList(true);
+                if (name === "game-client" && version)
+                  handleVerifyModpackFiles(version);
                 break;
               case "forge-libraries":
               case "neoforge-libraries":
+              case "cleanroom-libraries":
               case "optifine-libraries":
                 if (version) {
                   let instanceName = getInstanceList()?.find(
@@ -478,6 +507,11 @@ export const TaskContextProvider: React.FC<{ children: React.ReactNode }> = ({
               case "mod-update":
                 emit("instance:refresh-resource-list", OtherResourceType.Mod);
                 break;
+              case "modpack-update":
+              case "modpack-repair":
+                emit("instance:refresh-resource-list", OtherResourceType.Mod);
+                if (version) handleVerifyModpackFiles(version);
+                break;
               case "resourcepack":
                 emit(
                   "instance:refresh-resource-list",
@@ -572,6 +606,7 @@ export const TaskContextProvider: React.FC<{ children: React.ReactNode }> = ({
     getInstanceList,
     updateGroupInfo,
     getJavaInfos,
+    handleVerifyModpackFiles,
     openSharedModal,
     openGenericConfirmDialog,
     config.basicInfo.osType,
@@ -12,6 +12,9 @@ export enum OtherResourceSource {
   Modrinth = "Modrinth",
 }
 
+// searches CurseForge and Modrinth together, only offered by the downloader
+export const MERGED_SOURCE = "Merged";
+
 export enum DependencyType {
   Required = "required",
   Optional = "optional",
@@ -87,6 +90,12 @@ export const modTagList = {
     ],
     technology: ["worldgen", "storage", "transportation"],
   },
+  Merged: {
+    All: ["All"],
+    adventure: ["equipment", "mobs", "magic"],
+    utility: ["decoration", "food", "library", "optimization"],
+    technology: ["worldgen", "storage", "transportation"],
+  },
 };
 
 export const worldTagList = {
@@ -148,6 +157,11 @@ export const resourcePackTagList = {
       "vanilla-like",
     ],
   },
+  Merged: {
+    All: ["All"],
+    Resolution: ["16x", "32x", "64x", "128x", "256x", "512x+"],
+    Styles: ["fonts", "modded", "realistic"],
+  },
 };
 
 export const shaderPackTagList = {
@@ -176,6 +190,10 @@ export const shaderPackTagList = {
     ],
     performance: ["low", "medium", "high", "screenshot"],
   },
+  Merged: {
+    All: ["All"],
+    Styles: ["fantasy", "realistic", "vanilla-like"],
+  },
 };
 
 export const datapackTagList = {
@@ -216,6 +234,10 @@ export const datapackTagList = {
       "worldgen",
     ],
   },
+  Merged: {
+    All: ["All"],
+    styles: ["adventure", "library", "magic", "technology", "utility"],
+  },
 };
 
 export const modpackTagList = {
@@ -256,6 +278,20 @@ export const modpackTagList = {
       "technology",
     ],
   },
+  Merged: {
+    All: ["All"],
+    styles: [
+      "adventure",
+      "challenging",
+      "combat",
+      "kitchen-sink",
+      "lightweight",
+      "magic",
+      "multiplayer",
+      "quests",
+      "technology",
+    ],
+  },
 };
 
 export const sortByLists = {
@@ -267,4 +303,5 @@ export const sortByLists = {
     "Total downloads",
   ],
   Modrinth: ["relevance", "downloads", "follows", "updated", "newest"],
+  Merged: ["relevance", "downloads", "updated", "newest"],
 };
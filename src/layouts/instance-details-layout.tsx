@@ -1,4 +1,5 @@
 import { Button, HStack, Icon, Text, VStack } from "@chakra-ui/react";
+import { open } from "@tauri-apps/plugin-dialog";
 import { openPath } from "@tauri-apps/plugin-opener";
 import { useRouter } from "next/router";
 import React, { useCallback } from "react";
@@ -14,7 +15,9 @@ import {
   LuPackage,
   LuPackagePlus,
   LuPlay,
+  LuRefreshCcw,
   LuSettings,
+  LuShieldCheck,
   LuSquareLibrary,
   LuSquarePlus,
 } from "react-icons/lu";
@@ -96,6 +99,61 @@ const InstanceDetailsLayoutContent: React.FC<{ children: React.ReactNode }> = ({
     [summary, toast, closeSharedModal, openGenericConfirmDialog, t]
   );
 
+  const handleExportPackwizPack = useCallback(async () => {
+    if (!summary) return;
+    const savePath = await open({ directory: true, multiple: false });
+    if (!savePath) return;
+
+    InstanceService.exportPackwizPack(summary.id, savePath).then((response) => {
+      if (response.status === "success") {
+        toast({
+          title: response.message,
+          status: "success",
+        });
+      } else {
+        toast({
+          title: response.message,
+          description: response.details,
+          status: "error",
+        });
+      }
+    });
+  }, [summary, toast]);
+
+  const handleUpdateModpack = useCallback(async () => {
+    if (!summary?.modpackSource) return;
+    const isPackwiz = summary.modpackSource === "Packwiz";
+    const path = await open({
+      directory: isPackwiz,
+      multiple: false,
+      filters: isPackwiz
+        ? undefined
+        : [
+            {
+              name: t("General.dialog.filterName.modpack"),
+              extensions: ["zip", "mrpack"],
+            },
+          ],
+    });
+    if (!path) return;
+
+    InstanceService.updateModpack(summary.id, path).then((response) => {
+      if (response.status === "success") {
+        toast({
+          title: response.message,
+          status: "success",
+        });
+        router.push("/downloads");
+      } else {
+        toast({
+          title: response.message,
+          description: response.details,
+          status: "error",
+        });
+      }
+    });
+  }, [summary, toast, router, t]);
+
   const instanceSecMenuOperations = [
     {
       icon: "openFolder",
@@ -116,8 +174,27 @@ const InstanceDetailsLayoutContent: React.FC<{ children: React.ReactNode }> = ({
       icon: LuPackagePlus,
       label: t("InstanceDetailsLayout.secMenu.exportModPack"),
       danger: false,
-      onClick: () => {},
+      onClick: handleExportPackwizPack,
     },
+    ...(summary?.modpackSource
+      ? [
+          {
+            icon: LuRefreshCcw,
+            label: t("InstanceDetailsLayout.secMenu.updateModpack"),
+            danger: false,
+            onClick: handleUpdateModpack,
+          },
+          {
+            icon: LuShieldCheck,
+            label: t("InstanceDetailsLayout.secMenu.verifyModpack"),
+            danger: false,
+            onClick: () => {
+              if (summary)
+                openSharedModal("verify-modpack", { instanceId: summary.id });
+            },
+          },
+        ]
+      : []),
     {
       icon: "delete",
       label: t("InstanceMenu.label.delete"),
@@ -55,6 +55,13 @@ export interface GameDirectory {
   dir: string;
 }
 
+// a mirror following the official layout, keyed by the backend ResourceType
+export interface CustomDownloadSource {
+  name: string;
+  enabled: boolean;
+  baseUrls: Record<string, string>;
+}
+
 export interface LauncherConfig {
   basicInfo: {
     launcherVersion: string;
@@ -94,6 +101,11 @@ export interface LauncherConfig {
   download: {
     source: {
       strategy: string;
+      customSources: CustomDownloadSource[];
+    };
+    resourceApi: {
+      curseforge: string;
+      modrinth: string;
     };
     transmission: {
       autoConcurrent: boolean;
@@ -253,6 +265,11 @@ export const defaultConfig: LauncherConfig = {
   download: {
     source: {
       strategy: "auto",
+      customSources: [],
+    },
+    resourceApi: {
+      curseforge: "",
+      modrinth: "",
     },
     transmission: {
       autoConcurrent: true,
@@ -37,6 +37,7 @@ export interface InstanceSummary {
   supportQuickPlay: boolean;
   useSpecGameConfig: boolean;
   isVersionIsolated: boolean;
+  modpackSource?: string; // including sources not in `OtherResourceSource`, e.g. MultiMc, Packwiz
 }
 
 export interface ModpackMetaInfo {
@@ -47,6 +48,24 @@ export interface ModpackMetaInfo {
   modpackType: OtherResourceSource;
   clientVersion: string;
   modLoader?: ModLoader;
+  optionalFiles: ModpackOptionalFile[];
+}
+
+export interface ModpackOptionalFile {
+  path: string;
+  description?: string;
+  enabledByDefault: boolean;
+}
+
+export interface ModpackVerificationReport {
+  missing: string[];
+  corrupt: string[];
+  extra: string[];
+}
+
+export interface ModpackFilesCheckedPayload {
+  instanceId: string;
+  report: ModpackVerificationReport;
 }
 
 export interface GameServerInfo {
@@ -63,6 +82,8 @@ export interface GameServerInfo {
 export interface LocalModInfo {
   iconSrc: string;
   enabled: boolean;
+  modId: string;
+  dependencies: string[];
   name: string;
   translatedName?: string;
   version: string;
@@ -72,6 +93,45 @@ export interface LocalModInfo {
   description?: string;
   translatedDescription?: string;
   potentialIncompatibility: boolean;
+  children: NestedModInfo[];
+}
+
+export interface NestedModInfo {
+  modId: string;
+  name: string;
+  version: string;
+  filePath: string;
+  children: NestedModInfo[];
+}
+
+export interface DuplicateModGroup {
+  modId: string;
+  winner: LocalModInfo;
+  duplicates: LocalModInfo[];
+}
+
+export interface ModProfile {
+  name: string;
+  enabledMods: string[];
+}
+
+export interface ModBisectUnit {
+  modId: string;
+  files: string[];
+}
+
+export interface ModBisectStep {
+  disabledUnits: number[];
+  reachedReady?: boolean;
+}
+
+export interface ModBisectSession {
+  original: ModProfile;
+  units: ModBisectUnit[];
+  suspects: number[];
+  steps: ModBisectStep[];
+  culprit?: ModBisectUnit;
+  finished: boolean;
 }
 
 export interface ResourcePackInfo {
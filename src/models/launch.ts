@@ -14,4 +14,10 @@ export interface LaunchingState {
   selectedPlayer?: PlayerInfo;
   authServerMeta: string;
   pid: number;
+  modBisect: boolean;
+}
+
+export interface ModBisectRunExitedPayload {
+  instanceId: string;
+  exitOk: boolean;
 }
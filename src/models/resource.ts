@@ -26,6 +26,14 @@ export interface OtherResourceInfo {
   lastUpdated: string;
   downloads: number;
   source?: OtherResourceSource;
+  linkedSources?: OtherResourceLink[]; // set by the merged search
+}
+
+export interface OtherResourceLink {
+  id: string;
+  slug: string;
+  source: OtherResourceSource;
+  websiteUrl: string;
 }
 
 export interface OtherResourceSearchRes {
@@ -59,6 +67,13 @@ export interface OtherResourceVersionPack {
   items: OtherResourceFileInfo[];
 }
 
+export interface ResourceDependencyPlanItem {
+  resource: OtherResourceInfo;
+  file?: OtherResourceFileInfo; // undefined if no file matches the instance
+  requiredBy: string;
+  installed: boolean;
+}
+
 export interface ModLoaderResourceInfo {
   loaderType: ModLoaderType;
   version: string;
@@ -88,6 +103,7 @@ export interface ModUpdateRecord {
   downloadUrl: string;
   sha1: string;
   fileName: string;
+  changelog: string;
 }
 
 export interface ModUpdateQuery {
@@ -96,3 +112,23 @@ export interface ModUpdateQuery {
   fileName: string;
   oldFilePath: string;
 }
+
+export interface ModUpdateInfo {
+  name: string;
+  curVersion: string;
+  oldFilePath: string;
+  source: OtherResourceSource;
+  file: OtherResourceFileInfo;
+  changelog: string;
+}
+
+export interface ModUpdateHistoryItem {
+  oldFileName: string;
+  newFileName: string;
+}
+
+export interface ModUpdateHistoryEntry {
+  id: string;
+  time: number;
+  items: ModUpdateHistoryItem[];
+}
@@ -10,6 +10,7 @@ export interface DownloadTaskParam {
   dest: string; // destination path
   filename?: string; // destination filename
   sha1?: string;
+  altSrcs?: string[]; // tried in order if src fails
 }
 
 export type TaskParam = DownloadTaskParam;
@@ -20,6 +21,8 @@ export interface DownloadTaskPayload {
   dest: string; // destination path
   filename: string; // destination filename
   sha1: string;
+  altSrcs: string[];
+  servedSrc: string | null; // the url that finally served the file
 }
 
 export type TaskPayload = DownloadTaskPayload;
@@ -13,7 +13,12 @@ import {
 import { open } from "@tauri-apps/plugin-dialog";
 import { useRouter } from "next/router";
 import { useTranslation } from "react-i18next";
-import { LuArrowRight, LuCloudDownload, LuFolderPlus } from "react-icons/lu";
+import {
+  LuArrowRight,
+  LuCloudDownload,
+  LuFolderGit2,
+  LuFolderPlus,
+} from "react-icons/lu";
 import {
   OptionItemGroup,
   OptionItemGroupProps,
@@ -66,6 +71,18 @@ const AddAndImportInstancePage = () => {
     }
   };
 
+  const handleImportPackwizFromDisk = async () => {
+    let dirPath = await open({
+      directory: true,
+      multiple: false,
+    });
+    if (dirPath) {
+      openSharedModal("import-modpack", {
+        path: dirPath,
+      });
+    }
+  };
+
   const addAndImportOptions: Record<string, () => void> = {
     new: onOpenCreateInstanceModal,
     modpack: onOpenModpackMenu,
@@ -84,6 +101,13 @@ const AddAndImportInstancePage = () => {
         handleImportModpackFromDisk();
       },
     },
+    {
+      icon: LuFolderGit2,
+      label: t("AddAndImportInstancePage.modpackOperations.packwiz"),
+      onClick: () => {
+        handleImportPackwizFromDisk();
+      },
+    },
     {
       icon: LuCloudDownload,
       label: t("AddAndImportInstancePage.modpackOperations.download"),
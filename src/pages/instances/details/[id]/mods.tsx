@@ -17,6 +17,9 @@ import {
   LuCircleCheck,
   LuCircleMinus,
   LuClockArrowUp,
+  LuCopy,
+  LuCornerDownRight,
+  LuLayers,
   LuSearch,
   LuTriangleAlert,
   LuX,
@@ -34,9 +37,12 @@ import {
   modLoaderTypes,
   modLoaderTypesToIcon,
 } from "@/components/loader-selector";
+import ModBisectSection from "@/components/mod-bisect-section";
 import { ChangeModLoaderModal } from "@/components/modals/change-mod-loader-modal";
 import CheckModUpdateModal from "@/components/modals/check-mod-update-modal";
+import DuplicateModsModal from "@/components/modals/duplicate-mods-modal";
 import ModInfoModal from "@/components/modals/mod-info-modal";
+import ModProfilesModal from "@/components/modals/mod-profiles-modal";
 import { useLauncherConfig } from "@/contexts/config";
 import { useInstanceSharedData } from "@/contexts/instance";
 import { useSharedModals } from "@/contexts/shared-modal";
@@ -45,7 +51,7 @@ import { InstanceSubdirType, ModLoaderType } from "@/enums/instance";
 import { OtherResourceType } from "@/enums/resource";
 import { InstanceError } from "@/enums/service-error";
 import { GetStateFlag } from "@/hooks/get-state";
-import { LocalModInfo } from "@/models/instance/misc";
+import { LocalModInfo, NestedModInfo } from "@/models/instance/misc";
 import { InstanceService } from "@/services/instance";
 import { ResourceService } from "@/services/resource";
 import { UtilsService } from "@/services/utils";
@@ -94,6 +100,18 @@ const InstanceModsPage = () => {
     onClose: onCheckUpdateModalClose,
   } = useDisclosure();
 
+  const {
+    isOpen: isDuplicateModsModalOpen,
+    onOpen: onDuplicateModsModalOpen,
+    onClose: onDuplicateModsModalClose,
+  } = useDisclosure();
+
+  const {
+    isOpen: isModProfilesModalOpen,
+    onOpen: onModProfilesModalOpen,
+    onClose: onModProfilesModalClose,
+  } = useDisclosure();
+
   const {
     isOpen: isModInfoModalOpen,
     onOpen: onModInfoModalOpen,
@@ -286,6 +304,18 @@ const InstanceModsPage = () => {
       onClick: onCheckUpdateModalOpen,
       disable: isLoading || localMods.length === 0,
     },
+    {
+      icon: LuCopy,
+      label: t("InstanceModsPage.modList.menu.duplicates"),
+      onClick: onDuplicateModsModalOpen,
+      disable: isLoading || localMods.length === 0,
+    },
+    {
+      icon: LuLayers,
+      label: t("InstanceModsPage.modList.menu.profiles"),
+      onClick: onModProfilesModalOpen,
+      disable: isLoading,
+    },
     {
       icon: "add",
       onClick: () => {
@@ -354,6 +384,49 @@ const InstanceModsPage = () => {
     },
   ];
 
+  // mods bundled in a jar (Jar-in-Jar) are listed under their parent, indented by depth
+  const renderNestedModItems = (
+    parent: LocalModInfo,
+    children: NestedModInfo[],
+    depth: number = 1
+  ): React.ReactNode[] =>
+    children.flatMap((child) => [
+      <OptionItem
+        key={`${parent.fileName}:${child.filePath}`}
+        pl={depth * 4}
+        title={
+          <Text fontSize="xs-sm" className="ellipsis-text">
+            {child.name || child.modId}
+          </Text>
+        }
+        titleExtra={
+          <HStack flex="0 0 auto">
+            {child.version && (
+              <Text fontSize="xs" className="secondary-text">
+                {child.version}
+              </Text>
+            )}
+            <Tag className="tag-xs">
+              {t("InstanceModsPage.modList.nested")}
+            </Tag>
+          </HStack>
+        }
+        description={
+          <Text fontSize="xs" className="secondary-text ellipsis-text">
+            {child.filePath}
+          </Text>
+        }
+        prefixElement={
+          <Center boxSize="28px">
+            <Icon as={LuCornerDownRight} className="secondary-text" />
+          </Center>
+        }
+        titleLineWrap={false}
+        style={{ opacity: parent.enabled ? 1 : 0.5 }}
+      />,
+      ...renderNestedModItems(parent, child.children, depth + 1),
+    ]);
+
   const selectableCardItems = modLoaderTypes.map(
     (type): SelectableCardProps => ({
       title: type,
@@ -472,7 +545,7 @@ const InstanceModsPage = () => {
           </Center>
         ) : filteredMods.length > 0 ? (
           <OptionItemGroup
-            items={filteredMods.map((mod) => (
+            items={filteredMods.flatMap((mod) => [
               <OptionItem
                 key={mod.fileName} // unique
                 childrenOnHover
@@ -562,19 +635,36 @@ const InstanceModsPage = () => {
                     />
                   ))}
                 </HStack>
-              </OptionItem>
-            ))}
+              </OptionItem>,
+              ...renderNestedModItems(mod, mod.children || []),
+            ])}
           />
         ) : (
           <Empty withIcon={false} size="sm" />
         )}
       </Section>
+      <ModBisectSection
+        summary={summary}
+        onModsChanged={() => getLocalModListWrapper(true)}
+      />
       <CheckModUpdateModal
         isOpen={isCheckUpdateModalOpen}
         onClose={onCheckUpdateModalClose}
         summary={summary}
         localMods={localMods}
       />
+      <DuplicateModsModal
+        isOpen={isDuplicateModsModalOpen}
+        onClose={onDuplicateModsModalClose}
+        instanceId={summary?.id}
+        onCleanup={() => getLocalModListWrapper(true)}
+      />
+      <ModProfilesModal
+        isOpen={isModProfilesModalOpen}
+        onClose={onModProfilesModalClose}
+        instanceId={summary?.id}
+        onApply={() => getLocalModListWrapper(true)}
+      />
       <ChangeModLoaderModal
         isOpen={isChangeModLoaderModalOpen}
         onClose={onChangeModLoaderModalClose}
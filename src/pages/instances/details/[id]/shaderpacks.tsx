@@ -12,12 +12,15 @@ import SelectableCard, {
   SelectableCardProps,
 } from "@/components/common/selectable-card";
 import { useLauncherConfig } from "@/contexts/config";
+import { useGlobalData } from "@/contexts/global-data";
 import { useInstanceSharedData } from "@/contexts/instance";
 import { useSharedModals } from "@/contexts/shared-modal";
+import { useToast } from "@/contexts/toast";
 import { InstanceSubdirType } from "@/enums/instance";
 import { OtherResourceType } from "@/enums/resource";
 import { GetStateFlag } from "@/hooks/get-state";
 import { ShaderPackInfo } from "@/models/instance/misc";
+import { InstanceService } from "@/services/instance";
 import { ResourceService } from "@/services/resource";
 
 const InstanceShaderPacksPage = () => {
@@ -30,7 +33,9 @@ const InstanceShaderPacksPage = () => {
     getShaderPackList,
     isShaderPackListLoading: isLoading,
   } = useInstanceSharedData();
-  const { openSharedModal } = useSharedModals();
+  const { openSharedModal, openGenericConfirmDialog } = useSharedModals();
+  const { getInstanceList } = useGlobalData();
+  const toast = useToast();
   const accordionStates = config.states.instanceShaderPacksPage.accordionStates;
 
   const [shaderPacks, setShaderPacks] = useState<ShaderPackInfo[]>([]);
@@ -62,6 +67,22 @@ const InstanceShaderPacksPage = () => {
     return unlisten;
   }, [getShaderPackListWrapper]);
 
+  const handleRemoveOptiFine = useCallback(() => {
+    if (!summary?.id) return;
+    InstanceService.removeOptiFine(summary.id).then((response) => {
+      if (response.status === "success") {
+        toast({ title: response.message, status: "success" });
+        getInstanceList(true);
+      } else {
+        toast({
+          title: response.message,
+          description: response.details,
+          status: "error",
+        });
+      }
+    });
+  }, [summary?.id, toast, getInstanceList]);
+
   const shaderSecMenuOperations = [
     {
       icon: "openFolder",
@@ -121,11 +142,19 @@ const InstanceShaderPacksPage = () => {
         summary?.optifine?.status === "Installed"
           ? summary?.optifine?.version
           : t("InstanceShaderPacksPage.shaderLoaderList.notInstalled"),
-      displayMode: "entry",
+      displayMode: "selector",
       isSelected: summary?.optifine?.status === "Installed",
       onSelect: () => {},
+      onCancel: () =>
+        openGenericConfirmDialog({
+          title: t("InstanceShaderPacksPage.removeOptiFineDialog.title"),
+          body: t("InstanceShaderPacksPage.removeOptiFineDialog.content"),
+          btnOK: t("General.delete"),
+          isAlert: true,
+          onOKCallback: handleRemoveOptiFine,
+        }),
       // TODO: add OptiFine installation support
-      isDisabled: true,
+      isDisabled: summary?.optifine?.status !== "Installed",
       isChevronShown: false,
     },
   ];
@@ -14,6 +14,7 @@ import {
   SliderTrack,
   Switch,
   Text,
+  useDisclosure,
 } from "@chakra-ui/react";
 import { open } from "@tauri-apps/plugin-dialog";
 import { openPath } from "@tauri-apps/plugin-opener";
@@ -22,12 +23,14 @@ import { useState } from "react";
 import { useTranslation } from "react-i18next";
 import { LuChevronDown, LuChevronUp } from "react-icons/lu";
 import { LuArrowRight } from "react-icons/lu";
+import { CommonIconButton } from "@/components/common/common-icon-button";
 import { MenuSelector } from "@/components/common/menu-selector";
 import {
   OptionItemGroup,
   OptionItemGroupProps,
 } from "@/components/common/option-item";
 import SegmentedControl from "@/components/common/segmented";
+import AddDownloadSourceModal from "@/components/modals/add-download-source-modal";
 import { useLauncherConfig } from "@/contexts/config";
 import { useSharedModals } from "@/contexts/shared-modal";
 import { useTaskContext } from "@/contexts/task";
@@ -69,9 +72,18 @@ const DownloadSettingsPage = () => {
   const [proxyHost, setProxyHost] = useState<string>(
     downloadConfigs.proxy.host
   );
+  const [resourceApis, setResourceApis] = useState<
+    typeof downloadConfigs.resourceApi
+  >(downloadConfigs.resourceApi);
   const [isClearingDownloadCache, setIsClearingDownloadCache] =
     useState<boolean>(false);
 
+  const {
+    isOpen: isAddDownloadSourceModalOpen,
+    onOpen: onAddDownloadSourceModalOpen,
+    onClose: onAddDownloadSourceModalClose,
+  } = useDisclosure();
+
   const sourceStrategyTypes = ["auto", "official", "mirror"];
   const proxyTypeOptions = [
     {
@@ -97,6 +109,22 @@ const DownloadSettingsPage = () => {
     }
   };
 
+  const handleToggleCustomSource = (index: number, enabled: boolean) => {
+    update(
+      "download.source.customSources",
+      downloadConfigs.source.customSources.map((source, i) =>
+        i === index ? { ...source, enabled } : source
+      )
+    );
+  };
+
+  const handleRemoveCustomSource = (index: number) => {
+    update(
+      "download.source.customSources",
+      downloadConfigs.source.customSources.filter((_, i) => i !== index)
+    );
+  };
+
   const handleClearDownloadCache = () => {
     if (isClearingDownloadCache || hasActiveDownloadTasks) {
       return;
@@ -163,8 +191,78 @@ const DownloadSettingsPage = () => {
             />
           ),
         },
+        ...downloadConfigs.source.customSources.map((source, index) => ({
+          title: source.name,
+          description: t(
+            "DownloadSettingPage.source.settings.customSource.description",
+            { count: Object.keys(source.baseUrls).length }
+          ),
+          children: (
+            <HStack>
+              <Switch
+                colorScheme={primaryColor}
+                isChecked={source.enabled}
+                onChange={(event) =>
+                  handleToggleCustomSource(index, event.target.checked)
+                }
+              />
+              <CommonIconButton
+                icon="delete"
+                size="xs"
+                fontSize="sm"
+                h={21}
+                onClick={() => handleRemoveCustomSource(index)}
+              />
+            </HStack>
+          ),
+        })),
+        {
+          title: t("DownloadSettingPage.source.settings.customSource.title"),
+          description: t(
+            "DownloadSettingPage.source.settings.customSource.addDescription"
+          ),
+          children: (
+            <Button
+              variant="subtle"
+              size="xs"
+              onClick={onAddDownloadSourceModalOpen}
+            >
+              {t("DownloadSettingPage.source.settings.customSource.add")}
+            </Button>
+          ),
+        },
       ],
     },
+    {
+      title: t("DownloadSettingPage.resourceApi.title"),
+      items: (["curseforge", "modrinth"] as const).map((platform) => ({
+        title: t(`DownloadSettingPage.resourceApi.settings.${platform}.title`),
+        description: t("DownloadSettingPage.resourceApi.description"),
+        children: (
+          <Input
+            size="xs"
+            w={56}
+            focusBorderColor={`${primaryColor}.500`}
+            placeholder={t(
+              `DownloadSettingPage.resourceApi.settings.${platform}.placeholder`
+            )}
+            value={resourceApis[platform]}
+            onChange={(event) => {
+              setResourceApis({
+                ...resourceApis,
+                [platform]: event.target.value,
+              });
+            }}
+            onBlur={() => {
+              update(
+                `download.resourceApi.${platform}`,
+                resourceApis[platform].trim()
+              );
+            }}
+          />
+        ),
+      })),
+    },
     {
       title: t("DownloadSettingPage.download.title"),
       items: [
@@ -457,6 +555,10 @@ const DownloadSettingsPage = () => {
       {downloadSettingGroups.map((group, index) => (
         <OptionItemGroup title={group.title} items={group.items} key={index} />
       ))}
+      <AddDownloadSourceModal
+        isOpen={isAddDownloadSourceModalOpen}
+        onClose={onAddDownloadSourceModalClose}
+      />
     </>
   );
 };
@@ -1,11 +1,17 @@
 import { invoke } from "@tauri-apps/api/core";
+import { getCurrentWebview } from "@tauri-apps/api/webview";
 import { InstanceSubdirType } from "@/enums/instance";
 import { GameConfig, GameDirectory } from "@/models/config";
 import {
+  DuplicateModGroup,
   GameServerInfo,
   InstanceSummary,
   LocalModInfo,
+  ModBisectSession,
+  ModProfile,
+  ModpackFilesCheckedPayload,
   ModpackMetaInfo,
+  ModpackVerificationReport,
   ResourcePackInfo,
   SchematicInfo,
   ScreenshotInfo,
@@ -46,6 +52,8 @@ export class InstanceService {
    * @param {OptiFineResourceInfo} [optifine] - Optional OptiFine installation.
    * @param {string} [modpackPath] - Optional path to the modpack archive file.
    * @param {boolean} [isInstallFabricApi] - Optional flag to indicate whether to install Fabric API (only valid when modLoader is Fabric).
+   * @param {string[]} [modpackOptionalFiles] - Optional files of the modpack selected to install, the defaults are used if not provided.
+   * @param {ModLoaderResourceInfo} [liteloader] - Optional LiteLoader installed on top of Forge (only valid when modLoader is Forge).
    * @returns {Promise<InvokeResponse<null>>}
    */
   @responseHandler("instance")
@@ -58,7 +66,9 @@ export class InstanceService {
     modLoader: ModLoaderResourceInfo,
     optifine?: OptiFineResourceInfo,
     modpackPath?: string,
-    isInstallFabricApi?: boolean
+    isInstallFabricApi?: boolean,
+    modpackOptionalFiles?: string[],
+    liteloader?: ModLoaderResourceInfo
   ): Promise<InvokeResponse<null>> {
     return await invoke("create_instance", {
       directory,
@@ -70,6 +80,8 @@ export class InstanceService {
       optifine,
       modpackPath,
       isInstallFabricApi,
+      modpackOptionalFiles,
+      liteloader,
     });
   }
 
@@ -345,6 +357,161 @@ export class InstanceService {
     });
   }
 
+  /**
+   * RETRIEVE the groups of local mods sharing the same mod id.
+   * @param {string} instanceId - The instance ID to check duplicate mods for.
+   * @returns {Promise<InvokeResponse<DuplicateModGroup[]>>}
+   */
+  @responseHandler("instance")
+  static async retrieveDuplicateModList(
+    instanceId: string
+  ): Promise<InvokeResponse<DuplicateModGroup[]>> {
+    return await invoke("retrieve_duplicate_mod_list", {
+      instanceId,
+    });
+  }
+
+  /**
+   * CLEANUP duplicate mods, keeping only the winner file of each group.
+   * @param {string} instanceId - The instance ID to clean up duplicate mods for.
+   * @param {boolean} deleteFiles - Whether to delete the older files (true) or only disable them (false).
+   * @returns {Promise<InvokeResponse<void>>}
+   */
+  @responseHandler("instance")
+  static async cleanupDuplicateMods(
+    instanceId: string,
+    deleteFiles: boolean
+  ): Promise<InvokeResponse<void>> {
+    return await invoke("cleanup_duplicate_mods", {
+      instanceId,
+      delete: deleteFiles,
+    });
+  }
+
+  /**
+   * RETRIEVE the list of mod profiles saved for the instance.
+   * @param {string} instanceId - The instance ID to retrieve the mod profiles for.
+   * @returns {Promise<InvokeResponse<ModProfile[]>>}
+   */
+  @responseHandler("instance")
+  static async retrieveModProfileList(
+    instanceId: string
+  ): Promise<InvokeResponse<ModProfile[]>> {
+    return await invoke("retrieve_mod_profile_list", {
+      instanceId,
+    });
+  }
+
+  /**
+   * SAVE the currently enabled mods as a mod profile (overwrite if the name exists).
+   * @param {string} instanceId - The instance ID to save the mod profile for.
+   * @param {string} profileName - The name of the mod profile.
+   * @returns {Promise<InvokeResponse<ModProfile>>}
+   */
+  @responseHandler("instance")
+  static async saveModProfile(
+    instanceId: string,
+    profileName: string
+  ): Promise<InvokeResponse<ModProfile>> {
+    return await invoke("save_mod_profile", {
+      instanceId,
+      profileName,
+    });
+  }
+
+  /**
+   * DELETE a mod profile.
+   * @param {string} instanceId - The instance ID to delete the mod profile from.
+   * @param {string} profileName - The name of the mod profile.
+   * @returns {Promise<InvokeResponse<void>>}
+   */
+  @responseHandler("instance")
+  static async deleteModProfile(
+    instanceId: string,
+    profileName: string
+  ): Promise<InvokeResponse<void>> {
+    return await invoke("delete_mod_profile", {
+      instanceId,
+      profileName,
+    });
+  }
+
+  /**
+   * APPLY a mod profile by toggling mod files, all renames are rolled back on failure.
+   * @param {string} instanceId - The instance ID to apply the mod profile to.
+   * @param {string} profileName - The name of the mod profile.
+   * @returns {Promise<InvokeResponse<void>>}
+   */
+  @responseHandler("instance")
+  static async applyModProfile(
+    instanceId: string,
+    profileName: string
+  ): Promise<InvokeResponse<void>> {
+    return await invoke("apply_mod_profile", {
+      instanceId,
+      profileName,
+    });
+  }
+
+  /**
+   * RETRIEVE the mod bisect session of the instance.
+   * @param {string} instanceId - The instance ID.
+   * @returns {Promise<InvokeResponse<ModBisectSession | null>>}
+   */
+  @responseHandler("instance")
+  static async retrieveModBisectSession(
+    instanceId: string
+  ): Promise<InvokeResponse<ModBisectSession | null>> {
+    return await invoke("retrieve_mod_bisect_session", {
+      instanceId,
+    });
+  }
+
+  /**
+   * START bisecting mods to find the one causing a crash, half of the suspects are disabled for the next run.
+   * @param {string} instanceId - The instance ID.
+   * @returns {Promise<InvokeResponse<ModBisectSession>>}
+   */
+  @responseHandler("instance")
+  static async startModBisect(
+    instanceId: string
+  ): Promise<InvokeResponse<ModBisectSession>> {
+    return await invoke("start_mod_bisect", {
+      instanceId,
+    });
+  }
+
+  /**
+   * SUBMIT the result of the current bisect run manually (runs are also recorded when the game exits).
+   * @param {string} instanceId - The instance ID.
+   * @param {boolean} reachedReady - Whether the game worked normally in this run.
+   * @returns {Promise<InvokeResponse<ModBisectSession>>}
+   */
+  @responseHandler("instance")
+  static async submitModBisectResult(
+    instanceId: string,
+    reachedReady: boolean
+  ): Promise<InvokeResponse<ModBisectSession>> {
+    return await invoke("submit_mod_bisect_result", {
+      instanceId,
+      reachedReady,
+    });
+  }
+
+  /**
+   * STOP bisecting mods and restore the original enabled mods.
+   * @param {string} instanceId - The instance ID.
+   * @returns {Promise<InvokeResponse<void>>}
+   */
+  @responseHandler("instance")
+  static async stopModBisect(
+    instanceId: string
+  ): Promise<InvokeResponse<void>> {
+    return await invoke("stop_mod_bisect", {
+      instanceId,
+    });
+  }
+
   /**
    * RETRIEVE the level details for a specific world.
    * @param {string} instanceId - The instance ID to retrieve the level detail for.
@@ -409,24 +576,39 @@ export class InstanceService {
    * @param {string} instanceId - The ID of the instance to update.
    * @param {ModLoaderResourceInfo} newModLoader - The new mod loader information.
    * @param {boolean} [isInstallFabricApi] - Optional flag to indicate whether to install Fabric API (only valid when modLoader is Fabric).
+   * @param {boolean} [isMoveModsAside] - Optional flag to rename the mods folder when reverting to vanilla (only valid when modLoader is Unknown).
    * @returns {Promise<InvokeResponse<void>>}
    */
   @responseHandler("instance")
   static async changeModLoader(
     instanceId: string,
     newModLoader: ModLoaderResourceInfo,
-    isInstallFabricApi?: boolean
+    isInstallFabricApi?: boolean,
+    isMoveModsAside?: boolean
   ): Promise<InvokeResponse<void>> {
     return await invoke("change_mod_loader", {
       instanceId,
       newModLoader,
       isInstallFabricApi,
+      isMoveModsAside,
     });
   }
 
+  /**
+   * REMOVE OptiFine from a given instance, keeping its mod loader.
+   * @param {string} instanceId - The ID of the instance to update.
+   * @returns {Promise<InvokeResponse<void>>}
+   */
+  @responseHandler("instance")
+  static async removeOptiFine(
+    instanceId: string
+  ): Promise<InvokeResponse<void>> {
+    return await invoke("remove_optifine", { instanceId });
+  }
+
   /**
    * RETRIEVE the modpack meta info from a given manifest path.
-   * @param {string} path - The path to the modpack manifest file.
+   * @param {string} path - The path to the modpack archive, packwiz directory or `pack.toml` url.
    * @returns {Promise<InvokeResponse<ModpackMetaInfo>>}
    */
   @responseHandler("instance")
@@ -438,6 +620,87 @@ export class InstanceService {
     });
   }
 
+  /**
+   * EXPORT the instance as a packwiz pack.
+   * @param {string} instanceId - The instance ID.
+   * @param {string} savePath - The directory to save the pack.
+   * @returns {Promise<InvokeResponse<void>>}
+   */
+  @responseHandler("instance")
+  static async exportPackwizPack(
+    instanceId: string,
+    savePath: string
+  ): Promise<InvokeResponse<void>> {
+    return await invoke("export_packwiz_pack", {
+      instanceId,
+      savePath,
+    });
+  }
+
+  /**
+   * UPDATE the modpack instance in place with a new version of the pack.
+   * @param {string} instanceId - The instance ID.
+   * @param {string} path - Path to the new modpack archive, or the packwiz source.
+   * @returns {Promise<InvokeResponse<void>>}
+   */
+  @responseHandler("instance")
+  static async updateModpack(
+    instanceId: string,
+    path: string
+  ): Promise<InvokeResponse<void>> {
+    return await invoke("update_modpack", {
+      instanceId,
+      path,
+    });
+  }
+
+  /**
+   * VERIFY the files of the modpack instance against the hashes in the manifest.
+   * @param {string} instanceId - The instance ID.
+   * @returns {Promise<InvokeResponse<ModpackVerificationReport>>}
+   */
+  @responseHandler("instance")
+  static async verifyModpackFiles(
+    instanceId: string
+  ): Promise<InvokeResponse<ModpackVerificationReport>> {
+    return await invoke("verify_modpack_files", {
+      instanceId,
+    });
+  }
+
+  /**
+   * REPAIR the missing and corrupt files of the modpack instance by downloading them again.
+   * @param {string} instanceId - The instance ID.
+   * @returns {Promise<InvokeResponse<void>>}
+   */
+  @responseHandler("instance")
+  static async repairModpackFiles(
+    instanceId: string
+  ): Promise<InvokeResponse<void>> {
+    return await invoke("repair_modpack_files", {
+      instanceId,
+    });
+  }
+
+  /**
+   * Listen for the check of the modpack files after they are downloaded.
+   * @param callback - The callback to be invoked with the report of the check.
+   */
+  static onModpackFilesChecked(
+    callback: (payload: ModpackFilesCheckedPayload) => void
+  ): () => void {
+    const unlisten = getCurrentWebview().listen<ModpackFilesCheckedPayload>(
+      "instance:modpack-files-checked",
+      (event) => {
+        callback(event.payload);
+      }
+    );
+
+    return () => {
+      unlisten.then((f) => f());
+    };
+  }
+
   /**
    * ADD/REPLACE the custom instance icon.
    * Backend will create (if missing) or replace (if existing) the custom icon file at <version_path>/icon.
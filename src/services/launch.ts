@@ -1,6 +1,6 @@
 import { invoke } from "@tauri-apps/api/core";
 import { getCurrentWebview } from "@tauri-apps/api/webview";
-import { LaunchingState } from "@/models/launch";
+import { LaunchingState, ModBisectRunExitedPayload } from "@/models/launch";
 import { InvokeResponse } from "@/models/response";
 import { responseHandler } from "@/utils/response";
 
@@ -44,16 +44,22 @@ export class LaunchService {
    * Launching Step 4: generate command args, launch the game instance.
    * @param {string} [quickPlaySingleplayer] - Optional name of the singleplayer world to auto-enter.
    * @param {string} [quickPlayMultiplayer] - Optional address of multiplayer server to auto-join.
+   * @param {boolean} [safeMode] - Optional, disable all mods for this run only.
+   * @param {boolean} [modBisect] - Optional, whether the run is started by the mod bisect flow.
    * @returns {Promise<InvokeResponse<void>>}
    */
   @responseHandler("launch")
   static async launchGame(
     quickPlaySingleplayer?: string,
-    quickPlayMultiplayer?: string
+    quickPlayMultiplayer?: string,
+    safeMode?: boolean,
+    modBisect?: boolean
   ): Promise<InvokeResponse<void>> {
     return await invoke("launch_game", {
       quickPlaySingleplayer,
       quickPlayMultiplayer,
+      safeMode,
+      modBisect,
     });
   }
 
@@ -135,4 +141,23 @@ export class LaunchService {
       unlisten.then((f) => f());
     };
   }
+
+  /**
+   * LISTEN to the exit of the game runs started by the mod bisect flow.
+   * @param callback The callback function to be called with the instance and its exit status.
+   */
+  static onModBisectRunExited(
+    callback: (payload: ModBisectRunExitedPayload) => void
+  ) {
+    const unlisten = getCurrentWebview().listen<ModBisectRunExitedPayload>(
+      "launch:mod-bisect-run-exited",
+      (event) => {
+        callback(event.payload);
+      }
+    );
+
+    return () => {
+      unlisten.then((f) => f());
+    };
+  }
 }
@@ -5,12 +5,15 @@ import { OtherResourceSource, OtherResourceType } from "@/enums/resource";
 import {
   GameClientResourceInfo,
   ModLoaderResourceInfo,
+  ModUpdateHistoryEntry,
+  ModUpdateInfo,
   ModUpdateQuery,
   OptiFineResourceInfo,
   OtherResourceFileInfo,
   OtherResourceInfo,
   OtherResourceSearchRes,
   OtherResourceVersionPack,
+  ResourceDependencyPlanItem,
 } from "@/models/resource";
 import { InvokeResponse } from "@/models/response";
 import { responseHandler } from "@/utils/response";
@@ -100,6 +103,34 @@ export class ResourceService {
     });
   }
 
+  /**
+   * FETCH the list of resources from CurseForge and Modrinth, merged into one list.
+   * @param {string} sortBy - One of relevance, downloads, updated and newest.
+   * @returns {Promise<InvokeResponse<OtherResourceSearchRes>>}
+   */
+  @responseHandler("resource")
+  static async fetchMergedResourceListByName(
+    resourceType: string,
+    searchQuery: string,
+    gameVersion: string,
+    selectedTag: string,
+    sortBy: string,
+    page: number,
+    pageSize: number
+  ): Promise<InvokeResponse<OtherResourceSearchRes>> {
+    return await invoke("fetch_merged_resource_list_by_name", {
+      query: {
+        resourceType,
+        searchQuery,
+        gameVersion,
+        selectedTag,
+        sortBy,
+        page,
+        pageSize,
+      },
+    });
+  }
+
   /**
    * FETCH the version packs for a specific resource.
    * @returns {Promise<InvokeResponse<OtherResourceVersionPack[]>>}
@@ -155,6 +186,20 @@ export class ResourceService {
     });
   }
 
+  /**
+   * CHECK updates of all mods in an instance, resolved in bulk by file hashes.
+   * @param instanceId The ID of the instance.
+   * @returns {Promise<InvokeResponse<ModUpdateInfo[]>>}
+   */
+  @responseHandler("resource")
+  static async checkModUpdates(
+    instanceId: string
+  ): Promise<InvokeResponse<ModUpdateInfo[]>> {
+    return await invoke("check_mod_updates", {
+      instanceId,
+    });
+  }
+
   /**
    * UPDATE mods in an instance based on the provided queries.
    * @param instanceId The ID of the instance.
@@ -172,6 +217,37 @@ export class ResourceService {
     });
   }
 
+  /**
+   * RETRIEVE the mod update history of an instance.
+   * @param instanceId The ID of the instance.
+   * @returns {Promise<InvokeResponse<ModUpdateHistoryEntry[]>>}
+   */
+  @responseHandler("resource")
+  static async retrieveModUpdateHistory(
+    instanceId: string
+  ): Promise<InvokeResponse<ModUpdateHistoryEntry[]>> {
+    return await invoke("retrieve_mod_update_history", {
+      instanceId,
+    });
+  }
+
+  /**
+   * ROLLBACK a mod update and all the later ones, restoring the replaced files.
+   * @param instanceId The ID of the instance.
+   * @param entryId The ID of the update history entry.
+   * @returns {Promise<InvokeResponse<void>>}
+   */
+  @responseHandler("resource")
+  static async rollbackModUpdate(
+    instanceId: string,
+    entryId: string
+  ): Promise<InvokeResponse<void>> {
+    return await invoke("rollback_mod_update", {
+      instanceId,
+      entryId,
+    });
+  }
+
   /**
    * FETCH a remote resource by ID.
    * @param downloadSource The source from which to download the resource.
@@ -189,6 +265,26 @@ export class ResourceService {
     });
   }
 
+  /**
+   * RESOLVE the required dependencies of a resource file recursively for an instance.
+   * @param instanceId The ID of the target instance.
+   * @param downloadSource The source of the resource file.
+   * @param file The resource file to be downloaded.
+   * @returns {Promise<InvokeResponse<ResourceDependencyPlanItem[]>>}
+   */
+  @responseHandler("resource")
+  static async resolveResourceDependencies(
+    instanceId: string,
+    downloadSource: OtherResourceSource,
+    file: OtherResourceFileInfo
+  ): Promise<InvokeResponse<ResourceDependencyPlanItem[]>> {
+    return await invoke("resolve_resource_dependencies", {
+      instanceId,
+      downloadSource,
+      file,
+    });
+  }
+
   /**
    * Listen for resource refresh events.
    * @param callback - The callback to be invoked when a resource refresh event occurs.
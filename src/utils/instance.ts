@@ -56,6 +56,11 @@ export const parseModLoaderVersion = (version: string): string => {
       regex: /([\d.]+)-forge-([\d.]+)/,
       getVersion: (match: RegExpMatchArray) => match[2],
     },
+    {
+      // Cleanroom: "1.12.2-cleanroom-0.3.0-alpha" or "cleanroom-0.3.0-alpha"
+      regex: /cleanroom-([\w.-]+)/,
+      getVersion: (match: RegExpMatchArray) => match[1],
+    },
     {
       // NeoForge: "neoforge-21.4.121" (Not processed for "21.10.0-beta" or "0.25w14craftmine.3-beta")
       regex: /(neoforge-)?([a-zA-Z0-9.-]+)(-beta)?/,